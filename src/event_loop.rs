@@ -1,6 +1,19 @@
 use crate::{
+    interactor::Interactor,
     sk::{sk_quit, sk_step, AppFocus, MainThreadToken, QuitReason, Sk, SkInfo},
     system::{Input, Log},
+    tools::{
+        console::SHOW_CONSOLE,
+        file_browser::FILE_BROWSER_OPEN,
+        fly_over::ENABLE_FLY_OVER,
+        log_window::SHOW_LOG_WINDOW,
+        measure::MEASURE_CLEAR_ALL,
+        notif::HUD_NOTIFICATION_PUSH,
+        passthrough_fb_ext::PASSTHROUGH_FLIP,
+        screenshot::SHOW_SCREENSHOT_WINDOW,
+        shader_watch::{SHADER_WATCH_FAILED, SHADER_WATCH_RELOADED},
+        virtual_kbd_meta::KEYBOARD_SHOW,
+    },
 };
 use std::{
     any::{Any, TypeId},
@@ -19,6 +32,8 @@ use winit::{
 };
 
 type OnStepClosure<'a> = Box<dyn FnMut(&mut Sk, &MainThreadToken) + 'a>;
+type OnFocusClosure<'a> = Box<dyn FnMut(bool) + 'a>;
+type OnAppStateClosure<'a> = Box<dyn FnMut(AppFocus) + 'a>;
 
 #[derive(PartialEq)]
 enum SleepPhase {
@@ -35,6 +50,9 @@ pub struct SkClosures<'a> {
     on_step: OnStepClosure<'a>,
     on_sleeping_step: OnStepClosure<'a>,
     shutdown: Box<dyn FnMut(&mut Sk) + 'a>,
+    on_focus: OnFocusClosure<'a>,
+    on_app_state: OnAppStateClosure<'a>,
+    app_focus: Option<AppFocus>,
     window_id: Option<WindowId>,
     sleeping: SleepPhase,
 }
@@ -117,6 +135,7 @@ impl ApplicationHandler<StepperAction> for SkClosures<'_> {
             }
             SleepPhase::Sleeping => {
                 sleep(Duration::from_millis(200));
+                self.check_app_focus();
                 (self.on_sleeping_step)(&mut self.sk, &self.token);
                 if cfg!(not(target_os = "android")) && self.sk.get_app_focus() == AppFocus::Active {
                     self.sleeping = SleepPhase::WakingUp;
@@ -145,9 +164,13 @@ impl ApplicationHandler<StepperAction> for SkClosures<'_> {
 
 impl<'a> SkClosures<'a> {
     fn step(&mut self, event_loop: &ActiveEventLoop) {
+        Sk::apply_quit_request();
         if unsafe { sk_step(None) } == 0 {
             self.window_event(event_loop, self.window_id.unwrap_or(WindowId::dummy()), WindowEvent::CloseRequested);
         }
+        Sk::apply_fixed_timestep();
+        // The custom interactors must be up to date before the steppers draw their UI.
+        Interactor::step_customs();
         if !self.sk.steppers.step(&mut self.token) {
             self.sk.steppers.shutdown();
             unsafe { sk_quit(QuitReason::User) }
@@ -156,9 +179,18 @@ impl<'a> SkClosures<'a> {
         while let Some(mut action) = self.sk.actions.pop_front() {
             action();
         }
+        self.check_app_focus();
+        Sk::step_hooks(self.app_focus == Some(AppFocus::Active));
         (self.on_step)(&mut self.sk, &self.token);
     }
 
+    fn check_app_focus(&mut self) {
+        let app_focus = self.sk.get_app_focus();
+        if self.app_focus != Some(app_focus) {
+            self.app_focus_changed(app_focus);
+        }
+    }
+
     /// Common way to run the loop with step and shutdown
     /// If you need a process when the headset is going to sleep use new(..).on_hidden_step().run()
     pub fn run_app<U: FnMut(&mut Sk, &MainThreadToken) + 'a, S: FnMut(&mut Sk) + 'a>(
@@ -172,6 +204,9 @@ impl<'a> SkClosures<'a> {
             on_step: Box::new(step),
             on_sleeping_step: Box::new(|_sk, _main_thread| {}),
             shutdown: Box::new(shutdown),
+            on_focus: Box::new(|_focused| {}),
+            on_app_state: Box::new(|_app_focus| {}),
+            app_focus: None,
             token: MainThreadToken {
                 #[cfg(feature = "event-loop")]
                 event_report: vec![],
@@ -191,6 +226,9 @@ impl<'a> SkClosures<'a> {
             on_step: Box::new(step),
             on_sleeping_step: Box::new(|_sk, _main_thread| {}),
             shutdown: Box::new(|_sk| {}),
+            on_focus: Box::new(|_focused| {}),
+            on_app_state: Box::new(|_app_focus| {}),
+            app_focus: None,
             token: MainThreadToken {
                 #[cfg(feature = "event-loop")]
                 event_report: vec![],
@@ -210,6 +248,62 @@ impl<'a> SkClosures<'a> {
         self
     }
 
+    /// Called on the main thread, before the step closure, each time the app gains or loses the focus. The app has the
+    /// focus only when [`Sk::get_app_focus`] is [`AppFocus::Active`]. This is a good place to pause audio and heavy
+    /// work when the app is backgrounded.
+    ///
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{event_loop::SkClosures, sk::AppFocus};
+    /// use std::cell::Cell;
+    ///
+    /// let focused = Cell::new(None);
+    /// let app_state = Cell::new(None);
+    /// let mut closures = SkClosures::new(sk, |_sk, _token| {});
+    /// closures.on_focus(|value| focused.set(Some(value))).on_app_state(|value| app_state.set(Some(value)));
+    ///
+    /// closures.app_focus_changed(AppFocus::Active);
+    /// assert_eq!(focused.get(), Some(true));
+    ///
+    /// // Simulate the app going to the background.
+    /// closures.app_focus_changed(AppFocus::Background);
+    /// assert_eq!(focused.get(), Some(false));
+    /// assert_eq!(app_state.get(), Some(AppFocus::Background));
+    ///
+    /// // Still not focused, only on_app_state is called.
+    /// focused.set(None);
+    /// closures.app_focus_changed(AppFocus::Hidden);
+    /// assert_eq!(focused.get(), None);
+    /// assert_eq!(app_state.get(), Some(AppFocus::Hidden));
+    /// ```
+    pub fn on_focus<F: FnMut(bool) + 'a>(&mut self, on_focus: F) -> &mut Self {
+        self.on_focus = Box::new(on_focus);
+        self
+    }
+
+    /// Called on the main thread, before the step closure, each time [`Sk::get_app_focus`] changes. See
+    /// [`SkClosures::on_focus`] for an example.
+    pub fn on_app_state<F: FnMut(AppFocus) + 'a>(&mut self, on_app_state: F) -> &mut Self {
+        self.on_app_state = Box::new(on_app_state);
+        self
+    }
+
+    /// Call the hooks [`SkClosures::on_app_state`] and [`SkClosures::on_focus`] as if the app focus had changed to
+    /// `app_focus`. This is done by the loop each time [`Sk::get_app_focus`] changes, and can be used to simulate a
+    /// transition.
+    pub fn app_focus_changed(&mut self, app_focus: AppFocus) {
+        let was_focused = self.app_focus.map(|value| value == AppFocus::Active);
+        let focused = app_focus == AppFocus::Active;
+        Log::diag(format!("App focus: {:?} -> {:?}", self.app_focus, app_focus));
+        self.app_focus = Some(app_focus);
+        (self.on_app_state)(app_focus);
+        if was_focused != Some(focused) {
+            (self.on_focus)(focused);
+        }
+    }
+
     pub fn run(&mut self, event_loop: EventLoop<StepperAction>) {
         event_loop.set_control_flow(ControlFlow::Poll);
         if let Err(err) = event_loop.run_app(self) {
@@ -353,6 +447,124 @@ impl StepperAction {
     pub fn event<S: AsRef<str>>(stepper_id: StepperId, key: S, value: S) -> Self {
         StepperAction::Event(stepper_id, key.as_ref().to_owned(), value.as_ref().to_owned())
     }
+
+    /// Non canonical function creating the Event controlling a built-in tool, with its key and value checked at
+    /// compile time.
+    /// * stepper_id - The id of the sender, as for [`StepperAction::event`].
+    /// * event - The control message.
+    ///
+    /// see also [`ToolEvent`]
+    pub fn tool_event(stepper_id: StepperId, event: ToolEvent) -> Self {
+        let (key, value) = event.to_key_value();
+        StepperAction::Event(stepper_id, key.to_owned(), value)
+    }
+}
+
+/// Non canonical enum of the control messages understood or sent by the built-in tools. They travel as the usual
+/// string [`StepperAction::Event`], so the string constants of the tools keep working.
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{event_loop::ToolEvent, tools::log_window::{LogItem, LogWindow, SHOW_LOG_WINDOW}};
+/// use std::sync::Mutex;
+///
+/// static LOG_LOG: Mutex<Vec<LogItem>> = Mutex::new(vec![]);
+/// sk.push_action(StepperAction::add("LogWindow", LogWindow::new(&LOG_LOG)));
+///
+/// // The typed event is the one the string constant gives.
+/// let action = StepperAction::tool_event("main".into(), ToolEvent::ShowLogWindow(false));
+/// assert_eq!(ToolEvent::from_action(&action), Some(ToolEvent::ShowLogWindow(false)));
+/// assert_eq!(ToolEvent::from_key_value(SHOW_LOG_WINDOW, "false"), Some(ToolEvent::ShowLogWindow(false)));
+/// assert_eq!(ToolEvent::from_key_value("Tool_ShowLogWindowTypo", "false"), None);
+///
+/// let mut log_window_enabled = vec![];
+/// number_of_steps = 6;
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     match iter {
+///         2 => sk.push_action(StepperAction::tool_event("main".into(), ToolEvent::ShowLogWindow(false))),
+///         4 => sk.push_action(StepperAction::tool_event("main".into(), ToolEvent::ShowLogWindow(true))),
+///         1 | 3 | 5 => {
+///             let steppers = sk.get_setters().get_stepper_handlers();
+///             let log_window = steppers.iter().find(|stepper| stepper.get_id() == "LogWindow").unwrap();
+///             log_window_enabled.push(log_window.is_enabled());
+///         }
+///         _ => (),
+///     }
+/// );
+/// assert_eq!(log_window_enabled, vec![true, false, true]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolEvent {
+    /// Show or hide the [`crate::tools::console::Console`].
+    ShowConsole(bool),
+    /// Enable or disable the [`crate::tools::fly_over::FlyOver`].
+    EnableFlyOver(bool),
+    /// Show or hide the [`crate::tools::log_window::LogWindow`].
+    ShowLogWindow(bool),
+    /// Show or hide the [`crate::tools::screenshot::ScreenshotViewer`].
+    ShowScreenshotWindow(bool),
+    /// Show or hide the [`crate::tools::virtual_kbd_meta::VirtualKbdMETA`].
+    KeyboardShow(bool),
+    /// Activate or deactivate the [`crate::tools::passthrough_fb_ext::PassthroughFbExt`].
+    PassthroughFlip(bool),
+    /// Push a text to the [`crate::tools::notif::HudNotification`].
+    HudNotificationPush(String),
+    /// Sent by the [`crate::tools::file_browser::FileBrowser`] with the selected file.
+    FileBrowserOpen(String),
+    /// Sent by the [`crate::tools::shader_watch::ShaderWatcher`] with the path of the reloaded file.
+    ShaderWatchReloaded(String),
+    /// Sent by the [`crate::tools::shader_watch::ShaderWatcher`] with the compiler diagnostics.
+    ShaderWatchFailed(String),
+    /// Remove all the measurements of the [`crate::tools::measure::MeasureTool`].
+    MeasureClearAll,
+}
+
+impl ToolEvent {
+    /// The key and value of the string Event carrying this message.
+    pub fn to_key_value(&self) -> (&'static str, String) {
+        let one_zero = |value: bool| if value { "1" } else { "0" }.to_string();
+        match self {
+            ToolEvent::ShowConsole(value) => (SHOW_CONSOLE, value.to_string()),
+            ToolEvent::EnableFlyOver(value) => (ENABLE_FLY_OVER, value.to_string()),
+            ToolEvent::ShowLogWindow(value) => (SHOW_LOG_WINDOW, value.to_string()),
+            ToolEvent::ShowScreenshotWindow(value) => (SHOW_SCREENSHOT_WINDOW, value.to_string()),
+            ToolEvent::KeyboardShow(value) => (KEYBOARD_SHOW, one_zero(*value)),
+            ToolEvent::PassthroughFlip(value) => (PASSTHROUGH_FLIP, one_zero(*value)),
+            ToolEvent::HudNotificationPush(text) => (HUD_NOTIFICATION_PUSH, text.clone()),
+            ToolEvent::FileBrowserOpen(file) => (FILE_BROWSER_OPEN, file.clone()),
+            ToolEvent::ShaderWatchReloaded(path) => (SHADER_WATCH_RELOADED, path.clone()),
+            ToolEvent::ShaderWatchFailed(diagnostics) => (SHADER_WATCH_FAILED, diagnostics.clone()),
+            ToolEvent::MeasureClearAll => (MEASURE_CLEAR_ALL, String::new()),
+        }
+    }
+
+    /// Decode the key and value of a string Event, as the built-in tools do. None if the key is not a tool message.
+    pub fn from_key_value(key: &str, value: &str) -> Option<ToolEvent> {
+        let event = match key {
+            SHOW_CONSOLE => ToolEvent::ShowConsole(value.parse().unwrap_or(false)),
+            ENABLE_FLY_OVER => ToolEvent::EnableFlyOver(value.parse().unwrap_or(false)),
+            SHOW_LOG_WINDOW => ToolEvent::ShowLogWindow(value.parse().unwrap_or(false)),
+            SHOW_SCREENSHOT_WINDOW => ToolEvent::ShowScreenshotWindow(value.parse().unwrap_or(false)),
+            KEYBOARD_SHOW => ToolEvent::KeyboardShow(value != "0"),
+            PASSTHROUGH_FLIP => ToolEvent::PassthroughFlip(value != "0"),
+            HUD_NOTIFICATION_PUSH => ToolEvent::HudNotificationPush(value.to_string()),
+            FILE_BROWSER_OPEN => ToolEvent::FileBrowserOpen(value.to_string()),
+            SHADER_WATCH_RELOADED => ToolEvent::ShaderWatchReloaded(value.to_string()),
+            SHADER_WATCH_FAILED => ToolEvent::ShaderWatchFailed(value.to_string()),
+            MEASURE_CLEAR_ALL => ToolEvent::MeasureClearAll,
+            _ => return None,
+        };
+        Some(event)
+    }
+
+    /// Decode a [`StepperAction::Event`]. None for the other actions and for the keys that are not tool messages.
+    pub fn from_action(action: &StepperAction) -> Option<ToolEvent> {
+        match action {
+            StepperAction::Event(_, key, value) => Self::from_key_value(key, value),
+            _ => None,
+        }
+    }
 }
 
 /// State of the stepper
@@ -371,6 +583,18 @@ pub struct StepperHandler {
     state: StepperState,
 }
 
+impl StepperHandler {
+    /// The id given to the stepper when it was added.
+    pub fn get_id(&self) -> &StepperId {
+        &self.id
+    }
+
+    /// Is the stepper enabled? see [`IStepper::enabled`]
+    pub fn is_enabled(&self) -> bool {
+        self.stepper.enabled()
+    }
+}
+
 /// A lazy way to identify IStepper instances
 pub type StepperId = String;
 
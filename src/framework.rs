@@ -1045,3 +1045,159 @@ fn generate_img_frame(distance: f32, radius: f32) -> Mesh {
 
     mesh
 }
+
+/// The slots of a [`Pool`], shared with its handles so they can give their slot back when dropped.
+struct PoolSlots {
+    active: Vec<bool>,
+    free: Vec<usize>,
+}
+
+/// Non canonical structure keeping pre-allocated instances of a Default type, to be reused by short lived things like
+/// bullets or sparks instead of allocating them at each frame. An instance is in use as long as the [`PoolHandle`]
+/// given by [`Pool::acquire`] lives, dropping the handle gives the instance back to the pool. A released instance
+/// keeps the values of its last use, so set them all after acquiring it.
+///
+/// By default the pool doesn't grow, [`Pool::acquire`] returns None once all the instances are in use.
+/// ### Examples
+/// ```
+/// use stereokit_rust::{framework::Pool, maths::Vec3};
+///
+/// #[derive(Default)]
+/// struct Spark {
+///     position: Vec3,
+///     life: f32,
+/// }
+///
+/// let mut sparks: Pool<Spark> = Pool::new(3);
+/// let mut handles: Vec<_> = (0..3).map(|_| sparks.acquire().expect("a free slot")).collect();
+/// assert!(sparks.acquire().is_none());
+/// assert_eq!(sparks.active_count(), 3);
+///
+/// for (i, handle) in handles.iter().enumerate() {
+///     let spark = sparks.get_mut(handle).unwrap();
+///     spark.position = Vec3::X * i as f32;
+///     spark.life = 1.0;
+/// }
+/// assert_eq!(sparks.iter_active().map(|spark| spark.life).sum::<f32>(), 3.0);
+///
+/// // Two sparks die, their slots are free again.
+/// handles.truncate(1);
+/// assert_eq!(sparks.active_count(), 1);
+/// assert_eq!(sparks.iter_active().next().unwrap().position, Vec3::ZERO);
+/// handles.push(sparks.acquire().expect("a released slot"));
+/// handles.push(sparks.acquire().expect("a released slot"));
+/// assert!(sparks.acquire().is_none());
+///
+/// // Opt in to grow when exhausted.
+/// sparks.grow(true);
+/// handles.push(sparks.acquire().expect("a new slot"));
+/// assert_eq!(sparks.capacity(), 4);
+/// ```
+pub struct Pool<T: Default> {
+    instances: Vec<T>,
+    slots: Rc<RefCell<PoolSlots>>,
+    grow: bool,
+}
+
+impl<T: Default> Pool<T> {
+    /// Create a pool with `capacity` instances made with T::default().
+    pub fn new(capacity: usize) -> Self {
+        let instances = (0..capacity).map(|_| T::default()).collect();
+        let slots = PoolSlots { active: vec![false; capacity], free: (0..capacity).rev().collect() };
+        Self { instances, slots: Rc::new(RefCell::new(slots)), grow: false }
+    }
+
+    /// Should the pool add a new instance when all of them are in use? Default is false.
+    pub fn grow(&mut self, grow: bool) -> &mut Self {
+        self.grow = grow;
+        self
+    }
+
+    /// Take a free instance.
+    ///
+    /// Returns the handle of the instance, or None if all the instances are in use and the pool doesn't grow.
+    pub fn acquire(&mut self) -> Option<PoolHandle> {
+        let mut slots = self.slots.as_ref().borrow_mut();
+        let index = match slots.free.pop() {
+            Some(index) => index,
+            None if self.grow => {
+                self.instances.push(T::default());
+                slots.active.push(false);
+                self.instances.len() - 1
+            }
+            None => return None,
+        };
+        slots.active[index] = true;
+        Some(PoolHandle { index, slots: self.slots.clone() })
+    }
+
+    /// The instance of a handle, or None if the handle comes from another pool.
+    pub fn get(&self, handle: &PoolHandle) -> Option<&T> {
+        if Rc::ptr_eq(&self.slots, &handle.slots) {
+            self.instances.get(handle.index)
+        } else {
+            None
+        }
+    }
+
+    /// The mutable instance of a handle, or None if the handle comes from another pool.
+    pub fn get_mut(&mut self, handle: &PoolHandle) -> Option<&mut T> {
+        if Rc::ptr_eq(&self.slots, &handle.slots) {
+            self.instances.get_mut(handle.index)
+        } else {
+            None
+        }
+    }
+
+    /// The instances in use, to draw or update them.
+    pub fn iter_active(&self) -> impl Iterator<Item = &T> {
+        let slots = &self.slots;
+        self.instances
+            .iter()
+            .enumerate()
+            .filter(move |(index, _)| slots.as_ref().borrow().active[*index])
+            .map(|(_, instance)| instance)
+    }
+
+    /// The mutable instances in use.
+    pub fn iter_active_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let slots = &self.slots;
+        self.instances
+            .iter_mut()
+            .enumerate()
+            .filter(move |(index, _)| slots.as_ref().borrow().active[*index])
+            .map(|(_, instance)| instance)
+    }
+
+    /// The number of instances in use.
+    pub fn active_count(&self) -> usize {
+        let slots = self.slots.as_ref().borrow();
+        slots.active.len() - slots.free.len()
+    }
+
+    /// The number of instances, in use or not.
+    pub fn capacity(&self) -> usize {
+        self.instances.len()
+    }
+}
+
+/// An instance of a [`Pool`] in use. Dropping it gives the instance back to the pool.
+pub struct PoolHandle {
+    index: usize,
+    slots: Rc<RefCell<PoolSlots>>,
+}
+
+impl PoolHandle {
+    /// The index of the instance in its pool.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl Drop for PoolHandle {
+    fn drop(&mut self) {
+        let mut slots = self.slots.as_ref().borrow_mut();
+        slots.active[self.index] = false;
+        slots.free.push(self.index);
+    }
+}
@@ -0,0 +1,311 @@
+use crate::{
+    maths::{Pose, Vec3},
+    system::{BtnState, Log},
+};
+use std::cell::RefCell;
+
+/// The shape of the collision volume of an interactor.
+/// <https://stereokit.net/Pages/StereoKit/InteractorType.html>
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum InteractorType {
+    /// The interactor is a single point, like the tip of a finger.
+    Point = 0,
+    /// The interactor is a capsule between two points, like a far pointer ray.
+    Line = 1,
+}
+
+bitflags::bitflags! {
+    /// The kind of interaction events an interactor can trigger on UI elements.
+    /// <https://stereokit.net/Pages/StereoKit/InteractorEvent.html>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    #[repr(C)]
+    pub struct InteractorEvent: u32 {
+        /// This interactor doesn't trigger anything.
+        const None = 0;
+        /// The interactor presses elements by moving through them, like a finger tip.
+        const Poke = 1 << 1;
+        /// The interactor presses elements with a pinch, or any other active state.
+        const Pinch = 1 << 2;
+        /// The interactor grabs elements with a grip.
+        const Grip = 1 << 3;
+    }
+}
+
+/// How an interactor becomes active.
+/// <https://stereokit.net/Pages/StereoKit/InteractorActivation.html>
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(u32)]
+pub enum InteractorActivation {
+    /// The interactor is active when its `active` state is active, like a pinch or a trigger.
+    State = 0,
+    /// The interactor is active when its position goes through the element.
+    Position = 1,
+}
+
+/// Id of an interactor in StereoKit.
+pub type InteractorT = u32;
+
+extern "C" {
+    pub fn interactor_create(
+        shape_type: InteractorType,
+        events: InteractorEvent,
+        activation_type: InteractorActivation,
+        input_source_id: i32,
+        capsule_radius: f32,
+        secondary_motion_dimensions: i32,
+    ) -> InteractorT;
+    pub fn interactor_destroy(interactor: InteractorT);
+    pub fn interactor_update(
+        interactor: InteractorT,
+        capsule_start: Vec3,
+        capsule_end: Vec3,
+        motion: Pose,
+        motion_anchor: Vec3,
+        secondary_motion: Vec3,
+        active: BtnState,
+        tracked: BtnState,
+    );
+}
+
+/// An interactor is a source of interaction for the UI, like a finger tip, a far pointer ray or a tracked stylus. The
+/// UI elements are hovered, pressed and grabbed by interactors. StereoKit creates the interactors of hands, controllers
+/// and mouse, and you can add your own.
+/// <https://stereokit.net/Pages/StereoKit/Interactor.html>
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct Interactor(pub InteractorT);
+
+/// The length of the ray of a custom interactor, in meters.
+pub const CUSTOM_INTERACTOR_RAY_LENGTH: f32 = 100.0;
+
+type PoseProvider = Box<dyn FnMut() -> (Pose, bool)>;
+
+/// An interactor registered with [`Interactor::register_custom`].
+struct CustomInteractor {
+    source_id: String,
+    interactor: Interactor,
+    pose_provider: PoseProvider,
+    pressed: bool,
+}
+
+/// The interactors registered with [`Interactor::register_custom`].
+#[derive(Default)]
+struct CustomInteractors {
+    list: Vec<CustomInteractor>,
+    /// The ids of the interactors taken out by [`Interactor::step_customs`] while their providers are called.
+    stepping: Vec<String>,
+    /// The ids unregistered by a provider while they were taken out. They are destroyed at the end of the step.
+    removed: Vec<String>,
+}
+
+thread_local! {
+    static CUSTOM_INTERACTORS: RefCell<CustomInteractors> = RefCell::new(CustomInteractors::default());
+}
+
+impl Interactor {
+    /// Create a new interactor. It must be updated at each frame with [`Interactor::update`].
+    /// <https://stereokit.net/Pages/StereoKit/Interactor/Create.html>
+    /// * shape_type - The shape of the collision volume of this interactor.
+    /// * events - The interaction events this interactor can trigger.
+    /// * activation_type - How this interactor becomes active.
+    /// * input_source_id - The id of the input source driving this interactor, or -1 if there is none.
+    /// * capsule_radius - The radius of the collision volume.
+    /// * secondary_motion_dimensions - How many axis of the secondary motion are used (0 for none).
+    ///
+    /// see also [`crate::interactor::interactor_create`]
+    pub fn create(
+        shape_type: InteractorType,
+        events: InteractorEvent,
+        activation_type: InteractorActivation,
+        input_source_id: i32,
+        capsule_radius: f32,
+        secondary_motion_dimensions: i32,
+    ) -> Interactor {
+        Interactor(unsafe {
+            interactor_create(
+                shape_type,
+                events,
+                activation_type,
+                input_source_id,
+                capsule_radius,
+                secondary_motion_dimensions,
+            )
+        })
+    }
+
+    /// Update the state of this interactor. This must be done at each frame, before the UI elements are drawn.
+    /// <https://stereokit.net/Pages/StereoKit/Interactor/Update.html>
+    /// * capsule_start - The start of the collision volume, in world space.
+    /// * capsule_end - The end of the collision volume, in world space.
+    /// * motion - The pose used to move the grabbed elements.
+    /// * motion_anchor - The point the grabbed elements rotate around.
+    /// * secondary_motion - An additional motion, like a thumbstick.
+    /// * active - The state of the activation, like a pinch or a trigger.
+    /// * tracked - The tracking state of the source of this interactor.
+    ///
+    /// see also [`crate::interactor::interactor_update`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn update(
+        &self,
+        capsule_start: impl Into<Vec3>,
+        capsule_end: impl Into<Vec3>,
+        motion: impl Into<Pose>,
+        motion_anchor: impl Into<Vec3>,
+        secondary_motion: impl Into<Vec3>,
+        active: BtnState,
+        tracked: BtnState,
+    ) {
+        unsafe {
+            interactor_update(
+                self.0,
+                capsule_start.into(),
+                capsule_end.into(),
+                motion.into(),
+                motion_anchor.into(),
+                secondary_motion.into(),
+                active,
+                tracked,
+            )
+        }
+    }
+
+    /// Destroy this interactor. It must not be used anymore.
+    /// <https://stereokit.net/Pages/StereoKit/Interactor/Destroy.html>
+    ///
+    /// see also [`crate::interactor::interactor_destroy`]
+    pub fn destroy(self) {
+        unsafe { interactor_destroy(self.0) }
+    }
+
+    /// Non canonical function registering an interactor driven by your own source, like a tracked stylus or a remote
+    /// pointer. At each step, `pose_provider` gives the pose of the ray and the pressed state of the source. The UI
+    /// elements are hovered and pressed by this ray like with a native far interactor. Registering a `source_id`
+    /// already registered replaces the previous interactor.
+    /// * source_id - The id of this custom interactor, used to remove it.
+    /// * pose_provider - Called on the main thread at each step. Returns the pose of the ray (pointing forward) and
+    ///   whether the source is pressed.
+    ///
+    /// see also [`Interactor::unregister_custom`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{interactor::Interactor, maths::{Pose, Vec2, Vec3}, ui::Ui};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// // A stylus pointing at the button. It is pressed after a few steps.
+    /// let stylus_pressed = Rc::new(Cell::new(false));
+    /// let stylus = stylus_pressed.clone();
+    /// Interactor::register_custom("stylus", move || (Pose::IDENTITY, stylus.get()));
+    /// // A provider can unregister its own interactor.
+    /// Interactor::register_custom("one_shot", || {
+    ///     assert!(Interactor::unregister_custom("one_shot"));
+    ///     (Pose::IDENTITY, false)
+    /// });
+    ///
+    /// let mut button_pressed = false;
+    /// number_of_steps = 8;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     stylus_pressed.set(iter >= 4);
+    ///     Ui::push_surface(Pose::new([0.0, 0.0, -0.5], None), Vec3::ZERO, Vec2::ZERO);
+    ///     if Ui::button_at("Press me", [0.05, 0.05, 0.0], Vec2::new(0.1, 0.1)) {
+    ///         button_pressed = true;
+    ///     }
+    ///     Ui::pop_surface();
+    /// );
+    /// assert!(button_pressed);
+    /// assert!(!Interactor::unregister_custom("one_shot"));
+    /// assert!(Interactor::unregister_custom("stylus"));
+    /// assert!(!Interactor::unregister_custom("stylus"));
+    /// ```
+    pub fn register_custom(source_id: impl AsRef<str>, pose_provider: impl FnMut() -> (Pose, bool) + 'static) {
+        let source_id = source_id.as_ref().to_string();
+        Self::unregister_custom(&source_id);
+        let interactor =
+            Interactor::create(InteractorType::Line, InteractorEvent::Pinch, InteractorActivation::State, -1, 0.005, 0);
+        CUSTOM_INTERACTORS.with_borrow_mut(|customs| {
+            customs.list.push(CustomInteractor {
+                source_id,
+                interactor,
+                pose_provider: Box::new(pose_provider),
+                pressed: false,
+            })
+        });
+    }
+
+    /// Non canonical function removing an interactor registered with [`Interactor::register_custom`].
+    /// * source_id - The id given at registration.
+    ///
+    /// Returns true if an interactor was removed. This can be called from a pose provider, even to remove its own
+    /// interactor.
+    /// see also [`Interactor::register_custom`]
+    pub fn unregister_custom(source_id: impl AsRef<str>) -> bool {
+        let source_id = source_id.as_ref();
+        let removed = CUSTOM_INTERACTORS.with_borrow_mut(|customs| {
+            if let Some(index) = customs.list.iter().position(|custom| custom.source_id == source_id) {
+                return Some(Some(customs.list.remove(index)));
+            }
+            // Taken out by step_customs, it will be destroyed at the end of the step.
+            if customs.stepping.iter().any(|id| id == source_id) && !customs.removed.iter().any(|id| id == source_id) {
+                customs.removed.push(source_id.to_string());
+                return Some(None);
+            }
+            None
+        });
+        match removed {
+            Some(Some(custom)) => {
+                custom.interactor.destroy();
+                true
+            }
+            Some(None) => true,
+            None => false,
+        }
+    }
+
+    /// Update the custom interactors from their providers. This is called at each step by Sk.
+    pub(crate) fn step_customs() {
+        // The interactors are taken out of the RefCell, so the providers can register or unregister interactors.
+        let mut stepped = CUSTOM_INTERACTORS.with_borrow_mut(|customs| {
+            customs.stepping = customs.list.iter().map(|custom| custom.source_id.clone()).collect();
+            std::mem::take(&mut customs.list)
+        });
+        if stepped.is_empty() {
+            return;
+        }
+        for custom in stepped.iter_mut() {
+            // A previous provider may have unregistered this one.
+            if CUSTOM_INTERACTORS.with_borrow(|customs| customs.removed.contains(&custom.source_id)) {
+                continue;
+            }
+            let (pose, pressed) = (custom.pose_provider)();
+            let mut active = if pressed { BtnState::Active } else { BtnState::Inactive };
+            if pressed != custom.pressed {
+                active |= if pressed { BtnState::JustActive } else { BtnState::JustInactive };
+                Log::diag(format!("Custom interactor {} pressed: {}", custom.source_id, pressed));
+            }
+            custom.pressed = pressed;
+            custom.interactor.update(
+                pose.position,
+                pose.position + pose.get_forward() * CUSTOM_INTERACTOR_RAY_LENGTH,
+                pose,
+                pose.position,
+                Vec3::ZERO,
+                active,
+                BtnState::Active,
+            );
+        }
+        let removed = CUSTOM_INTERACTORS.with_borrow_mut(|customs| {
+            let removed = std::mem::take(&mut customs.removed);
+            customs.stepping.clear();
+            let (removed, kept): (Vec<_>, Vec<_>) =
+                stepped.into_iter().partition(|custom| removed.contains(&custom.source_id));
+            let registered = std::mem::replace(&mut customs.list, kept);
+            customs.list.extend(registered);
+            removed
+        });
+        for custom in removed {
+            custom.interactor.destroy();
+        }
+    }
+}
@@ -1,7 +1,59 @@
 use std::{ffi::NulError, path::PathBuf};
+/// Embed the paths, and optionally the bytes, of the files of an assets tree.
+/// ### Examples
+/// ```
+/// use stereokit_rust::include_asset_files;
+///
+/// const ASSET_FILES: &[&str] = include_asset_files!("assets");
+/// assert!(ASSET_FILES.contains(&"assets/textures/readme.md"));
+/// assert!(!ASSET_FILES.contains(&"assets/textures"));
+///
+/// const ASSET_BYTES: &[(&str, &[u8])] = include_asset_files!("assets/textures", bytes);
+/// let (_, readme) = ASSET_BYTES.iter().find(|(path, _)| *path == "assets/textures/readme.md").unwrap();
+/// assert_eq!(*readme, std::fs::read("assets/textures/readme.md").unwrap().as_slice());
+/// ```
+pub use stereokit_macros::include_asset_files;
 pub use stereokit_macros::include_asset_tree;
 pub use stereokit_macros::test_init_sk;
 pub use stereokit_macros::test_screenshot;
+/// Derive [`event_loop::IStepper`]. See the fields and functions expected in the documentation of the macro.
+/// ### Examples
+/// ```
+/// // A low frame rate of 10 steps per second.
+/// stereokit_rust::test_init_sk!(0.1); // !!!! Get a proper way to initialize sk !!!!
+/// use stereokit_rust::util::Time;
+///
+/// #[derive(IStepper)]
+/// pub struct Physics {
+///     id: StepperId,
+///     sk_info: Option<Rc<RefCell<SkInfo>>>,
+///     fixed_accumulator: f32,
+///     fixed_dt: f32,
+///     count: u32,
+/// }
+/// unsafe impl Send for Physics {}
+///
+/// impl Physics {
+///     fn start(&mut self) -> bool { true }
+///     fn check_event(&mut self, _id: &StepperId, _key: &str, _value: &str) {}
+///     fn fixed_update(&mut self, _dt: f32) { self.count += 1 }
+///     fn draw(&mut self, _token: &MainThreadToken) {}
+/// }
+///
+/// let mut physics =
+///     Physics { id: "Physics".into(), sk_info: None, fixed_accumulator: 0.0, fixed_dt: 1.0 / 60.0, count: 0 };
+/// physics.initialize("Physics".into(), sk.get_sk_info_clone());
+///
+/// let mut elapsed = 0.0;
+/// number_of_steps = 10;
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     physics.step(token);
+///     elapsed += Time::get_stepf();
+/// );
+/// // About 6 fixed updates per step.
+/// let expected = (elapsed / physics.fixed_dt) as u32;
+/// assert!(physics.count.abs_diff(expected) <= 1, "{} fixed updates for {expected}", physics.count);
+/// ```
 #[cfg(feature = "event-loop")]
 pub use stereokit_macros::IStepper;
 use thiserror::Error;
@@ -11,10 +63,12 @@ pub mod event_loop;
 pub mod font;
 #[cfg(feature = "event-loop")]
 pub mod framework;
+pub mod interactor;
 pub mod material;
 pub mod maths;
 pub mod mesh;
 pub mod model;
+pub mod permission;
 pub mod prelude;
 pub mod render_list;
 pub mod shader;
@@ -73,6 +127,8 @@ pub enum StereoKitError {
     ShaderFile(PathBuf, String),
     #[error("failed to create shader from raw memory")]
     ShaderMem,
+    #[error("failed to compile shader: {0}")]
+    ShaderCompile(String),
     #[error("failed to find material {0} for reason {1}")]
     MaterialFind(String, String),
     #[error("failed to create sprite from texture")]
@@ -81,6 +137,8 @@ pub enum StereoKitError {
     SpriteFile(PathBuf),
     #[error("failed to find sprite {0} for reason {1}")]
     SpriteFind(String, String),
+    #[error("failed to add {0} to a sprite atlas for reason {1}")]
+    SpriteAtlas(String, String),
     #[error("failed to find sound {0} for reason {1}")]
     SoundFind(String, String),
     #[error("failed to find render list {0} for reason {1}")]
@@ -93,13 +151,21 @@ pub enum StereoKitError {
     AnchorFind(String, String),
     #[error("failed to init stereokit with settings {0}")]
     SkInit(String),
+    #[error("failed to set refresh rate {0} for reason {1}")]
+    RefreshRate(f32, String),
     #[cfg(feature = "event-loop")]
     #[error("failed to init stereokit event_loop")]
     SkInitEventLoop(#[from] winit::error::EventLoopError),
     #[error("failed to get a string from native C {0}")]
     CStrError(String),
+    #[error("unsupported format {1} to load {0}")]
+    Unsupported(String, String),
     #[error("failed to read a file {0}")]
     ReadFileError(String),
+    #[error("failed to write a file {0}")]
+    WriteFileError(String),
+    #[error("failed to parse action binding {0} for reason {1}")]
+    ActionBinding(String, String),
     #[error("Directory {0} do not exist or is not a directory")]
     DirectoryError(String),
     #[error(transparent)]
@@ -1,13 +1,15 @@
 use crate::maths::{Bool32T, Matrix, Vec2, Vec3, Vec4};
 use crate::shader::{Shader, ShaderT};
-use crate::system::{IAsset, Log};
-use crate::tex::{Tex, TexT};
+use crate::system::{Backend, BackendGraphics, IAsset, Log};
+use crate::tex::{Tex, TexT, TexType};
 use crate::util::Color128;
 use crate::StereoKitError;
 use std::ffi::{c_char, c_void, CStr, CString};
 use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut};
 use std::path::Path;
 use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Also known as ‘alpha’ for those in the know. But there’s actually more than one type of transparency in rendering!
 /// The horrors. We’re keepin’ it fairly simple for now, so you get three options!
@@ -207,6 +209,68 @@ impl Material {
         Material(NonNull::new(unsafe { material_copy(self.0.as_ptr()) }).unwrap())
     }
 
+    /// Non canonical function creating a new Material asset like [`Material::copy`], where each texture is also copied
+    /// to a new Tex asset. Modifying the textures of the copy doesn't affect the textures of this Material, which is
+    /// useful for per-instance dynamic textures. The textures are copied as TexType::Image with their format kept.
+    ///
+    /// A texture that can't be copied is shared with this Material, and a warning is logged.
+    ///
+    /// see also [`Material::copy`] [`crate::tex::Tex::copy`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, tex::Tex, util::{named_colors, Color32}};
+    ///
+    /// let red = Tex::from_color32(&[named_colors::RED; 4 * 4], 4, 4, false).unwrap();
+    /// let mut material = Material::unlit().copy();
+    /// material.diffuse_tex(&red);
+    ///
+    /// let shallow = material.copy();
+    /// let shallow_tex = shallow.get_all_param_info().get_texture("diffuse").expect("the texture is shared");
+    /// assert_eq!(shallow_tex.get_id(), red.get_id());
+    ///
+    /// let deep = material.copy_deep();
+    /// let mut deep_tex = deep.get_all_param_info().get_texture("diffuse").expect("the texture is copied");
+    /// assert_ne!(deep_tex.get_id(), red.get_id());
+    ///
+    /// deep_tex.set_colors32(4, 4, &[named_colors::BLUE; 4 * 4]);
+    /// let pixels = vec![Color32::default(); 4 * 4];
+    /// assert!(deep_tex.get_color_data::<Color32>(&pixels, 0));
+    /// assert!(pixels.iter().all(|pixel| *pixel == named_colors::BLUE));
+    /// assert!(red.get_color_data::<Color32>(&pixels, 0));
+    /// assert!(pixels.iter().all(|pixel| *pixel == named_colors::RED));
+    /// ```
+    pub fn copy_deep(&self) -> Material {
+        let copy = self.copy();
+        let material = copy.0.as_ptr();
+        let count = unsafe { material_get_param_count(material) };
+        for index in 0..count {
+            let mut name: *mut c_char = std::ptr::null_mut();
+            let mut type_info = MaterialParam::Unknown;
+            unsafe { material_get_param_info(material, index, &mut name, &mut type_info) };
+            if name.is_null() || type_info != MaterialParam::Texture {
+                continue;
+            }
+            let name = unsafe { CStr::from_ptr(name) }.to_owned();
+            let Some(tex) = NonNull::new(unsafe { material_get_texture(material, name.as_ptr()) }).map(Tex) else {
+                continue;
+            };
+            match tex.copy(Some(TexType::Image), None) {
+                Ok(tex_copy) => unsafe {
+                    material_set_texture(material, name.as_ptr(), tex_copy.0.as_ptr());
+                },
+                Err(err) => Log::warn(format!(
+                    "Material::copy_deep of {}: texture {} of param {:?} is shared ({err})",
+                    self.get_id(),
+                    tex.get_id(),
+                    name
+                )),
+            }
+        }
+        copy
+    }
+
     /// Creates a new Material asset with the same shader and properties! Draw calls with the new Material will not
     /// batch together with this one.
     /// <https://stereokit.net/Pages/StereoKit/Material/Copy.html>
@@ -489,11 +553,37 @@ impl Material {
     }
 
     /// Should this material draw only the edges/wires of the mesh? This can be useful for debugging, and even some kinds of visualization work.
-    /// Note that this may not work on some mobile OpenGL systems like Quest.
+    /// Note that this doesn't work on mobile OpenGL ES systems like Quest. There, a warning is logged once and the call
+    /// does nothing.
     /// <https://stereokit.net/Pages/StereoKit/Material/Wireframe.html>
     ///
     /// see also [`crate::material::material_set_wireframe`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Matrix, mesh::Mesh, material::Material};
+    ///
+    /// let sphere = Mesh::generate_sphere(1.0, None);
+    /// let mut material_wire = Material::pbr().copy();
+    /// material_wire.wireframe(true);
+    ///
+    /// filename_scr = "screenshots/material_wireframe.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     sphere.draw(token, &material_wire, Matrix::IDENTITY, None, None);
+    /// );
+    /// material_wire.wireframe(false);
+    /// assert!(!material_wire.get_wireframe());
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/material_wireframe.jpeg" alt="screenshot" width="200">
     pub fn wireframe(&mut self, wireframe: bool) -> &mut Self {
+        static WIREFRAME_WARNED: AtomicBool = AtomicBool::new(false);
+        if wireframe && Backend::graphics() == BackendGraphics::OpenGLESEGL {
+            if !WIREFRAME_WARNED.swap(true, Ordering::Relaxed) {
+                Log::warn("Material::wireframe is not available with OpenGL ES. The call is ignored.");
+            }
+            return self;
+        }
         unsafe { material_set_wireframe(self.0.as_ptr(), wireframe as Bool32T) };
         self
     }
@@ -527,6 +617,21 @@ impl Material {
     /// <https://stereokit.net/Pages/StereoKit/Material/QueueOffset.html>
     ///
     /// see also [`crate::material::material_set_queue_offset`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::material::{Material, Transparency};
+    ///
+    /// // The decal must be drawn after the glass it lies on.
+    /// let mut glass = Material::pbr().copy();
+    /// glass.transparency(Transparency::Blend).queue_offset(-1);
+    /// let mut decal = Material::pbr().copy();
+    /// decal.transparency(Transparency::Blend).queue_offset(2);
+    ///
+    /// assert_eq!(glass.get_queue_offset(), -1);
+    /// assert_eq!(decal.get_queue_offset(), 2);
+    /// ```
     pub fn queue_offset(&mut self, offset: i32) -> &mut Self {
         unsafe { material_set_queue_offset(self.0.as_ptr(), offset) };
         self
@@ -622,6 +727,70 @@ impl Material {
         ParamInfos::from(self)
     }
 
+    /// Non canonical function opening a scope for temporary parameter changes. The returned guard records the current
+    /// values of all the shader parameters of this material and restores them when dropped, so the params set through
+    /// the guard (it derefs to this Material) only last for the scope. Scopes can be nested, they are restored in the
+    /// reverse order of their creation.
+    ///
+    /// Only the shader parameters are restored, not the render states like transparency or cull mode.
+    /// see also [`MaterialParamScope`] [`crate::ui::Ui::push_tint`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, util::{named_colors, Color128}};
+    ///
+    /// let same = |a: Color128, b: Color128| a.r == b.r && a.g == b.g && a.b == b.b && a.a == b.a;
+    /// let mut material = Material::unlit().copy();
+    /// material.color_tint(named_colors::GREEN);
+    /// let original = material.get_all_param_info().get_color("color");
+    /// {
+    ///     let mut scope = material.push_param_scope();
+    ///     scope.color_tint(named_colors::RED);
+    ///     let red = scope.get_all_param_info().get_color("color");
+    ///     assert!(!same(red, original));
+    ///     {
+    ///         let mut nested = scope.push_param_scope();
+    ///         nested.color_tint(named_colors::BLUE);
+    ///         assert!(!same(nested.get_all_param_info().get_color("color"), red));
+    ///     }
+    ///     assert!(same(scope.get_all_param_info().get_color("color"), red));
+    /// }
+    /// assert!(same(material.get_all_param_info().get_color("color"), original));
+    /// ```
+    pub fn push_param_scope(&mut self) -> MaterialParamScope<'_> {
+        let material = self.0.as_ptr();
+        let count = unsafe { material_get_param_count(material) };
+        let mut saved = Vec::with_capacity(count.max(0) as usize);
+        for index in 0..count {
+            let mut name: *mut c_char = std::ptr::null_mut();
+            let mut type_info = MaterialParam::Unknown;
+            unsafe { material_get_param_info(material, index, &mut name, &mut type_info) };
+            if name.is_null() || type_info == MaterialParam::Unknown {
+                continue;
+            }
+            let name = unsafe { CStr::from_ptr(name) }.to_owned();
+            let value = if type_info == MaterialParam::Texture {
+                let tex = unsafe { material_get_texture(material, name.as_ptr()) };
+                match NonNull::new(tex) {
+                    Some(tex) => SavedParam::Texture(Tex(tex)),
+                    None => continue,
+                }
+            } else {
+                // 16 floats is enough for the largest param, a Matrix.
+                let mut raw = [0.0f32; 16];
+                if unsafe { material_get_param(material, name.as_ptr(), type_info, raw.as_mut_ptr() as *mut c_void) }
+                    == 0
+                {
+                    continue;
+                }
+                SavedParam::Raw(type_info, raw)
+            };
+            saved.push((name, value));
+        }
+        MaterialParamScope { material: self, saved }
+    }
+
     /// The default Physically Based Rendering material! This is used by StereoKit anytime a mesh or model has metallic
     /// or roughness properties, or needs to look more realistic. Its shader may change based on system performance
     /// characteristics, so it can be great to copy this one when creating your own materials! Or if you want to
@@ -708,6 +877,52 @@ impl Material {
     }
 }
 
+/// A value recorded by a [`MaterialParamScope`].
+#[derive(Debug)]
+enum SavedParam {
+    Raw(MaterialParam, [f32; 16]),
+    Texture(Tex),
+}
+
+/// Non canonical structure returned by [`Material::push_param_scope`]. It derefs to the Material, and restores the
+/// shader parameters recorded at its creation when dropped.
+/// see also [`Material::push_param_scope`]
+#[derive(Debug)]
+pub struct MaterialParamScope<'a> {
+    material: &'a mut Material,
+    saved: Vec<(CString, SavedParam)>,
+}
+
+impl Deref for MaterialParamScope<'_> {
+    type Target = Material;
+
+    fn deref(&self) -> &Self::Target {
+        self.material
+    }
+}
+
+impl DerefMut for MaterialParamScope<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.material
+    }
+}
+
+impl Drop for MaterialParamScope<'_> {
+    fn drop(&mut self) {
+        let material = self.material.0.as_ptr();
+        for (name, value) in self.saved.iter() {
+            match value {
+                SavedParam::Raw(type_info, raw) => unsafe {
+                    material_set_param(material, name.as_ptr(), *type_info, raw.as_ptr() as *const c_void)
+                },
+                SavedParam::Texture(tex) => unsafe {
+                    material_set_texture(material, name.as_ptr(), tex.0.as_ptr());
+                },
+            }
+        }
+    }
+}
+
 /// Infos of a Material.  This includes all global shader variables and textures.
 /// Warning, you have to be cautious when settings some infos
 /// <https://stereokit.net/Pages/StereoKit/Material/GetAllParamInfo.html>
@@ -1106,6 +1321,35 @@ impl<'a> ParamInfos<'a> {
     pub fn get_count(&self) -> i32 {
         unsafe { material_get_param_count(self.material.0.as_ptr()) }
     }
+
+    /// Gets the value of a shader parameter with the given name. If no parameter is found, a default value of ‘0’ will
+    /// be returned.
+    /// <https://stereokit.net/Pages/StereoKit/Material/GetFloat.html>
+    ///
+    /// see also [`crate::material::material_get_float`]
+    pub fn get_float<S: AsRef<str>>(&self, name: S) -> f32 {
+        let cstr = CString::new(name.as_ref()).unwrap();
+        unsafe { material_get_float(self.material.0.as_ptr(), cstr.as_ptr()) }
+    }
+
+    /// Gets the value of a shader parameter with the given name. If no parameter is found, a default value of
+    /// Color128::WHITE will be returned.
+    /// <https://stereokit.net/Pages/StereoKit/Material/GetColor.html>
+    ///
+    /// see also [`crate::material::material_get_color`]
+    pub fn get_color<S: AsRef<str>>(&self, name: S) -> Color128 {
+        let cstr = CString::new(name.as_ref()).unwrap();
+        unsafe { material_get_color(self.material.0.as_ptr(), cstr.as_ptr()) }
+    }
+
+    /// Gets the texture of a shader parameter with the given name. If no texture is found, None is returned.
+    /// <https://stereokit.net/Pages/StereoKit/Material/GetTexture.html>
+    ///
+    /// see also [`crate::material::material_get_texture`]
+    pub fn get_texture<S: AsRef<str>>(&self, name: S) -> Option<Tex> {
+        let cstr = CString::new(name.as_ref()).unwrap();
+        NonNull::new(unsafe { material_get_texture(self.material.0.as_ptr(), cstr.as_ptr()) }).map(Tex)
+    }
 }
 
 /// One Info of a Material. This is only used for read
@@ -74,6 +74,7 @@ pub mod units {
 /// see also [`glam::Vec2`]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 #[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
@@ -457,6 +458,7 @@ impl Neg for Vec2 {
 /// see also [`glam::Vec3`]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 #[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec3 {
     pub x: f32,
     pub y: f32,
@@ -931,6 +933,7 @@ impl Neg for Vec3 {
 /// see also [`glam::Vec4`]
 #[derive(Debug, Default, Copy, Clone)]
 #[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec4 {
     pub x: f32,
     pub y: f32,
@@ -1231,6 +1234,7 @@ impl Neg for Vec4 {
 ///  see also [`glam::Quat`]
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Quat {
     pub x: f32,
     pub y: f32,
@@ -1565,12 +1569,122 @@ impl From<glam::Mat4> for Matrix {
     }
 }
 
+/// The StereoKit row-major Matrix with row vectors has the same memory layout as the column-major [`glam::Mat4`] with
+/// column vectors, the rows of the first are the columns of the second.
+impl From<Matrix> for glam::Mat4 {
+    fn from(m: Matrix) -> Self {
+        glam::Mat4::from_cols_array(&unsafe { m.m })
+    }
+}
+
+/// Create a matrix from its 16 floats `m`, in StereoKit's row-major order. This is also the column-major order of the
+/// libraries using column vectors (glam, nalgebra, OpenXR, OpenGL), so a matrix goes through them without being
+/// transposed.
+/// ## Examples
+/// ```
+/// use stereokit_rust::maths::{Matrix, Quat, Vec3};
+///
+/// let translation = Vec3::new(1.0, 2.0, 3.0);
+/// let rotation = Quat::from_angles(10.0, 45.0, -30.0);
+/// let scale = Vec3::new(2.0, 0.5, 1.5);
+/// let trs = Matrix::trs(&translation, &rotation, &scale);
+///
+/// // Round-trip through the array forms.
+/// let array: [f32; 16] = trs.into();
+/// assert_eq!(unsafe { Matrix::from(array).m }, unsafe { trs.m });
+/// let rows: [[f32; 4]; 4] = trs.into();
+/// assert_eq!(rows[3][..3], [1.0, 2.0, 3.0]);
+/// assert_eq!(unsafe { Matrix::from(rows).m }, unsafe { trs.m });
+///
+/// // Round-trip through glam, which transforms the points the same way.
+/// let mat4: glam::Mat4 = trs.into();
+/// assert_eq!(unsafe { Matrix::from(mat4).m }, unsafe { trs.m });
+/// let expected = glam::Mat4::from_scale_rotation_translation(scale.into(), rotation.into(), translation.into());
+/// assert!(mat4.abs_diff_eq(expected, 0.0001));
+/// let point = Vec3::new(0.3, -0.2, 0.7);
+/// let glam_point: Vec3 = mat4.transform_point3(point.into()).into();
+/// assert!(Vec3::distance(glam_point, trs.transform_point(point)) < 0.0001);
+///
+/// // Vec3 and Quat too.
+/// assert_eq!(Vec3::from(glam::Vec3::from(translation)), translation);
+/// let back = Quat::from(glam::Quat::from(rotation));
+/// assert_eq!((back.x, back.y, back.z, back.w), (rotation.x, rotation.y, rotation.z, rotation.w));
+/// ```
+impl From<[f32; 16]> for Matrix {
+    fn from(m: [f32; 16]) -> Self {
+        Matrix { m }
+    }
+}
+
+/// The 16 floats `m` of the matrix, in StereoKit's row-major order. This is also the column-major order of the
+/// libraries using column vectors.
+impl From<Matrix> for [f32; 16] {
+    fn from(matrix: Matrix) -> Self {
+        unsafe { matrix.m }
+    }
+}
+
+/// Create a matrix from its 4 rows. They are also the 4 columns of the libraries using column vectors.
+impl From<[[f32; 4]; 4]> for Matrix {
+    fn from(rows: [[f32; 4]; 4]) -> Self {
+        Matrix { row: rows.map(|[x, y, z, w]| Vec4::new(x, y, z, w)) }
+    }
+}
+
+/// The 4 rows of the matrix, the last one holds the translation.
+impl From<Matrix> for [[f32; 4]; 4] {
+    fn from(matrix: Matrix) -> Self {
+        unsafe { matrix.row }.map(|row| [row.x, row.y, row.z, row.w])
+    }
+}
+
 impl std::fmt::Debug for Matrix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self)
     }
 }
 
+/// The layout serde gives to a Matrix, a union can't derive it.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename = "Matrix")]
+struct MatrixM {
+    m: [f32; 16],
+}
+
+/// Serialize the matrix as its 16 floats `m`, in row-major order. Available with the `serde` feature.
+/// ## Examples
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use stereokit_rust::maths::{Matrix, Pose, Quat, Vec3};
+///
+/// let pose = Pose::new(Vec3::new(1.0, 2.0, 3.0), Some(Quat::from_angles(0.0, 90.0, 0.0)));
+/// let json = serde_json::to_string(&pose).unwrap();
+/// assert!(json.starts_with(r#"{"position":{"x":1.0,"y":2.0,"z":3.0},"orientation":{"x":"#));
+/// assert_eq!(serde_json::from_str::<Pose>(&json).unwrap(), pose);
+///
+/// let matrix = pose.to_matrix(Some(Vec3::ONE * 2.0));
+/// let json = serde_json::to_string(&matrix).unwrap();
+/// assert!(json.starts_with(r#"{"m":["#));
+/// let back: Matrix = serde_json::from_str(&json).unwrap();
+/// assert_eq!(unsafe { back.m }, unsafe { matrix.m });
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for Matrix {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MatrixM { m: unsafe { self.m } }.serialize(serializer)
+    }
+}
+
+/// Deserialize a matrix from its 16 floats `m`, in row-major order. Available with the `serde` feature.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Matrix {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        MatrixM::deserialize(deserializer).map(|matrix| Matrix { m: matrix.m })
+    }
+}
+
 extern "C" {
     pub fn pose_matrix_out(pose: *const Pose, out_result: *mut Matrix, scale: Vec3);
     pub fn matrix_inverse(a: *const Matrix, out_Matrix: *mut Matrix);
@@ -1630,6 +1744,31 @@ impl Matrix {
         unsafe { matrix_orthographic(width, height, near_clip, far_clip) }
     }
 
+    /// Same as [`Matrix::ortographic`] with the right spelling.
+    /// * width - in meters, of the area that will  be projected.
+    /// * height - The height, in meters, of the area that will be projected
+    /// * near_clip - Anything closer than this distance (in meters) will be discarded.
+    /// * far_clip - Anything further than this distance (in meters) will be discarded.
+    ///
+    /// Returns the final orthographic Matrix.
+    /// see also [`crate::maths::matrix_orthographic`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Matrix, Vec4};
+    ///
+    /// let projection = Matrix::orthographic(2.0, 1.0, 0.1, 10.0);
+    ///
+    /// // The top right corner of the projected area, half way between the clip planes.
+    /// let clip = projection * Vec4::new(1.0, 0.5, -5.05, 1.0);
+    /// assert!((clip.x / clip.w - 1.0).abs() < 0.0001);
+    /// assert!((clip.y / clip.w - 1.0).abs() < 0.0001);
+    /// assert!((clip.z / clip.w - 0.5).abs() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn orthographic(width: f32, height: f32, near_clip: f32, far_clip: f32) -> Self {
+        Self::ortographic(width, height, near_clip, far_clip)
+    }
+
     /// This creates a matrix used for projecting 3D geometry onto a 2D surface for rasterization. Perspective
     /// projection matrices will cause parallel lines to converge at the horizon. This is great for normal looking
     /// content.
@@ -1698,6 +1837,70 @@ impl Matrix {
         }
     }
 
+    /// Non canonical function creating a perspective projection matrix from the edges of the near plane, like a
+    /// projection of an asymmetric view (a portal, a mirror or an eye of a stereo display). Like
+    /// [`Matrix::perspective`] it is right handed and maps the depth from 0 (near_clip) to 1 (far_clip).
+    /// * left - The left edge of the near plane, in meters, relative to the optical axis.
+    /// * right - The right edge of the near plane, in meters, relative to the optical axis.
+    /// * bottom - The bottom edge of the near plane, in meters, relative to the optical axis.
+    /// * top - The top edge of the near plane, in meters, relative to the optical axis.
+    /// * near_clip - Anything closer than this distance (in meters) will be discarded. Must not be zero.
+    /// * far_clip - Anything further than this distance (in meters) will be discarded.
+    ///
+    /// Returns the final perspective matrix.
+    /// see also [`Matrix::perspective`] [`Matrix::perspective_focal`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Matrix, Vec4};
+    ///
+    /// let projection = Matrix::perspective_off_center(-0.1, 0.1, -0.05, 0.05, 0.1, 100.0);
+    ///
+    /// // The top right corner of the near plane.
+    /// let clip = projection * Vec4::new(0.1, 0.05, -0.1, 1.0);
+    /// assert!((clip.w - 0.1).abs() < 0.0001);
+    /// assert!((clip.x / clip.w - 1.0).abs() < 0.0001);
+    /// assert!((clip.y / clip.w - 1.0).abs() < 0.0001);
+    /// assert!((clip.z / clip.w).abs() < 0.0001);
+    ///
+    /// // The center of the far plane.
+    /// let clip = projection * Vec4::new(0.0, 0.0, -100.0, 1.0);
+    /// assert!((clip.x / clip.w).abs() < 0.0001);
+    /// assert!((clip.z / clip.w - 1.0).abs() < 0.0001);
+    ///
+    /// // With the optical axis on the left edge, the points straight ahead are on the left of the image.
+    /// let projection = Matrix::perspective_off_center(0.0, 0.2, -0.05, 0.05, 0.1, 100.0);
+    /// let clip = projection * Vec4::new(0.0, 0.0, -2.0, 1.0);
+    /// assert!((clip.x / clip.w + 1.0).abs() < 0.0001);
+    /// ```
+    pub fn perspective_off_center(left: f32, right: f32, bottom: f32, top: f32, near_clip: f32, far_clip: f32) -> Self {
+        let two_near = near_clip + near_clip;
+        let f_range = far_clip / (near_clip - far_clip);
+
+        Self {
+            m: [
+                two_near / (right - left),
+                0.0,
+                0.0,
+                0.0,
+                //
+                0.0,
+                two_near / (top - bottom),
+                0.0,
+                0.0,
+                //
+                (left + right) / (right - left),
+                (top + bottom) / (top - bottom),
+                f_range,
+                -1.0,
+                //
+                0.0,
+                0.0,
+                f_range * near_clip,
+                0.0,
+            ],
+        }
+    }
+
     /// <https://stereokit.net/Pages/StereoKit/Matrix/LookAt.html>
     ///
     /// see also [`crate::maths::matrix_r`]
@@ -2235,6 +2438,7 @@ impl MulAssign<Matrix> for Matrix {
 /// see also [`crate::maths::Bounds`]
 #[derive(Copy, Clone, Debug, Default)]
 #[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bounds {
     pub center: Vec3,
     pub dimensions: Vec3,
@@ -2410,12 +2614,49 @@ impl Bounds {
     /// Note that this will likely introduce a lot of extra empty volume in many cases, as the result is still always axis aligned.
     /// <https://stereokit.net/Pages/StereoKit/Bounds/Transformed.html>
     ///
-    /// see also [`crate::maths::bounds_transform]
+    /// see also [`crate::maths::bounds_transform] [`Bounds::corners`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Bounds, Matrix, Quat, Vec3};
+    /// let cube = Bounds::bounds_centered(Vec3::ONE);
+    /// let rotated = cube.transformed(Matrix::r(Quat::from_angles(0.0, 45.0, 0.0)));
+    /// assert!((rotated.dimensions.x - 2.0_f32.sqrt()).abs() < 0.0001);
+    /// assert!((rotated.dimensions.y - 1.0).abs() < 0.0001);
+    /// assert!((rotated.dimensions.z - 2.0_f32.sqrt()).abs() < 0.0001);
+    /// assert!(Vec3::distance(rotated.center, Vec3::ZERO) < 0.0001);
+    /// ```
     #[inline]
     pub fn transformed(&self, transform: impl Into<Matrix>) -> Self {
         unsafe { bounds_transform(*self, transform.into()) }
     }
 
+    /// Non canonical function giving the 8 corners of the bounds. The first 4 are at the bottom (Y-), the last 4 at
+    /// the top (Y+). The first one is the minimum corner, the last one the maximum corner.
+    ///
+    /// see also [`Bounds::transformed`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Bounds, Vec3};
+    /// let bounds = Bounds::new([1.0, 1.0, 1.0], [2.0, 2.0, 2.0]);
+    /// let corners = bounds.corners();
+    /// assert_eq!(corners[0], Vec3::ZERO);
+    /// assert_eq!(corners[7], Vec3::new(2.0, 2.0, 2.0));
+    /// assert!(corners.iter().all(|corner| bounds.contains_point(*corner * 0.999 + Vec3::ONE * 0.001)));
+    /// ```
+    pub fn corners(&self) -> [Vec3; 8] {
+        let min = self.center - self.dimensions / 2.0;
+        let max = self.center + self.dimensions / 2.0;
+        let mut corners = [Vec3::ZERO; 8];
+        for (index, corner) in corners.iter_mut().enumerate() {
+            *corner = Vec3::new(
+                if index & 1 == 0 { min.x } else { max.x },
+                if index & 4 == 0 { min.y } else { max.y },
+                if index & 2 == 0 { min.z } else { max.z },
+            );
+        }
+        corners
+    }
+
     /// From the front, this is the Top (Y+), Left (X+), Center
     /// (Z0) of the bounds. Useful when working with UI layout bounds.
     /// <https://stereokit.net/Pages/StereoKit/Bounds/TLC.html>
@@ -2552,7 +2793,8 @@ impl Plane {
         Plane { normal, d: -Vec3::dot(p_o_p, normal) }
     }
 
-    /// Creates a plane from 3 points that are directly on that plane.
+    /// Creates a plane from 3 points that are directly on that plane. If the points are colinear there is no such
+    /// plane, the normal is then Vec3::ZERO (instead of NaN values).
     ///
     /// <https://stereokit.net/Pages/StereoKit/Plane/Plane.html>
     /// ## Examples
@@ -2560,6 +2802,13 @@ impl Plane {
     /// use stereokit_rust::maths::{Plane,Vec3};
     /// let ground = Plane::from_points(Vec3::X, Vec3::Z, Vec3::X + Vec3::Z);
     /// assert_eq!(ground.d , 0.0);
+    ///
+    /// let triangle = Plane::from_points([0.0, 0.0, -2.0], [1.0, 0.0, -2.0], [0.0, 1.0, -2.0]);
+    /// assert_eq!(triangle.normal.z.abs(), 1.0);
+    /// assert_eq!(triangle.closest(Vec3::ZERO), Vec3::new(0.0, 0.0, -2.0));
+    ///
+    /// let colinear = Plane::from_points(Vec3::ZERO, Vec3::X, Vec3::X * 2.0);
+    /// assert_eq!(colinear, Plane::new(Vec3::ZERO, 0.0));
     /// ```
     #[inline]
     pub fn from_points<V: Into<Vec3>>(point_on_plane1: V, point_on_plane2: V, point_on_plane3: V) -> Plane {
@@ -2568,7 +2817,11 @@ impl Plane {
         let p3 = point_on_plane3.into();
         let dir1 = p2 - p1;
         let dir2 = p2 - p3;
-        let normal = Vec3::cross(dir1, dir2).get_normalized();
+        let cross = Vec3::cross(dir1, dir2);
+        if cross.length_sq() <= f32::EPSILON * f32::EPSILON {
+            return Plane { normal: Vec3::ZERO, d: 0.0 };
+        }
+        let normal = cross.get_normalized();
         //let plane0 = Plane { normal, d: 0.0 };
         //let p0 = plane0.closest(p2);
         //Plane { normal, d: Vec3::distance(p0, p2) }
@@ -2612,19 +2865,39 @@ impl Plane {
         }
     }
 
-    /// Checks the intersection of a line with this plane!
+    /// Checks the intersection of a line segment with this plane!
     /// <https://stereokit.net/Pages/StereoKit/Plane/Intersect.html>
     /// * line_start - Start of the line.
     /// * line_end - End of the line.
     ///
-    /// Returns the intersection point or None if there isn't an instersection
+    /// Returns the intersection point or None if the segment is parallel to the plane or doesn't reach it.
     /// see also [`crate::maths::plane_line_intersect`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Plane,Vec3};
+    /// let plane = Plane::from_points([0.0, 0.0, -2.0], [1.0, 0.0, -2.0], [0.0, 1.0, -2.0]);
+    ///
+    /// assert_eq!(plane.intersect_line([0.5, 0.5, 0.0], [0.5, 0.5, -4.0]), Some(Vec3::new(0.5, 0.5, -2.0)));
+    /// // Too short to reach the plane.
+    /// assert_eq!(plane.intersect_line([0.5, 0.5, 0.0], [0.5, 0.5, -1.0]), None);
+    /// // Parallel to the plane.
+    /// assert_eq!(plane.intersect_line([0.0, 0.0, -1.0], [1.0, 0.0, -1.0]), None);
+    /// ```
     #[inline]
     pub fn intersect_line<V: Into<Vec3>>(&self, line_start: V, line_end: V) -> Option<Vec3> {
+        let line_start = line_start.into();
+        let line_end = line_end.into();
         let mut pt = Vec3::default();
-        match unsafe { plane_line_intersect(*self, line_start.into(), line_end.into(), &mut pt) != 0 } {
-            true => Some(pt),
-            false => None,
+        if unsafe { plane_line_intersect(*self, line_start, line_end, &mut pt) == 0 } {
+            return None;
+        }
+        // Make sure the point is on the segment, not only on the line.
+        let dir = line_end - line_start;
+        let t = Vec3::dot(pt - line_start, dir) / dir.length_sq();
+        if (0.0..=1.0).contains(&t) {
+            Some(pt)
+        } else {
+            None
         }
     }
 }
@@ -2635,11 +2908,109 @@ impl Display for Plane {
         write!(f, "[normal:{} distance:{}]", self.normal, self.d)
     }
 }
+/// The result of [`Frustum::contains_bounds`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Visibility {
+    /// Completely inside the frustum.
+    Inside,
+    /// Crossing one or more planes of the frustum.
+    Intersecting,
+    /// Completely outside the frustum, so it can be culled.
+    Outside,
+}
+
+/// Non canonical structure describing the volume seen by a camera with six planes facing inward, in the order left,
+/// right, bottom, top, near and far. Use it to cull what is not visible before submitting it to a RenderList.
+///
+/// see also [`Plane`] [`Matrix::perspective`]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Frustum {
+    /// The left, right, bottom, top, near and far planes, with normalized normals facing the inside.
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Extract the planes of a frustum from a view * projection matrix. The view matrix is the inverse of the camera
+    /// transform, the projection has StereoKit depth range (from 0 to 1) like [`Matrix::perspective`] or
+    /// [`Matrix::ortographic`]. The planes are in the space the view matrix comes from (usually world space).
+    /// * view_proj - The view matrix multiplied by the projection matrix.
+    ///
+    /// see also [`Frustum::contains_bounds`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Bounds, Frustum, Matrix, Vec3, Visibility};
+    ///
+    /// // The camera at (0,1,0) looks forward (-Z).
+    /// let view = Matrix::t(Vec3::Y).get_inverse();
+    /// let frustum = Frustum::from_view_projection(view * Matrix::perspective(90.0, 1.0, 0.1, 50.0));
+    ///
+    /// let near_the_camera = Bounds::new(Vec3::new(0.0, 1.0, -1.0), Vec3::ONE * 0.2);
+    /// assert_eq!(frustum.contains_bounds(near_the_camera), Visibility::Inside);
+    ///
+    /// let far_behind = Bounds::new(Vec3::new(0.0, 1.0, 100.0), Vec3::ONE);
+    /// assert_eq!(frustum.contains_bounds(far_behind), Visibility::Outside);
+    ///
+    /// let on_the_left_edge = Bounds::new(Vec3::new(-2.0, 1.0, -2.0), Vec3::ONE);
+    /// assert_eq!(frustum.contains_bounds(on_the_left_edge), Visibility::Intersecting);
+    ///
+    /// let beyond_far_clip = Bounds::new(Vec3::new(0.0, 1.0, -60.0), Vec3::ONE);
+    /// assert_eq!(frustum.contains_bounds(beyond_far_clip), Visibility::Outside);
+    /// ```
+    pub fn from_view_projection(view_proj: impl Into<Matrix>) -> Frustum {
+        let rows = unsafe { view_proj.into().row };
+        // StereoKit transforms row vectors, so a clip coordinate comes from a column of the matrix.
+        let column = |i: usize| {
+            let value = |row: Vec4| match i {
+                0 => row.x,
+                1 => row.y,
+                2 => row.z,
+                _ => row.w,
+            };
+            Vec4::new(value(rows[0]), value(rows[1]), value(rows[2]), value(rows[3]))
+        };
+        let (x, y, z, w) = (column(0), column(1), column(2), column(3));
+        let plane = |v: Vec4| {
+            let normal = Vec3::new(v.x, v.y, v.z);
+            let length = normal.length();
+            if length > 0.0 {
+                Plane::new(normal / length, v.w / length)
+            } else {
+                Plane::new(normal, v.w)
+            }
+        };
+        Frustum { planes: [plane(w + x), plane(w - x), plane(w + y), plane(w - y), plane(z), plane(w - z)] }
+    }
+
+    /// Check where some bounds are relative to the frustum. The bounds must be in the same space as the planes.
+    /// * bounds - The axis aligned box to check.
+    ///
+    /// Returns Inside, Intersecting or Outside. Some bounds close to a corner of the frustum may be Intersecting while
+    /// they are outside, but Outside bounds are never visible.
+    /// see also [`Frustum::from_view_projection`]
+    pub fn contains_bounds(&self, bounds: impl AsRef<Bounds>) -> Visibility {
+        let bounds = bounds.as_ref();
+        let extents = bounds.dimensions / 2.0;
+        let mut visibility = Visibility::Inside;
+        for plane in &self.planes {
+            let radius =
+                extents.x * plane.normal.x.abs() + extents.y * plane.normal.y.abs() + extents.z * plane.normal.z.abs();
+            let distance = Vec3::dot(plane.normal, bounds.center) + plane.d;
+            if distance < -radius {
+                return Visibility::Outside;
+            } else if distance < radius {
+                visibility = Visibility::Intersecting;
+            }
+        }
+        visibility
+    }
+}
+
 /// Pose represents a location and orientation in space, excluding scale! The default value of a Pose use
 /// Pose.Identity .
 /// <https://stereokit.net/Pages/StereoKit/Pose.html>
 #[repr(C)]
 #[derive(Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Pose {
     pub position: Vec3,
     pub orientation: Quat,
@@ -2674,6 +3045,46 @@ impl Pose {
         }
     }
 
+    /// Non canonical function interpolating between two poses, clamped to the endpoints. The position is lerped and
+    /// the orientation is normalized-lerped along the shortest arc, which is cheaper than [`Pose::lerp`] and smooth
+    /// enough to animate windows and cameras.
+    /// * percent - Clamped to 0..1.
+    ///
+    /// see also [`Pose::lerp`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::maths::{Pose, Quat, Vec3};
+    ///
+    /// let a = Pose::new([0.0, 1.0, 0.0], Some(Quat::from_angles(0.0, 10.0, 0.0)));
+    /// let b = Pose::new([2.0, 1.0, -2.0], Some(Quat::from_angles(0.0, 90.0, 0.0)));
+    /// assert_eq!(Pose::nlerp(a, b, 0.0), a);
+    /// assert_eq!(Pose::nlerp(a, b, 1.0), b);
+    /// assert_eq!(Pose::nlerp(a, b, 2.0), b);
+    /// assert_eq!(Pose::nlerp(a, b, 0.5).position, Vec3::new(1.0, 1.0, -1.0));
+    /// ```
+    pub fn nlerp(a: impl Into<Pose>, b: impl Into<Pose>, percent: f32) -> Self {
+        let a = a.into();
+        let b = b.into();
+        let t = percent.clamp(0.0, 1.0);
+        if t <= 0.0 {
+            return a;
+        } else if t >= 1.0 {
+            return b;
+        }
+        let (qa, mut qb) = (a.orientation, b.orientation);
+        if qa.x * qb.x + qa.y * qb.y + qa.z * qb.z + qa.w * qb.w < 0.0 {
+            qb = Quat::new(-qb.x, -qb.y, -qb.z, -qb.w);
+        }
+        let orientation = Quat::new(
+            qa.x + (qb.x - qa.x) * t,
+            qa.y + (qb.y - qa.y) * t,
+            qa.z + (qb.z - qa.z) * t,
+            qa.w + (qb.w - qa.w) * t,
+        )
+        .get_normalized();
+        Self { position: Vec3::lerp(a.position, b.position, t), orientation }
+    }
+
     /// Creates a Pose that looks from one location in the direction of another location. This leaves “Up” as the +Y
     /// axis.
     /// <https://stereokit.net/Pages/StereoKit/Pose/LookAt.html>
@@ -2686,6 +3097,27 @@ impl Pose {
         Self { position: from, orientation: Quat::look_at(from, at, None) }
     }
 
+    /// Non canonical function creating a Pose that looks from one location in the direction of another location, with
+    /// the given “Up” direction to control the roll.
+    /// * up - The direction the top of the pose points to. It must not be parallel to the look direction.
+    ///
+    /// see also [`Pose::look_at`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::maths::{Pose, Vec3};
+    ///
+    /// let pose = Pose::look_at_up([1.0, 0.0, 0.0], [1.0, 0.0, -3.0], Vec3::X);
+    /// assert_eq!(pose.position, Vec3::new(1.0, 0.0, 0.0));
+    /// assert!(Vec3::distance(pose.get_forward(), Vec3::FORWARD) < 0.001);
+    /// assert!(Vec3::distance(pose.orientation.mul_vec3(Vec3::UP), Vec3::X) < 0.001);
+    /// ```
+    #[inline]
+    pub fn look_at_up(from: impl Into<Vec3>, at: impl Into<Vec3>, up: impl Into<Vec3>) -> Self {
+        let from = from.into();
+        let at = at.into();
+        Self { position: from, orientation: Quat::look_at(from, at, Some(up.into())) }
+    }
+
     /// Converts this pose into a transform matrix.
     /// <https://stereokit.net/Pages/StereoKit/Pose/ToMatrix.html>
     /// * scale - Let you add a scale factor if needed.
@@ -2736,6 +3168,91 @@ impl Pose {
     pub fn get_up(&self) -> Vec3 {
         self.orientation.mul_vec3(Vec3::UP)
     }
+
+    /// Non canonical function encoding this pose in [`POSE_COMPRESSED_SIZE`] bytes to be sent over the network. The
+    /// position takes 3 × 16 bits as described by `position`, the orientation takes 48 bits with the smallest-three
+    /// compression: the largest component of the quaternion is dropped and the three others are stored on 15 bits.
+    ///
+    /// After a round trip, each component of the orientation is within 0.0001 of the original (once both are in the
+    /// same hemisphere), and the position is within the tolerance of [`PositionCompression`].
+    /// * position - How the position is encoded, the same value must be given to [`Pose::from_compressed`].
+    ///
+    /// see also [`Pose::from_compressed`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Pose, PositionCompression, Quat, Vec3, Vec4, POSE_COMPRESSED_SIZE};
+    ///
+    /// let pose = Pose::new(Vec3::new(1.25, -0.5, 3.0), Some(Quat::from_angles(20.0, 135.0, -40.0)));
+    /// let bytes = pose.to_compressed(PositionCompression::Half);
+    /// assert_eq!(bytes.len(), POSE_COMPRESSED_SIZE);
+    ///
+    /// let decoded = Pose::from_compressed(&bytes, PositionCompression::Half).expect("12 bytes");
+    /// assert!(Vec3::distance(decoded.position, pose.position) < 0.002);
+    /// let (a, b) = (pose.orientation, decoded.orientation);
+    /// let sign = if Vec4::dot(a.get_as_vec4(), b.get_as_vec4()) < 0.0 { -1.0 } else { 1.0 };
+    /// for (x, y) in [(a.x, b.x), (a.y, b.y), (a.z, b.z), (a.w, b.w)] {
+    ///     assert!((x - y * sign).abs() < 0.0001);
+    /// }
+    ///
+    /// // A range gives a uniform precision, here 4 / 65535 m.
+    /// let room = PositionCompression::Range(4.0);
+    /// let decoded = Pose::from_compressed(&pose.to_compressed(room), room).unwrap();
+    /// assert!(Vec3::distance(decoded.position, pose.position) < 0.0001);
+    ///
+    /// // Truncated input can't be decoded.
+    /// assert!(Pose::from_compressed(&bytes[..POSE_COMPRESSED_SIZE - 1], PositionCompression::Half).is_none());
+    /// ```
+    pub fn to_compressed(&self, position: PositionCompression) -> [u8; POSE_COMPRESSED_SIZE] {
+        let mut bytes = [0u8; POSE_COMPRESSED_SIZE];
+        for (i, value) in [self.position.x, self.position.y, self.position.z].into_iter().enumerate() {
+            bytes[i * 2..i * 2 + 2].copy_from_slice(&position.encode(value).to_le_bytes());
+        }
+
+        let components = self.orientation.get_normalized().to_array();
+        let largest = (0..4).fold(0, |max, i| if components[i].abs() > components[max].abs() { i } else { max });
+        let sign = if components[largest] < 0.0 { -1.0 } else { 1.0 };
+        let mut packed = largest as u64;
+        for value in (0..4).filter(|i| *i != largest).map(|i| components[i] * sign) {
+            let unit = (value / std::f32::consts::FRAC_1_SQRT_2).clamp(-1.0, 1.0) * 0.5 + 0.5;
+            packed = (packed << 15) | (unit * SMALLEST_THREE_MAX).round() as u64;
+        }
+        bytes[6..12].copy_from_slice(&packed.to_le_bytes()[..6]);
+        bytes
+    }
+
+    /// Non canonical function decoding a pose encoded with [`Pose::to_compressed`].
+    /// * bytes - At least [`POSE_COMPRESSED_SIZE`] bytes, the extra ones are ignored.
+    /// * position - How the position was encoded.
+    ///
+    /// Returns None if there are not enough bytes or if the range of [`PositionCompression::Range`] isn't positive.
+    /// see also [`Pose::to_compressed`]
+    pub fn from_compressed(bytes: &[u8], position: PositionCompression) -> Option<Pose> {
+        if bytes.len() < POSE_COMPRESSED_SIZE {
+            return None;
+        }
+        if let PositionCompression::Range(range) = position {
+            if range.is_nan() || range <= 0.0 {
+                return None;
+            }
+        }
+        let value = |i: usize| position.decode(u16::from_le_bytes([bytes[i * 2], bytes[i * 2 + 1]]));
+        let position = Vec3::new(value(0), value(1), value(2));
+
+        let mut packed_bytes = [0u8; 8];
+        packed_bytes[..6].copy_from_slice(&bytes[6..12]);
+        let packed = u64::from_le_bytes(packed_bytes);
+        let largest = ((packed >> 45) & 0b11) as usize;
+        let mut components = [0.0f32; 4];
+        let mut shift = 45;
+        for i in (0..4).filter(|i| *i != largest) {
+            shift -= 15;
+            let unit = ((packed >> shift) & 0x7fff) as f32 / SMALLEST_THREE_MAX;
+            components[i] = (unit * 2.0 - 1.0) * std::f32::consts::FRAC_1_SQRT_2;
+        }
+        components[largest] = (1.0 - components.iter().map(|value| value * value).sum::<f32>()).max(0.0).sqrt();
+        let [x, y, z, w] = components;
+        Some(Pose { position, orientation: Quat::new(x, y, z, w) })
+    }
 }
 
 impl Display for Pose {
@@ -2746,6 +3263,79 @@ impl Display for Pose {
     }
 }
 
+/// The number of bytes of a pose encoded with [`Pose::to_compressed`].
+pub const POSE_COMPRESSED_SIZE: usize = 12;
+
+/// The largest value of the 15 bits components of the smallest-three quaternion compression.
+const SMALLEST_THREE_MAX: f32 = 32767.0;
+
+/// How [`Pose::to_compressed`] encodes each coordinate of a position on 16 bits.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PositionCompression {
+    /// A half-float, for positions up to 65504m. The precision is relative to the distance to the origin: within 1mm
+    /// under 2m, 4mm under 8m and 1cm under 16m.
+    Half,
+    /// A fixed point value within -range..range meters, the positions outside being clamped. The precision is uniform:
+    /// within range / 65535.
+    Range(f32),
+}
+
+impl PositionCompression {
+    fn encode(&self, value: f32) -> u16 {
+        match self {
+            PositionCompression::Half => f32_to_f16(value),
+            PositionCompression::Range(range) => {
+                (((value / range).clamp(-1.0, 1.0) * 0.5 + 0.5) * u16::MAX as f32).round() as u16
+            }
+        }
+    }
+
+    fn decode(&self, value: u16) -> f32 {
+        match self {
+            PositionCompression::Half => f16_to_f32(value),
+            PositionCompression::Range(range) => (value as f32 / u16::MAX as f32 * 2.0 - 1.0) * range,
+        }
+    }
+}
+
+/// Convert a f32 to the bits of the nearest IEEE 754 half-float. Too large values become infinite.
+fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+    if exponent == 0xff {
+        return sign | 0x7c00 | if mantissa != 0 { 0x200 } else { 0 };
+    }
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+    if exponent <= 0 {
+        if exponent < -10 {
+            return sign;
+        }
+        let mantissa = mantissa | 0x80_0000;
+        let shift = (14 - exponent) as u32;
+        return sign | ((mantissa >> shift) + ((mantissa >> (shift - 1)) & 1)) as u16;
+    }
+    // A rounding carry goes into the exponent, as expected.
+    sign | ((((exponent as u32) << 10) | (mantissa >> 13)) + ((mantissa >> 12) & 1)) as u16
+}
+
+/// Convert the bits of an IEEE 754 half-float to a f32.
+fn f16_to_f32(half: u16) -> f32 {
+    let sign = if half & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((half >> 10) & 0x1f) as i32;
+    let mantissa = (half & 0x3ff) as f32;
+    sign * match exponent {
+        0 => mantissa * 2f32.powi(-24),
+        0x1f if mantissa == 0.0 => f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => (1.0 + mantissa / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
 /// fluent syntax for Sphere.
 /// Represents a sphere in 3D space! Composed of a center point and a radius, can be used for raycasting, collision,
 /// visibility, and other things!
@@ -2807,6 +3397,135 @@ impl Sphere {
             false => None,
         }
     }
+
+    /// Non canonical function to check if the given point is contained in or on the sphere. Same as
+    /// [`Sphere::contains`] but computed in Rust.
+    /// * point - The point to check.
+    ///
+    /// see also [`crate::maths::Sphere::contains`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Sphere, Vec3};
+    /// let sphere = Sphere::new(Vec3::ZERO, 1.0);
+    ///
+    /// assert!(sphere.contains_point(Vec3::ZERO));
+    /// assert!(sphere.contains_point(Vec3::X));
+    /// assert!(!sphere.contains_point([0.8, 0.8, 0.0]));
+    /// ```
+    #[inline]
+    pub fn contains_point<V: Into<Vec3>>(&self, point: V) -> bool {
+        (point.into() - self.center).length_sq() <= self.radius * self.radius
+    }
+
+    /// Non canonical function to intersect a ray with this sphere. Unlike [`Sphere::intersect`], this also returns the
+    /// distance from the ray's origin to the hit. If the ray starts inside the sphere, the far intersection (where the
+    /// ray leaves the sphere) is returned.
+    /// * ray - A ray to intersect with. Its direction doesn't need to be normalized.
+    ///
+    /// Returns the distance in meters along the ray and the point of the nearest hit in front of the origin, or None
+    /// if there is no intersection.
+    /// see also [`crate::maths::Sphere::intersect`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Ray, Sphere, Vec3};
+    /// let sphere = Sphere::new(Vec3::ZERO, 1.0);
+    ///
+    /// // Hit from the outside.
+    /// let (distance, point) = sphere.intersect_ray(Ray::new([0.0, 0.0, 3.0], [0.0, 0.0, -2.0])).unwrap();
+    /// assert_eq!(distance, 2.0);
+    /// assert_eq!(point, Vec3::new(0.0, 0.0, 1.0));
+    ///
+    /// // Miss, aside or behind.
+    /// assert!(sphere.intersect_ray(Ray::new([2.0, 0.0, 3.0], [0.0, 0.0, -1.0])).is_none());
+    /// assert!(sphere.intersect_ray(Ray::new([0.0, 0.0, 3.0], [0.0, 0.0, 1.0])).is_none());
+    ///
+    /// // From the inside, the ray leaves the sphere.
+    /// let (distance, point) = sphere.intersect_ray(Ray::new(Vec3::ZERO, Vec3::FORWARD)).unwrap();
+    /// assert_eq!(distance, 1.0);
+    /// assert_eq!(point, Vec3::FORWARD);
+    /// ```
+    pub fn intersect_ray(&self, ray: Ray) -> Option<(f32, Vec3)> {
+        let length = ray.direction.length();
+        if length <= f32::EPSILON {
+            return None;
+        }
+        let direction = ray.direction / length;
+        let to_center = self.center - ray.position;
+        let projection = Vec3::dot(to_center, direction);
+        let discriminant = projection * projection - to_center.length_sq() + self.radius * self.radius;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let half_chord = discriminant.sqrt();
+        let near = projection - half_chord;
+        let distance = if near >= 0.0 { near } else { projection + half_chord };
+        if distance < 0.0 {
+            return None;
+        }
+        Some((distance, ray.position + direction * distance))
+    }
+
+    /// Non canonical function creating the sphere containing the given bounds, its radius is half the diagonal.
+    /// * bounds - The box to contain.
+    ///
+    /// see also [`Sphere::from_points`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Bounds, Sphere, Vec3};
+    /// let sphere = Sphere::from_bounds(Bounds::new([1.0, 0.0, 0.0], [2.0, 2.0, 2.0]));
+    /// assert_eq!(sphere.center, Vec3::X);
+    /// assert!((sphere.radius - 3.0f32.sqrt()).abs() < 0.0001);
+    /// ```
+    #[inline]
+    pub fn from_bounds(bounds: impl AsRef<Bounds>) -> Sphere {
+        let bounds = bounds.as_ref();
+        Sphere { center: bounds.center, radius: bounds.dimensions.length() / 2.0 }
+    }
+
+    /// Non canonical function approximating the smallest sphere containing all the points, with Ritter's algorithm.
+    /// The result is at most a few percents larger than the optimal sphere.
+    /// * points - The points to contain. If empty, the sphere is at the origin with a radius of 0.
+    ///
+    /// see also [`Sphere::from_bounds`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Sphere, Vec3};
+    /// let points = [Vec3::X, -Vec3::X, Vec3::Y, -Vec3::Y, Vec3::Z, -Vec3::Z, Vec3::new(0.5, 0.5, 0.5)];
+    /// let sphere = Sphere::from_points(&points);
+    /// assert!(Vec3::distance(sphere.center, Vec3::ZERO) < 0.01);
+    /// assert!((sphere.radius - 1.0).abs() < 0.01);
+    /// for point in points {
+    ///     assert!(sphere.contains_point(point));
+    /// }
+    /// ```
+    pub fn from_points(points: &[Vec3]) -> Sphere {
+        let Some(first) = points.first() else {
+            return Sphere::default();
+        };
+        let farthest_from = |from: Vec3| {
+            points.iter().copied().fold(from, |far, point| {
+                if Vec3::distance_sq(from, point) > Vec3::distance_sq(from, far) {
+                    point
+                } else {
+                    far
+                }
+            })
+        };
+        let a = farthest_from(*first);
+        let b = farthest_from(a);
+        let mut center = (a + b) / 2.0;
+        let mut radius = Vec3::distance(a, b) / 2.0;
+        for point in points {
+            let distance = Vec3::distance(center, *point);
+            if distance > radius {
+                let new_radius = (radius + distance) / 2.0;
+                center = center + (*point - center) * ((new_radius - radius) / distance);
+                radius = new_radius;
+            }
+        }
+        // Rounding errors must not leave a point outside.
+        Sphere { center, radius: radius * (1.0 + f32::EPSILON * 4.0) }
+    }
 }
 impl Display for Sphere {
     /// Creates a text description of the Sphere, in the format of “[center:X radius:X]”
@@ -2843,6 +3562,397 @@ impl Rect {
     }
 }
 
+/// Non canonical structure. A 2D point or size with integer coordinates, to address the pixels of a texture, the cells
+/// of a grid or the frames of a sprite sheet.
+/// ## Examples
+/// ```
+/// use stereokit_rust::maths::{Vec2, Vec2i};
+///
+/// let size = Vec2i::new(4, 3);
+/// assert_eq!(size + Vec2i::ONE, Vec2i::from((5, 4)));
+/// assert_eq!(size * 2 - Vec2i::from([1, 1]), Vec2i::new(7, 5));
+/// assert_eq!(-size / 2, Vec2i::new(-2, -1));
+/// assert_eq!(<[i32; 2]>::from(size), [4, 3]);
+/// assert_eq!(size.x * size.y, 12);
+///
+/// let float: Vec2 = size.into();
+/// assert_eq!(float, Vec2::new(4.0, 3.0));
+/// assert_eq!(Vec2i::from_rounded(float), size);
+/// assert_eq!(Vec2i::from_rounded(Vec2::new(1.5, -1.5)), Vec2i::new(2, -2));
+/// assert_eq!(Vec2i::from_floor(Vec2::new(1.9, -1.1)), Vec2i::new(1, -2));
+/// assert_eq!(Vec2i::from_ceil(Vec2::new(1.1, -1.9)), Vec2i::new(2, -1));
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Vec2i {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Vec2i {
+    pub const ZERO: Vec2i = Vec2i { x: 0, y: 0 };
+    pub const ONE: Vec2i = Vec2i { x: 1, y: 1 };
+
+    /// Create an integer point.
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// The nearest integer point, halfway cases away from zero.
+    pub fn from_rounded(v: impl Into<Vec2>) -> Self {
+        let v = v.into();
+        Self { x: v.x.round() as i32, y: v.y.round() as i32 }
+    }
+
+    /// The integer point below or equal on each axis.
+    pub fn from_floor(v: impl Into<Vec2>) -> Self {
+        let v = v.into();
+        Self { x: v.x.floor() as i32, y: v.y.floor() as i32 }
+    }
+
+    /// The integer point above or equal on each axis.
+    pub fn from_ceil(v: impl Into<Vec2>) -> Self {
+        let v = v.into();
+        Self { x: v.x.ceil() as i32, y: v.y.ceil() as i32 }
+    }
+}
+
+impl From<[i32; 2]> for Vec2i {
+    fn from(val: [i32; 2]) -> Self {
+        Vec2i { x: val[0], y: val[1] }
+    }
+}
+
+impl From<(i32, i32)> for Vec2i {
+    fn from(val: (i32, i32)) -> Self {
+        Vec2i { x: val.0, y: val.1 }
+    }
+}
+
+impl From<Vec2i> for [i32; 2] {
+    fn from(val: Vec2i) -> Self {
+        [val.x, val.y]
+    }
+}
+
+impl From<Vec2i> for (i32, i32) {
+    fn from(val: Vec2i) -> Self {
+        (val.x, val.y)
+    }
+}
+
+impl From<Vec2i> for Vec2 {
+    fn from(val: Vec2i) -> Self {
+        Vec2 { x: val.x as f32, y: val.y as f32 }
+    }
+}
+
+impl Display for Vec2i {
+    /// Mostly for debug purposes, this is a decent way to log or inspect the vector in debug mode. Looks like “[x:1, y:2]”
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[x:{}, y:{}]", self.x, self.y)
+    }
+}
+
+/// Adds matching components together. Commutative.
+impl Add<Vec2i> for Vec2i {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+/// Adds matching components together. Commutative.
+impl AddAssign<Vec2i> for Vec2i {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+/// Subtracts matching components from eachother. Not commutative.
+impl Sub<Vec2i> for Vec2i {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+/// Subtracts matching components from eachother. Not commutative.
+impl SubAssign<Vec2i> for Vec2i {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+/// A component-wise vector multiplication.
+impl Mul<Vec2i> for Vec2i {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { x: self.x * rhs.x, y: self.y * rhs.y }
+    }
+}
+
+/// A scalar multiplication of each component.
+impl Mul<i32> for Vec2i {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self { x: self.x * rhs, y: self.y * rhs }
+    }
+}
+
+/// A scalar multiplication of each component.
+impl MulAssign<i32> for Vec2i {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+/// A scalar integer division of each component, rounded toward zero.
+impl Div<i32> for Vec2i {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        Self { x: self.x / rhs, y: self.y / rhs }
+    }
+}
+
+/// Vector negation, returns a vector where each component has been negated.
+impl Neg for Vec2i {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { x: -self.x, y: -self.y }
+    }
+}
+
+/// Non canonical structure. A 3D point or size with integer coordinates, to address the cells of a voxel grid or the
+/// texels of a 3D texture.
+/// ## Examples
+/// ```
+/// use stereokit_rust::maths::{Vec3, Vec3i};
+///
+/// let cell = Vec3i::new(1, -2, 3);
+/// assert_eq!(cell + Vec3i::ONE, Vec3i::from((2, -1, 4)));
+/// assert_eq!(cell * Vec3i::new(2, 2, 0), Vec3i::from([2, -4, 0]));
+/// assert_eq!((cell * 3) / 2, Vec3i::new(1, -3, 4));
+/// let mut moved = cell;
+/// moved -= Vec3i::new(1, 1, 1);
+/// assert_eq!(<(i32, i32, i32)>::from(moved), (0, -3, 2));
+///
+/// let float: Vec3 = cell.into();
+/// assert_eq!(float, Vec3::new(1.0, -2.0, 3.0));
+/// assert_eq!(Vec3i::from_rounded(float + Vec3::ONE * 0.4), cell);
+/// assert_eq!(Vec3i::from_floor(float - Vec3::ONE * 0.1), cell - Vec3i::ONE);
+/// assert_eq!(Vec3i::from_ceil(float + Vec3::ONE * 0.1), cell + Vec3i::ONE);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Vec3i {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Vec3i {
+    pub const ZERO: Vec3i = Vec3i { x: 0, y: 0, z: 0 };
+    pub const ONE: Vec3i = Vec3i { x: 1, y: 1, z: 1 };
+
+    /// Create an integer point.
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The nearest integer point, halfway cases away from zero.
+    pub fn from_rounded(v: impl Into<Vec3>) -> Self {
+        let v = v.into();
+        Self { x: v.x.round() as i32, y: v.y.round() as i32, z: v.z.round() as i32 }
+    }
+
+    /// The integer point below or equal on each axis.
+    pub fn from_floor(v: impl Into<Vec3>) -> Self {
+        let v = v.into();
+        Self { x: v.x.floor() as i32, y: v.y.floor() as i32, z: v.z.floor() as i32 }
+    }
+
+    /// The integer point above or equal on each axis.
+    pub fn from_ceil(v: impl Into<Vec3>) -> Self {
+        let v = v.into();
+        Self { x: v.x.ceil() as i32, y: v.y.ceil() as i32, z: v.z.ceil() as i32 }
+    }
+}
+
+impl From<[i32; 3]> for Vec3i {
+    fn from(val: [i32; 3]) -> Self {
+        Vec3i { x: val[0], y: val[1], z: val[2] }
+    }
+}
+
+impl From<(i32, i32, i32)> for Vec3i {
+    fn from(val: (i32, i32, i32)) -> Self {
+        Vec3i { x: val.0, y: val.1, z: val.2 }
+    }
+}
+
+impl From<Vec3i> for [i32; 3] {
+    fn from(val: Vec3i) -> Self {
+        [val.x, val.y, val.z]
+    }
+}
+
+impl From<Vec3i> for (i32, i32, i32) {
+    fn from(val: Vec3i) -> Self {
+        (val.x, val.y, val.z)
+    }
+}
+
+impl From<Vec3i> for Vec3 {
+    fn from(val: Vec3i) -> Self {
+        Vec3 { x: val.x as f32, y: val.y as f32, z: val.z as f32 }
+    }
+}
+
+impl Display for Vec3i {
+    /// Mostly for debug purposes, this is a decent way to log or inspect the vector in debug mode. Looks like “[x:1, y:2, z:3]”
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[x:{}, y:{}, z:{}]", self.x, self.y, self.z)
+    }
+}
+
+/// Adds matching components together. Commutative.
+impl Add<Vec3i> for Vec3i {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    }
+}
+
+/// Adds matching components together. Commutative.
+impl AddAssign<Vec3i> for Vec3i {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+/// Subtracts matching components from eachother. Not commutative.
+impl Sub<Vec3i> for Vec3i {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+/// Subtracts matching components from eachother. Not commutative.
+impl SubAssign<Vec3i> for Vec3i {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+/// A component-wise vector multiplication.
+impl Mul<Vec3i> for Vec3i {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { x: self.x * rhs.x, y: self.y * rhs.y, z: self.z * rhs.z }
+    }
+}
+
+/// A scalar multiplication of each component.
+impl Mul<i32> for Vec3i {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+    }
+}
+
+/// A scalar multiplication of each component.
+impl MulAssign<i32> for Vec3i {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+/// A scalar integer division of each component, rounded toward zero.
+impl Div<i32> for Vec3i {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: i32) -> Self::Output {
+        Self { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs }
+    }
+}
+
+/// Vector negation, returns a vector where each component has been negated.
+impl Neg for Vec3i {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+/// Non canonical structure. A 2D rectangle with integer coordinates, defined by its top left corner and its
+/// width/height, to address a region of pixels in a texture.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Recti {
+    /// The X axis position of the top left corner of the rectangle.
+    pub x: i32,
+    /// The Y axis position of the top left corner of the rectangle.
+    pub y: i32,
+    /// The width of the rectangle.
+    pub width: i32,
+    /// The height of the rectangle.
+    pub height: i32,
+}
+
+impl Recti {
+    /// Create an integer rectangle, defined by its top left corner and its width/height.
+    pub const fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Is this rectangle not empty and entirely inside a `width` x `height` area starting at 0,0.
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::Recti;
+    /// assert!(Recti::new(0, 0, 4, 4).is_inside(4, 4));
+    /// assert!(Recti::new(2, 1, 2, 3).is_inside(4, 4));
+    /// assert!(!Recti::new(2, 1, 3, 3).is_inside(4, 4));
+    /// assert!(!Recti::new(-1, 0, 2, 2).is_inside(4, 4));
+    /// assert!(!Recti::new(0, 0, 0, 2).is_inside(4, 4));
+    /// ```
+    pub fn is_inside(&self, width: i32, height: i32) -> bool {
+        self.x >= 0
+            && self.y >= 0
+            && self.width > 0
+            && self.height > 0
+            && self.x + self.width <= width
+            && self.y + self.height <= height
+    }
+}
+
 /// A position and a direction indicating a ray through space! This is a great tool for intersection testing with
 /// geometrical shapes.
 /// <https://stereokit.net/Pages/StereoKit/Ray.html>
@@ -2911,6 +4021,87 @@ impl Ray {
         unsafe { ray_point_closest(*self, to.into()) }
     }
 
+    /// Non canonical function calculating the point of the segment going from `position` to `position + direction`
+    /// that’s closest to the given point.
+    /// * to - Any point in the same coordinate space as the Ray.
+    ///
+    /// Returns the point on the segment that's closest to the given point.
+    /// see also [`Ray::closest`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Ray, Vec3};
+    ///
+    /// let segment = Ray::new(Vec3::ZERO, Vec3::new(2.0, 0.0, 0.0));
+    ///
+    /// assert_eq!(segment.closest_point_on_segment(Vec3::new(1.0, 5.0, 0.0)), Vec3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(segment.closest_point_on_segment(Vec3::new(5.0, 1.0, 0.0)), Vec3::new(2.0, 0.0, 0.0));
+    /// assert_eq!(segment.closest_point_on_segment(Vec3::new(-3.0, 1.0, 0.0)), Vec3::ZERO);
+    /// // Unlike Ray::closest, which goes on behind the ray.
+    /// assert_eq!(segment.closest(Vec3::new(-3.0, 1.0, 0.0)), Vec3::new(-3.0, 0.0, 0.0));
+    /// ```
+    pub fn closest_point_on_segment<V: Into<Vec3>>(&self, to: V) -> Vec3 {
+        let length_sq = self.direction.length_sq();
+        if length_sq <= f32::EPSILON {
+            return self.position;
+        }
+        let percent = Vec3::dot(to.into() - self.position, self.direction) / length_sq;
+        self.get_at(percent.clamp(0.0, 1.0))
+    }
+
+    /// Non canonical function calculating the closest points between the lines of two rays. Like [`Ray::closest`] the
+    /// points can be in front of, or behind the rays’ starting positions. If the rays are parallel, the first point is
+    /// `a.position`.
+    /// * a - The first ray.
+    /// * b - The second ray.
+    ///
+    /// Returns the point on `a` closest to `b`, and the point on `b` closest to `a`.
+    /// see also [`Ray::closest`]
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{Ray, Vec3};
+    ///
+    /// // Perpendicular rays.
+    /// let a = Ray::new(Vec3::ZERO, Vec3::X);
+    /// let b = Ray::new(Vec3::new(2.0, 1.0, 0.0), Vec3::Z);
+    /// assert_eq!(Ray::closest_points(a, b), (Vec3::new(2.0, 0.0, 0.0), Vec3::new(2.0, 1.0, 0.0)));
+    ///
+    /// // Parallel rays.
+    /// let b = Ray::new(Vec3::new(3.0, 1.0, 0.0), Vec3::X * 2.0);
+    /// assert_eq!(Ray::closest_points(a, b), (Vec3::ZERO, Vec3::new(0.0, 1.0, 0.0)));
+    ///
+    /// // Skew rays.
+    /// let b = Ray::new(Vec3::new(0.0, 1.0, 1.0), Vec3::new(1.0, 0.0, -1.0));
+    /// let (on_a, on_b) = Ray::closest_points(a, b);
+    /// assert!(Vec3::distance(on_a, Vec3::new(1.0, 0.0, 0.0)) < 0.0001);
+    /// assert!(Vec3::distance(on_b, Vec3::new(1.0, 1.0, 0.0)) < 0.0001);
+    /// ```
+    pub fn closest_points(a: Ray, b: Ray) -> (Vec3, Vec3) {
+        let r = a.position - b.position;
+        let aa = a.direction.length_sq();
+        let ee = b.direction.length_sq();
+        let ab = Vec3::dot(a.direction, b.direction);
+        let c = Vec3::dot(a.direction, r);
+        let f = Vec3::dot(b.direction, r);
+        if aa <= f32::EPSILON || ee <= f32::EPSILON {
+            // At least one of the rays is a point.
+            let on_a = if aa <= f32::EPSILON { a.position } else { a.get_at(-c / aa) };
+            let on_b = if ee <= f32::EPSILON {
+                b.position
+            } else {
+                b.get_at(Vec3::dot(b.direction, on_a - b.position) / ee)
+            };
+            return (on_a, on_b);
+        }
+        let denominator = aa * ee - ab * ab;
+        if denominator <= f32::EPSILON * aa * ee {
+            // Parallel rays, any point of a will do.
+            return (a.position, b.get_at(f / ee));
+        }
+        let s = (ab * f - c * ee) / denominator;
+        let t = (aa * f - ab * c) / denominator;
+        (a.get_at(s), b.get_at(t))
+    }
+
     /// Checks the intersection of this ray with a plane!
     /// <https://stereokit.net/Pages/StereoKit/Ray/Intersect.html>
     /// * plane - Any plane you want to intersect with.
@@ -3061,3 +4252,260 @@ impl Display for Ray {
         write!(f, "[position:{} direction:{}]", self.position, self.direction)
     }
 }
+
+/// The number of samples per segment of a [`Spline`] used to measure its length.
+const SPLINE_SAMPLES_PER_SEGMENT: usize = 16;
+
+/// Non canonical structure. A smooth path going through a list of Pose waypoints, to animate a camera or an object.
+/// Positions follow a Catmull-Rom curve and orientations are normalized-lerped between the waypoints. The first and
+/// last waypoints are duplicated to compute the end tangents, so the path doesn't overshoot its endpoints.
+///
+/// see also [`Pose::nlerp`]
+/// ## Examples
+/// ```
+/// use stereokit_rust::maths::{Pose, Quat, Spline, Vec3};
+///
+/// let waypoints = [
+///     Pose::new([0.0, 0.0, 0.0], None),
+///     Pose::new([1.0, 0.0, 0.0], Some(Quat::from_angles(0.0, 90.0, 0.0))),
+///     Pose::new([1.0, 0.0, -1.0], Some(Quat::from_angles(0.0, 180.0, 0.0))),
+///     Pose::new([2.0, 0.0, -1.0], None),
+/// ];
+/// let spline = Spline::new(&waypoints);
+///
+/// assert_eq!(spline.sample(0.0), waypoints[0]);
+/// assert_eq!(spline.sample(1.0), waypoints[3]);
+///
+/// // The middle of the second segment lies between its waypoints.
+/// let middle = spline.sample(0.5).position;
+/// assert!(middle.x > 0.9 && middle.x < 1.1);
+/// assert!(middle.z < 0.0 && middle.z > -1.0);
+///
+/// // The path is a little longer than the straight lines between the waypoints.
+/// assert!(spline.length() >= 3.0 && spline.length() < 3.5);
+/// assert_eq!(spline.sample_by_distance(0.0), waypoints[0]);
+/// assert_eq!(spline.sample_by_distance(100.0), waypoints[3]);
+/// let half_way = spline.sample_by_distance(spline.length() / 2.0).position;
+/// assert!(Vec3::distance(half_way, middle) < 0.05);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Spline {
+    waypoints: Vec<Pose>,
+    /// The distance from the start of the path of each sample.
+    distances: Vec<f32>,
+}
+
+impl Spline {
+    /// Create a spline going through the waypoints in this order.
+    /// * waypoints - The poses to go through. An empty list gives Pose::IDENTITY everywhere.
+    pub fn new(waypoints: &[Pose]) -> Self {
+        let mut spline = Self { waypoints: waypoints.to_vec(), distances: vec![0.0] };
+        let sample_count = spline.get_sample_count();
+        let mut previous = spline.sample(0.0).position;
+        let mut distance = 0.0;
+        for index in 1..=sample_count {
+            let position = spline.sample(index as f32 / sample_count as f32).position;
+            distance += Vec3::distance(previous, position);
+            spline.distances.push(distance);
+            previous = position;
+        }
+        spline
+    }
+
+    /// The waypoints of this spline.
+    pub fn get_waypoints(&self) -> &[Pose] {
+        &self.waypoints
+    }
+
+    /// The approximate length of the path in meters.
+    pub fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or(0.0)
+    }
+
+    /// The pose along the path, each segment between two waypoints taking the same share of `t`.
+    /// * t - 0.0 is the first waypoint, 1.0 the last one. Clamped to 0..1.
+    pub fn sample(&self, t: f32) -> Pose {
+        let count = self.waypoints.len();
+        match count {
+            0 => return Pose::IDENTITY,
+            1 => return self.waypoints[0],
+            _ => {}
+        }
+        if t <= 0.0 {
+            return self.waypoints[0];
+        } else if t >= 1.0 {
+            return self.waypoints[count - 1];
+        }
+        let scaled = t * (count - 1) as f32;
+        let index = (scaled.floor() as usize).min(count - 2);
+        let u = scaled - index as f32;
+
+        let point = |i: isize| self.waypoints[i.clamp(0, count as isize - 1) as usize].position;
+        let i = index as isize;
+        let (p0, p1, p2, p3) = (point(i - 1), point(i), point(i + 1), point(i + 2));
+        let (u2, u3) = (u * u, u * u * u);
+        let position = (p1 * 2.0
+            + (p2 - p0) * u
+            + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * u2
+            + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * u3)
+            * 0.5;
+
+        let orientation = Pose::nlerp(self.waypoints[index], self.waypoints[index + 1], u).orientation;
+        Pose { position, orientation }
+    }
+
+    /// The pose at the given distance from the start of the path, to move along the path at a constant speed.
+    /// * distance - In meters, clamped to 0..length().
+    pub fn sample_by_distance(&self, distance: f32) -> Pose {
+        let sample_count = self.get_sample_count();
+        if distance <= 0.0 || sample_count == 0 {
+            return self.sample(0.0);
+        } else if distance >= self.length() {
+            return self.sample(1.0);
+        }
+        let next = self.distances.partition_point(|sample| *sample < distance).max(1);
+        let (before, after) = (self.distances[next - 1], self.distances[next]);
+        let fraction = if after > before { (distance - before) / (after - before) } else { 0.0 };
+        self.sample((next as f32 - 1.0 + fraction) / sample_count as f32)
+    }
+
+    fn get_sample_count(&self) -> usize {
+        self.waypoints.len().saturating_sub(1) * SPLINE_SAMPLES_PER_SEGMENT
+    }
+}
+
+/// Non canonical overlap tests between spheres, bounds and meshes, for grabbing, snapping or triggers without a physics
+/// engine.
+pub mod collide {
+    use super::{Bounds, Matrix, Sphere, Vec3};
+    use crate::mesh::Mesh;
+
+    /// Does the sphere overlap the bounds? Touching counts as overlapping.
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{collide, Bounds, Sphere, Vec3};
+    ///
+    /// let bounds = Bounds::new(Vec3::ZERO, Vec3::ONE);
+    /// assert!(collide::sphere_vs_bounds(Sphere { center: Vec3::ZERO, radius: 0.1 }, bounds));
+    /// assert!(collide::sphere_vs_bounds(Sphere { center: [0.7, 0.0, 0.0].into(), radius: 0.25 }, bounds));
+    /// assert!(!collide::sphere_vs_bounds(Sphere { center: [0.7, 0.0, 0.0].into(), radius: 0.1 }, bounds));
+    /// // Near a corner, the distance is the diagonal.
+    /// assert!(!collide::sphere_vs_bounds(Sphere { center: [0.7, 0.7, 0.7].into(), radius: 0.3 }, bounds));
+    /// ```
+    pub fn sphere_vs_bounds(sphere: Sphere, bounds: Bounds) -> bool {
+        let half = bounds.dimensions / 2.0;
+        let closest = Vec3::max(bounds.center - half, Vec3::min(sphere.center, bounds.center + half));
+        Vec3::distance_sq(closest, sphere.center) <= sphere.radius * sphere.radius
+    }
+
+    /// Do the two bounds overlap? Touching counts as overlapping.
+    /// ## Examples
+    /// ```
+    /// use stereokit_rust::maths::{collide, Bounds};
+    ///
+    /// let a = Bounds::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+    /// assert!(collide::bounds_vs_bounds(a, Bounds::new([0.9, 0.0, 0.0], [1.0, 1.0, 1.0])));
+    /// assert!(collide::bounds_vs_bounds(a, Bounds::new([0.0, 0.0, 0.0], [0.1, 0.1, 0.1])));
+    /// assert!(!collide::bounds_vs_bounds(a, Bounds::new([0.0, 1.2, 0.0], [1.0, 1.0, 1.0])));
+    /// ```
+    pub fn bounds_vs_bounds(a: Bounds, b: Bounds) -> bool {
+        let distance = (a.center - b.center).abs();
+        let reach = (a.dimensions + b.dimensions) / 2.0;
+        distance.x <= reach.x && distance.y <= reach.y && distance.z <= reach.z
+    }
+
+    /// Does the sphere touch a triangle of the mesh? The mesh must keep its data on the CPU, see
+    /// [`Mesh::keep_data`].
+    /// * mesh_transform - The transform of the mesh in the space of the sphere.
+    ///
+    /// Returns the contact normal of the deepest contact, pointing from the mesh to the center of the sphere, or None
+    /// if there is no contact.
+    /// ## Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{collide, Matrix, Sphere, Vec3}, mesh::{Mesh, Vertex}};
+    ///
+    /// // A triangle in the plane Z = 0, facing Z+.
+    /// let mut triangle = Mesh::new();
+    /// triangle.set_verts(&[Vertex::new([0.0, 0.0, 0.0], [0.0, 0.0, 1.0], None, None),
+    ///                      Vertex::new([1.0, 0.0, 0.0], [0.0, 0.0, 1.0], None, None),
+    ///                      Vertex::new([0.0, 1.0, 0.0], [0.0, 0.0, 1.0], None, None)], true)
+    ///         .set_inds(&[0, 2, 1]);
+    ///
+    /// let touching = Sphere { center: [0.2, 0.2, 0.05].into(), radius: 0.1 };
+    /// let normal = collide::sphere_vs_mesh(touching, &triangle, Matrix::IDENTITY).expect("a contact");
+    /// assert!(Vec3::distance(normal, Vec3::Z) < 0.001);
+    ///
+    /// // Beyond the edge of the triangle, the normal points from the edge.
+    /// let on_edge = Sphere { center: [0.6, 0.6, 0.0].into(), radius: 0.2 };
+    /// let normal = collide::sphere_vs_mesh(on_edge, &triangle, Matrix::IDENTITY).expect("a contact");
+    /// assert!(Vec3::distance(normal, Vec3::new(1.0, 1.0, 0.0).get_normalized()) < 0.001);
+    ///
+    /// let separated = Sphere { center: [0.2, 0.2, 0.5].into(), radius: 0.1 };
+    /// assert!(collide::sphere_vs_mesh(separated, &triangle, Matrix::IDENTITY).is_none());
+    ///
+    /// // Moved away by its transform.
+    /// assert!(collide::sphere_vs_mesh(touching, &triangle, Matrix::t(Vec3::Z)).is_none());
+    /// ```
+    pub fn sphere_vs_mesh(sphere: Sphere, mesh: &Mesh, mesh_transform: impl Into<Matrix>) -> Option<Vec3> {
+        let mesh_transform: Matrix = mesh_transform.into();
+        if !sphere_vs_bounds(sphere, mesh.get_bounds().transformed(mesh_transform)) {
+            return None;
+        }
+        let vertices: Vec<Vec3> =
+            mesh.get_verts().iter().map(|vertex| mesh_transform.transform_point(vertex.pos)).collect();
+        let radius_sq = sphere.radius * sphere.radius;
+
+        let mut deepest: Option<(f32, Vec3)> = None;
+        for triangle in mesh.get_inds().chunks_exact(3) {
+            let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(|index| vertices[index as usize]);
+            let closest = closest_point_on_triangle(sphere.center, a, b, c);
+            let distance_sq = Vec3::distance_sq(closest, sphere.center);
+            if distance_sq > radius_sq || deepest.is_some_and(|(deepest_sq, _)| deepest_sq <= distance_sq) {
+                continue;
+            }
+            let normal = if distance_sq > f32::EPSILON * f32::EPSILON {
+                (sphere.center - closest).get_normalized()
+            } else {
+                // The center is on the triangle, use the face normal (clockwise triangles).
+                Vec3::cross(c - a, b - a).get_normalized()
+            };
+            deepest = Some((distance_sq, normal));
+        }
+        deepest.map(|(_, normal)| normal)
+    }
+
+    /// The closest point of the triangle abc to p (Real-Time Collision Detection, 5.1.5).
+    fn closest_point_on_triangle(p: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+        let (ab, ac, ap) = (b - a, c - a, p - a);
+        let (d1, d2) = (Vec3::dot(ab, ap), Vec3::dot(ac, ap));
+        if d1 <= 0.0 && d2 <= 0.0 {
+            return a;
+        }
+        let bp = p - b;
+        let (d3, d4) = (Vec3::dot(ab, bp), Vec3::dot(ac, bp));
+        if d3 >= 0.0 && d4 <= d3 {
+            return b;
+        }
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+            return a + ab * (d1 / (d1 - d3));
+        }
+        let cp = p - c;
+        let (d5, d6) = (Vec3::dot(ab, cp), Vec3::dot(ac, cp));
+        if d6 >= 0.0 && d5 <= d6 {
+            return c;
+        }
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+            return a + ac * (d2 / (d2 - d6));
+        }
+        let va = d3 * d6 - d5 * d4;
+        if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+            return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+        let denominator = 1.0 / (va + vb + vc);
+        a + ab * (vb * denominator) + ac * (vc * denominator)
+    }
+}
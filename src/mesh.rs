@@ -1,13 +1,15 @@
 use crate::{
     material::{Cull, Material, MaterialT},
-    maths::{Bool32T, Bounds, Matrix, Ray, Vec2, Vec3, Vec4},
+    maths::{Bool32T, Bounds, Matrix, Ray, Sphere, Vec2, Vec2i, Vec3, Vec4},
     sk::MainThreadToken,
     system::{IAsset, RenderLayer},
     util::{Color128, Color32},
     StereoKitError,
 };
 use std::{
+    collections::HashMap,
     ffi::{c_char, CStr, CString},
+    ops::{Deref, DerefMut},
     ptr::{slice_from_raw_parts_mut, NonNull},
 };
 
@@ -462,6 +464,262 @@ impl Mesh {
         Mesh(NonNull::new(unsafe { mesh_gen_cylinder(diameter, depth, direction.into(), subdivisions) }).unwrap())
     }
 
+    /// Non canonical function generating a capsule mesh: two hemispheres joined by a cylinder, with smooth normals
+    /// all along the surface. UV coordinates go around the capsule on U, and from the bottom tip to the top tip on V.
+    /// This is a good visual for a physics proxy or a character.
+    ///
+    /// NOTE: This generates a completely new Mesh asset on the GPU, and is best done during 'initialization' of your
+    /// app/scene.
+    /// * radius - The radius of the hemispheres and of the cylinder, in meters.
+    /// * height - The total length of the capsule from one tip to the other, in meters. It can't be less than
+    ///   2*radius.
+    /// * direction - The direction from the bottom tip to the top tip, it does not need to be normalized.
+    /// * segments - How many vertices compose the circumference of the capsule? Clamps to a minimum of 3. More is
+    ///   smoother, but less performant. None is 16.
+    ///
+    /// see also [`Mesh::generate_cylinder`] [`Mesh::generate_sphere`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, maths::{Matrix, Vec3}, mesh::Mesh};
+    ///
+    /// let capsule = Mesh::generate_capsule(0.15, 0.8, Vec3::Y, None);
+    /// let bounds = capsule.get_bounds();
+    /// assert!((bounds.dimensions.y - 0.8).abs() < 0.001);
+    /// assert!((bounds.dimensions.x - 0.3).abs() < 0.001);
+    /// let lying = Mesh::generate_capsule(0.1, 0.6, [1.0, 1.0, 0.0], Some(24));
+    /// let material = Material::pbr();
+    ///
+    /// filename_scr = "screenshots/generate_capsule.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     capsule.draw(token, &material, Matrix::t(Vec3::X * -0.2), None, None);
+    ///     lying.draw(token, &material, Matrix::t(Vec3::X * 0.25), None, None);
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/generate_capsule.jpeg" alt="screenshot" width="200">
+    pub fn generate_capsule(radius: f32, height: f32, direction: impl Into<Vec3>, segments: Option<i32>) -> Mesh {
+        let segments = segments.unwrap_or(16).max(3) as u32;
+        let rings = (segments / 4).max(2);
+        let half_cylinder = (height / 2.0 - radius).max(0.0);
+
+        let direction = direction.into();
+        let axis = if direction.length_sq() > 0.0 { direction.get_normalized() } else { Vec3::UP };
+        let helper = if axis.x.abs() < 0.9 { Vec3::X } else { Vec3::Y };
+        let right = Vec3::cross(helper, axis).get_normalized();
+        let forward = Vec3::cross(axis, right);
+
+        // The profile from the bottom tip to the top tip: (position along the axis, angle from the equator).
+        let quarter = std::f32::consts::FRAC_PI_2;
+        let mut profile = vec![];
+        for ring in 0..=rings {
+            profile.push((-half_cylinder, -quarter + ring as f32 * quarter / rings as f32));
+        }
+        for ring in 0..=rings {
+            profile.push((half_cylinder, ring as f32 * quarter / rings as f32));
+        }
+        let total_length = std::f32::consts::PI * radius + 2.0 * half_cylinder;
+
+        let mut vertices = vec![];
+        for (center, angle) in &profile {
+            let (axial, radial) = angle.sin_cos();
+            let length = radius * (angle + quarter) + center + half_cylinder;
+            let v = if total_length > 0.0 { length / total_length } else { 0.0 };
+            for segment in 0..=segments {
+                let u = segment as f32 / segments as f32;
+                let (sin, cos) = (u * std::f32::consts::TAU).sin_cos();
+                let normal = (right * cos + forward * sin) * radial + axis * axial;
+                let position = axis * *center + normal * radius;
+                vertices.push(Vertex::new(position, normal, Some(Vec2::new(u, v)), None));
+            }
+        }
+
+        let mut indices = vec![];
+        let row = segments + 1;
+        for step in 0..profile.len() as u32 - 1 {
+            for segment in 0..segments {
+                let a = step * row + segment;
+                let (b, c, d) = (a + 1, a + row, a + row + 1);
+                indices.extend_from_slice(&[a, c, b, b, c, d]);
+            }
+        }
+
+        let mut mesh = Mesh::new();
+        mesh.set_data(&vertices, &indices, true);
+        mesh
+    }
+
+    /// Non canonical function generating a plane on the XZ axis facing up, made of a regular grid of quads, pre-sized
+    /// to the given dimensions. UV coordinates start at 0,0 at the -X,-Z corner, and go to 1,1 at the +X,+Z corner,
+    /// like [`Mesh::generate_plane_up`], but the subdivisions may differ on each axis. This is useful for terrains or
+    /// cloths.
+    ///
+    /// NOTE: This generates a completely new Mesh asset on the GPU, and is best done during 'initialization' of your
+    /// app/scene.
+    /// * size - How large is this plane on the X and Z axis, in meters?
+    /// * subdivisions - How many extra slices of vertices across the X and Z axis? 0 gives a single quad.
+    ///
+    /// see also [`Mesh::generate_plane_up`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, maths::{Matrix, Quat, Vec2, Vec2i}, mesh::Mesh};
+    ///
+    /// let quad = Mesh::generate_plane_grid(Vec2::ONE, Vec2i::ZERO);
+    /// assert_eq!(quad.get_vert_count(), 4);
+    /// assert_eq!(quad.get_ind_count(), 6);
+    ///
+    /// let grid = Mesh::generate_plane_grid(Vec2::new(0.8, 0.6), Vec2i::new(2, 2));
+    /// assert_eq!(grid.get_vert_count(), 16);
+    /// assert_eq!(grid.get_ind_count(), 9 * 6);
+    ///
+    /// let mut wireframe = Material::pbr().copy();
+    /// wireframe.wireframe(true);
+    ///
+    /// filename_scr = "screenshots/generate_plane_grid.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     grid.draw(token, &wireframe, Matrix::r(Quat::from_angles(60.0, 0.0, 0.0)), None, None);
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/generate_plane_grid.jpeg" alt="screenshot" width="200">
+    pub fn generate_plane_grid(size: impl Into<Vec2>, subdivisions: impl Into<Vec2i>) -> Mesh {
+        let size = size.into();
+        let subdivisions = subdivisions.into();
+        let columns = subdivisions.x.max(0) as u32 + 1;
+        let rows = subdivisions.y.max(0) as u32 + 1;
+
+        let mut vertices = vec![];
+        for z in 0..=rows {
+            for x in 0..=columns {
+                let uv = Vec2::new(x as f32 / columns as f32, z as f32 / rows as f32);
+                let position = Vec3::new((uv.x - 0.5) * size.x, 0.0, (uv.y - 0.5) * size.y);
+                vertices.push(Vertex::new(position, Vec3::UP, Some(uv), None));
+            }
+        }
+
+        let mut indices = vec![];
+        for z in 0..rows {
+            for x in 0..columns {
+                let a = z * (columns + 1) + x;
+                let (b, c, d) = (a + 1, a + columns + 1, a + columns + 2);
+                indices.extend_from_slice(&[a, b, c, b, d, c]);
+            }
+        }
+
+        let mut mesh = Mesh::new();
+        mesh.set_data(&vertices, &indices, true);
+        mesh
+    }
+
+    /// Non canonical function generating a terrain from a grid of heights. The grid is centered on the XZ plane, each
+    /// height is placed on the Y axis, and the normals follow the slopes between the neighbouring heights. UV
+    /// coordinates start at 0,0 at the -X,-Z corner, and go to 1,1 at the +X,+Z corner.
+    /// * heights - The heights row by row, from -Z to +Z, each row going from -X to +X.
+    /// * width - The number of heights on the X axis, from 2 to [`MAX_HEIGHTMAP_SIZE`].
+    /// * height - The number of heights on the Z axis, from 2 to [`MAX_HEIGHTMAP_SIZE`].
+    /// * cell_size - The distance between two heights on the X and Z axis, in meters.
+    /// * height_scale - The factor applied to each height.
+    ///
+    /// Returns StereoKitError::MeshGen if the dimensions are out of range or don't match the number of heights.
+    ///
+    /// see also [`Mesh::generate_plane_grid`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, maths::{Matrix, Quat, Vec2, Vec3}, mesh::Mesh};
+    ///
+    /// let flat = Mesh::from_heightmap(&[0.5; 16], 4, 4, Vec2::new(0.1, 0.1), 1.0).expect("a valid heightmap");
+    /// assert_eq!(flat.get_vert_count(), 16);
+    /// assert_eq!(flat.get_ind_count(), 9 * 6);
+    /// for vertex in flat.get_verts() {
+    ///     assert!(Vec3::distance(vertex.norm, Vec3::UP) < 0.0001);
+    ///     assert!((vertex.pos.y - 0.5).abs() < 0.0001);
+    /// }
+    ///
+    /// // Heights rising along +X tilt the normals toward -X.
+    /// let heights: Vec<f32> = (0..16).map(|i| (i % 4) as f32).collect();
+    /// let sloped = Mesh::from_heightmap(&heights, 4, 4, Vec2::new(0.1, 0.1), 0.1).expect("a valid heightmap");
+    /// for vertex in sloped.get_verts() {
+    ///     assert!(Vec3::distance(vertex.norm, Vec3::new(-1.0, 1.0, 0.0).get_normalized()) < 0.0001);
+    /// }
+    ///
+    /// assert!(Mesh::from_heightmap(&heights, 4, 3, Vec2::ONE, 1.0).is_err());
+    /// assert!(Mesh::from_heightmap(&[0.0; 4], 1, 4, Vec2::ONE, 1.0).is_err());
+    ///
+    /// let heights: Vec<f32> = (0..32 * 32)
+    ///     .map(|i| ((i % 32) as f32 * 0.4).sin() * ((i / 32) as f32 * 0.3).cos())
+    ///     .collect();
+    /// let terrain = Mesh::from_heightmap(&heights, 32, 32, Vec2::new(0.03, 0.03), 0.1).expect("a valid heightmap");
+    /// let material = Material::pbr();
+    ///
+    /// filename_scr = "screenshots/from_heightmap.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     terrain.draw(token, &material, Matrix::r(Quat::from_angles(40.0, 0.0, 0.0)), None, None);
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/from_heightmap.jpeg" alt="screenshot" width="200">
+    pub fn from_heightmap(
+        heights: &[f32],
+        width: usize,
+        height: usize,
+        cell_size: impl Into<Vec2>,
+        height_scale: f32,
+    ) -> Result<Mesh, StereoKitError> {
+        let cell_size = cell_size.into();
+        if !(2..=MAX_HEIGHTMAP_SIZE).contains(&width) || !(2..=MAX_HEIGHTMAP_SIZE).contains(&height) {
+            return Err(StereoKitError::MeshGen(format!(
+                "heightmap of {width}x{height}, each side must be from 2 to {MAX_HEIGHTMAP_SIZE}"
+            )));
+        }
+        if heights.len() != width * height {
+            return Err(StereoKitError::MeshGen(format!(
+                "heightmap of {}x{} with {} heights instead of {}",
+                width,
+                height,
+                heights.len(),
+                width * height
+            )));
+        }
+
+        let at = |x: usize, z: usize| heights[z * width + x] * height_scale;
+        let mut vertices = Vec::with_capacity(width * height);
+        for z in 0..height {
+            for x in 0..width {
+                let uv = Vec2::new(x as f32 / (width - 1) as f32, z as f32 / (height - 1) as f32);
+                let position = Vec3::new(
+                    (x as f32 - (width - 1) as f32 / 2.0) * cell_size.x,
+                    at(x, z),
+                    (z as f32 - (height - 1) as f32 / 2.0) * cell_size.y,
+                );
+
+                // Central differences, one sided on the borders.
+                let (x0, x1) = (x.saturating_sub(1), (x + 1).min(width - 1));
+                let (z0, z1) = (z.saturating_sub(1), (z + 1).min(height - 1));
+                let slope_x = (at(x1, z) - at(x0, z)) / ((x1 - x0) as f32 * cell_size.x);
+                let slope_z = (at(x, z1) - at(x, z0)) / ((z1 - z0) as f32 * cell_size.y);
+                let normal = Vec3::new(-slope_x, 1.0, -slope_z).get_normalized();
+
+                vertices.push(Vertex::new(position, normal, Some(uv), None));
+            }
+        }
+
+        let (columns, rows) = (width as u32 - 1, height as u32 - 1);
+        let mut indices = Vec::with_capacity((columns * rows * 6) as usize);
+        for z in 0..rows {
+            for x in 0..columns {
+                let a = z * (columns + 1) + x;
+                let (b, c, d) = (a + 1, a + columns + 1, a + columns + 2);
+                indices.extend_from_slice(&[a, b, c, b, d, c]);
+            }
+        }
+
+        let mut mesh = Mesh::new();
+        mesh.set_data(&vertices, &indices, true);
+        Ok(mesh)
+    }
+
     /// Finds the Mesh with the matching id, and returns a reference to it. If no Mesh is found, it returns
     /// StereoKitError::MeshFind.
     /// <https://stereokit.net/Pages/StereoKit/Mesh/Find.html>
@@ -506,6 +764,68 @@ impl Mesh {
         self
     }
 
+    /// Non canonical function computing a tangent for each vertex from the positions, the UVs and the normals, with
+    /// Lengyel's method, and storing them in the vertex data of the Mesh. As [`Vertex`] matches the vertex layout of
+    /// StereoKit and has no room for another field, the tangents replace the vertex colors: `rgb` is the tangent
+    /// remapped from -1..1 to 0..1 and `a` is 1 for a right handed bitangent, 0 for a left handed one. A normal mapping
+    /// shader reads them back with `tangent = float4(color.rgb * 2 - 1, color.a > 0.5 ? 1 : -1)`. The Mesh must keep
+    /// its data on the CPU.
+    ///
+    /// Returns the tangents at full precision, `w` being the handedness (1 or -1) of the bitangent
+    /// `cross(normal, tangent) * w`, or an error if there is no data or no UVs.
+    /// see also [`Mesh::get_verts`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Vec2, Vec3}, mesh::{Mesh, Vertex}};
+    ///
+    /// // A plane facing up, the U coordinate growing along X.
+    /// let vertices = [
+    ///     Vertex::new(Vec3::new(-0.5, 0.0, -0.5), Vec3::UP, Some(Vec2::new(0.0, 0.0)), None),
+    ///     Vertex::new(Vec3::new(0.5, 0.0, -0.5), Vec3::UP, Some(Vec2::new(1.0, 0.0)), None),
+    ///     Vertex::new(Vec3::new(0.5, 0.0, 0.5), Vec3::UP, Some(Vec2::new(1.0, 1.0)), None),
+    ///     Vertex::new(Vec3::new(-0.5, 0.0, 0.5), Vec3::UP, Some(Vec2::new(0.0, 1.0)), None),
+    /// ];
+    /// let mut plane = Mesh::new();
+    /// plane.keep_data(true).set_data(&vertices, &[0, 2, 1, 0, 3, 2], true);
+    ///
+    /// let tangents = plane.compute_tangents().expect("the plane has UVs");
+    /// assert_eq!(tangents.len(), 4);
+    /// for tangent in tangents {
+    ///     assert!(Vec3::distance(Vec3::new(tangent.x, tangent.y, tangent.z), Vec3::X) < 0.0001);
+    ///     assert_eq!(tangent.w.abs(), 1.0);
+    /// }
+    /// // The tangents are stored in the vertex colors, the shader reads +X.
+    /// for vertex in plane.get_verts() {
+    ///     let color = Vec3::new(vertex.col.r as f32, vertex.col.g as f32, vertex.col.b as f32) / 255.0;
+    ///     let tangent = color * 2.0 - Vec3::ONE;
+    ///     assert!(Vec3::distance(tangent, Vec3::X) < 0.01);
+    /// }
+    ///
+    /// // Without UVs, there is no tangent space.
+    /// let no_uvs: Vec<Vertex> = vertices.iter().map(|vertex| Vertex::new(vertex.pos, vertex.norm, None, None)).collect();
+    /// plane.set_data(&no_uvs, &[0, 2, 1, 0, 3, 2], true);
+    /// assert!(plane.compute_tangents().is_err());
+    /// ```
+    pub fn compute_tangents(&mut self) -> Result<Vec<Vec4>, StereoKitError> {
+        if !self.get_keep_data() || self.get_ind_count() == 0 {
+            return Err(StereoKitError::MeshGen(format!("tangents of {} that doesn't keep its data", self.get_id())));
+        }
+        let mut vertices = self.get_verts_copy();
+        if vertices.iter().all(|vertex| vertex.uv == vertices[0].uv) {
+            return Err(StereoKitError::MeshGen(format!("tangents of {} that has no UVs", self.get_id())));
+        }
+        let tangents = lengyel_tangents(&vertices, self.get_inds());
+        let to_u8 = |value: f32| ((value * 0.5 + 0.5).clamp(0.0, 1.0) * 255.0).round() as u8;
+        for (vertex, tangent) in vertices.iter_mut().zip(&tangents) {
+            let handedness = if tangent.w > 0.0 { 255 } else { 0 };
+            vertex.col = Color32::new(to_u8(tangent.x), to_u8(tangent.y), to_u8(tangent.z), handedness);
+        }
+        self.set_verts(&vertices, false);
+        Ok(tangents)
+    }
+
     /// Should StereoKit keep the mesh data on the CPU for later access, or collision detection? Defaults to true. If you
     /// set this to false before setting data, the data won't be stored. If you call this after setting data, that
     /// stored data will be freed! If you set this to true again later on, it will not contain data until it's set again.
@@ -587,6 +907,47 @@ impl Mesh {
         self
     }
 
+    /// Non canonical function giving a mutable access to the vertices of this Mesh, uploaded to the graphics card when
+    /// the guard is dropped. StereoKit doesn't map its GPU buffers, so the guard writes directly in the CPU copy of
+    /// the vertices, which avoids the copies of [`Mesh::get_verts_copy`] and [`Mesh::set_verts`]. The bounds of the
+    /// Mesh are updated when the guard is dropped.
+    ///
+    /// Returns None if the Mesh doesn't keep its data (see [`Mesh::keep_data`]), as there are no vertices on the CPU to
+    /// edit.
+    /// see also [`Mesh::set_verts`] [`Mesh::get_verts`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Vec2, Vec3}, mesh::Mesh};
+    ///
+    /// let mut ribbon = Mesh::generate_plane_up(Vec2::ONE, None, false);
+    /// ribbon.keep_data(true);
+    /// let count = ribbon.get_vert_count() as usize;
+    /// {
+    ///     let mut verts = ribbon.map_verts().expect("the ribbon keeps its data");
+    ///     assert_eq!(verts.len(), count);
+    ///     verts[0].pos = Vec3::new(0.0, 2.0, 0.0);
+    /// }
+    /// assert_eq!(ribbon.get_verts()[0].pos, Vec3::new(0.0, 2.0, 0.0));
+    /// assert!(ribbon.get_bounds().dimensions.y > 1.9);
+    ///
+    /// // Without data on the CPU, there is nothing to write.
+    /// ribbon.keep_data(false);
+    /// assert!(ribbon.map_verts().is_none());
+    /// ```
+    pub fn map_verts(&mut self) -> Option<VertsGuard<'_>> {
+        if !self.get_keep_data() {
+            return None;
+        }
+        // The pointer comes straight from StereoKit, so writing through it doesn't go through a shared slice.
+        let mut data: *mut Vertex = std::ptr::null_mut();
+        let mut len = 0;
+        unsafe { mesh_get_verts(self.0.as_ptr(), &mut data, &mut len, Memory::Reference) };
+        let len = if data.is_null() { 0 } else { len.max(0) as usize };
+        Some(VertsGuard { mesh: self, data, len })
+    }
+
     /// Adds a mesh to the render queue for this frame! If the Hierarchy has a transform on it, that transform is
     /// combined with the Matrix provided here.
     /// <https://stereokit.net/Pages/StereoKit/Mesh/Draw.html>
@@ -632,6 +993,98 @@ impl Mesh {
         unsafe { mesh_get_bounds(self.0.as_ptr()) }
     }
 
+    /// Non canonical function giving a sphere containing the Mesh, for fast distance based culling or level of detail.
+    /// If the Mesh keeps its data on the CPU, the sphere is computed from the vertices, which is usually tighter than
+    /// the bounds. It is computed each time, so it follows the changes of the vertices.
+    ///
+    /// see also [`Mesh::get_bounds`] [`crate::maths::Sphere::from_points`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec3, mesh::Mesh};
+    ///
+    /// let sphere = Mesh::generate_sphere(1.0, None).get_bounding_sphere();
+    /// assert!(Vec3::distance(sphere.center, Vec3::ZERO) < 0.01);
+    /// assert!((sphere.radius - 0.5).abs() < 0.025);
+    ///
+    /// let cube = Mesh::generate_cube([1.0, 1.0, 1.0], None).get_bounding_sphere();
+    /// assert!((cube.radius - 3.0f32.sqrt() / 2.0).abs() < 0.025);
+    /// ```
+    pub fn get_bounding_sphere(&self) -> Sphere {
+        let from_bounds = Sphere::from_bounds(self.get_bounds());
+        if !self.get_keep_data() || self.get_vert_count() == 0 {
+            return from_bounds;
+        }
+        let points: Vec<Vec3> = self.get_verts().iter().map(|vertex| vertex.pos).collect();
+        let from_points = Sphere::from_points(&points);
+        if from_points.radius < from_bounds.radius {
+            from_points
+        } else {
+            from_bounds
+        }
+    }
+
+    /// Non canonical function creating a smoother Mesh by splitting each triangle into four, `iterations` times. The
+    /// new vertices in the middle of the edges shared by two triangles are pulled toward the opposite vertices, like
+    /// in Loop subdivision, while the original vertices stay in place. UVs and colors are interpolated and the normals
+    /// are recomputed. The Mesh must keep its data on the CPU.
+    /// * iterations - The number of subdivisions, each one multiplying the triangle count by 4. At most
+    ///   [`MAX_SUBDIVISION_ITERATIONS`].
+    ///
+    /// Returns a new Mesh keeping its data, or an error if there are too many iterations or no data to subdivide.
+    /// see also [`Mesh::set_data`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec3, mesh::{Mesh, Vertex}};
+    ///
+    /// let vertices = [
+    ///     Vertex::new(Vec3::new(1.0, 1.0, 1.0), Vec3::ZERO, None, None),
+    ///     Vertex::new(Vec3::new(1.0, -1.0, -1.0), Vec3::ZERO, None, None),
+    ///     Vertex::new(Vec3::new(-1.0, 1.0, -1.0), Vec3::ZERO, None, None),
+    ///     Vertex::new(Vec3::new(-1.0, -1.0, 1.0), Vec3::ZERO, None, None),
+    /// ];
+    /// let indices = [0, 1, 2, 0, 3, 1, 0, 2, 3, 1, 3, 2];
+    /// let mut tetrahedron = Mesh::new();
+    /// tetrahedron.keep_data(true).set_data(&vertices, &indices, true);
+    ///
+    /// let smoother = tetrahedron.subdivide(1).expect("one iteration is allowed");
+    /// assert_eq!(smoother.get_ind_count(), tetrahedron.get_ind_count() * 4);
+    /// // 4 vertices and one more for each of the 6 edges.
+    /// assert_eq!(smoother.get_vert_count(), 10);
+    ///
+    /// assert_eq!(tetrahedron.subdivide(2).unwrap().get_ind_count(), 12 * 16);
+    /// assert!(tetrahedron.subdivide(5).is_err());
+    /// ```
+    pub fn subdivide(&self, iterations: u32) -> Result<Mesh, StereoKitError> {
+        if iterations > MAX_SUBDIVISION_ITERATIONS {
+            return Err(StereoKitError::MeshGen(format!(
+                "subdivision of {} with {} iterations, the maximum is {}",
+                self.get_id(),
+                iterations,
+                MAX_SUBDIVISION_ITERATIONS
+            )));
+        }
+        if !self.get_keep_data() || self.get_ind_count() == 0 {
+            return Err(StereoKitError::MeshGen(format!(
+                "subdivision of {} that doesn't keep its data",
+                self.get_id()
+            )));
+        }
+        let mut vertices = self.get_verts_copy();
+        let mut indices = self.get_inds_copy();
+        for _ in 0..iterations {
+            (vertices, indices) = subdivide_triangles(&vertices, &indices);
+        }
+        recompute_normals(&mut vertices, &indices);
+
+        let mut mesh = Mesh::new();
+        mesh.keep_data(true).set_data(&vertices, &indices, true);
+        Ok(mesh)
+    }
+
     /// Should StereoKit keep the mesh data on the CPU for later access, or collision detection? Defaults to true. If you
     /// set this to false before setting data, the data won't be stored. If you call this after setting data, that
     /// stored data will be freed! If you set this to true again later on, it will not contain data until it's set again.
@@ -824,3 +1277,144 @@ impl Mesh {
         Mesh::find("default/mesh_righthand").unwrap()
     }
 }
+
+/// The vertices of a Mesh given by [`Mesh::map_verts`], uploaded to the graphics card when this guard is dropped.
+pub struct VertsGuard<'a> {
+    mesh: &'a mut Mesh,
+    data: *mut Vertex,
+    len: usize,
+}
+
+impl Deref for VertsGuard<'_> {
+    type Target = [Vertex];
+
+    fn deref(&self) -> &[Vertex] {
+        match self.len {
+            0 => &[],
+            len => unsafe { std::slice::from_raw_parts(self.data, len) },
+        }
+    }
+}
+
+impl DerefMut for VertsGuard<'_> {
+    fn deref_mut(&mut self) -> &mut [Vertex] {
+        match self.len {
+            0 => &mut [],
+            len => unsafe { std::slice::from_raw_parts_mut(self.data, len) },
+        }
+    }
+}
+
+impl Drop for VertsGuard<'_> {
+    fn drop(&mut self) {
+        if self.len > 0 {
+            unsafe { mesh_set_verts(self.mesh.0.as_ptr(), self.data, self.len as i32, 1) }
+        }
+    }
+}
+
+/// The maximum number of iterations of [`Mesh::subdivide`], each one multiplies the triangle count by 4.
+pub const MAX_SUBDIVISION_ITERATIONS: u32 = 4;
+
+/// The maximum number of heights on each side of [`Mesh::from_heightmap`].
+pub const MAX_HEIGHTMAP_SIZE: usize = 4096;
+
+/// Split each triangle into four. The vertices in the middle of an edge shared by two triangles get the Loop
+/// subdivision weights, 3/8 for the edge ends and 1/8 for the opposite vertices.
+fn subdivide_triangles(vertices: &[Vertex], indices: &[u32]) -> (Vec<Vertex>, Vec<u32>) {
+    let edge = |a: u32, b: u32| (a.min(b), a.max(b));
+    let mut opposites: HashMap<(u32, u32), Vec<u32>> = HashMap::new();
+    for triangle in indices.chunks_exact(3) {
+        for k in 0..3 {
+            let (a, b, c) = (triangle[k], triangle[(k + 1) % 3], triangle[(k + 2) % 3]);
+            opposites.entry(edge(a, b)).or_default().push(c);
+        }
+    }
+
+    let mut new_vertices = vertices.to_vec();
+    let mut new_indices = Vec::with_capacity(indices.len() * 4);
+    let mut middles: HashMap<(u32, u32), u32> = HashMap::new();
+    for triangle in indices.chunks_exact(3) {
+        let mut middle = [0u32; 3];
+        for k in 0..3 {
+            let (a, b) = (triangle[k], triangle[(k + 1) % 3]);
+            middle[k] = *middles.entry(edge(a, b)).or_insert_with(|| {
+                let (va, vb) = (vertices[a as usize], vertices[b as usize]);
+                let pos = match opposites[&edge(a, b)][..] {
+                    [c, d] => (va.pos + vb.pos) * 0.375 + (vertices[c as usize].pos + vertices[d as usize].pos) * 0.125,
+                    _ => (va.pos + vb.pos) * 0.5,
+                };
+                let mix = |x: u8, y: u8| ((x as u16 + y as u16) / 2) as u8;
+                let col = Color32::new(
+                    mix(va.col.r, vb.col.r),
+                    mix(va.col.g, vb.col.g),
+                    mix(va.col.b, vb.col.b),
+                    mix(va.col.a, vb.col.a),
+                );
+                new_vertices.push(Vertex { pos, norm: va.norm + vb.norm, uv: (va.uv + vb.uv) * 0.5, col });
+                (new_vertices.len() - 1) as u32
+            });
+        }
+        let (a, b, c) = (triangle[0], triangle[1], triangle[2]);
+        let [ab, bc, ca] = middle;
+        new_indices.extend_from_slice(&[a, ab, ca, ab, b, bc, ca, bc, c, ab, bc, ca]);
+    }
+    (new_vertices, new_indices)
+}
+
+/// Set the normals to the area weighted average of the normals of the triangles around each vertex. The normals are
+/// flipped if they mostly disagree with the previous ones, so the winding order of the mesh doesn't matter.
+fn recompute_normals(vertices: &mut [Vertex], indices: &[u32]) {
+    let mut normals = vec![Vec3::ZERO; vertices.len()];
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize];
+        let normal = Vec3::cross(vertices[b].pos - vertices[a].pos, vertices[c].pos - vertices[a].pos);
+        for index in [a, b, c] {
+            normals[index] += normal;
+        }
+    }
+    let agreement: f32 = vertices.iter().zip(&normals).map(|(vertex, normal)| Vec3::dot(vertex.norm, *normal)).sum();
+    let sign = if agreement < 0.0 { -1.0 } else { 1.0 };
+    for (vertex, normal) in vertices.iter_mut().zip(normals) {
+        if normal.length_sq() > 0.0 {
+            vertex.norm = normal.get_normalized() * sign;
+        }
+    }
+}
+
+/// Lengyel's tangents: the U and V directions of the triangles around each vertex are summed, then the U direction is
+/// orthogonalized against the normal. Vertices without a usable U direction get a tangent orthogonal to their normal.
+fn lengyel_tangents(vertices: &[Vertex], indices: &[u32]) -> Vec<Vec4> {
+    let mut u_dirs = vec![Vec3::ZERO; vertices.len()];
+    let mut v_dirs = vec![Vec3::ZERO; vertices.len()];
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize];
+        let (edge1, edge2) = (vertices[b].pos - vertices[a].pos, vertices[c].pos - vertices[a].pos);
+        let (duv1, duv2) = (vertices[b].uv - vertices[a].uv, vertices[c].uv - vertices[a].uv);
+        let det = duv1.x * duv2.y - duv2.x * duv1.y;
+        if det.abs() < f32::EPSILON {
+            continue;
+        }
+        let u_dir = (edge1 * duv2.y - edge2 * duv1.y) / det;
+        let v_dir = (edge2 * duv1.x - edge1 * duv2.x) / det;
+        for index in [a, b, c] {
+            u_dirs[index] += u_dir;
+            v_dirs[index] += v_dir;
+        }
+    }
+    vertices
+        .iter()
+        .zip(u_dirs.iter().zip(&v_dirs))
+        .map(|(vertex, (u_dir, v_dir))| {
+            let normal = vertex.norm.get_normalized();
+            let mut tangent = *u_dir - normal * Vec3::dot(normal, *u_dir);
+            if tangent.length_sq() < f32::EPSILON {
+                let side = if normal.x.abs() < 0.9 { Vec3::X } else { Vec3::Y };
+                tangent = side - normal * Vec3::dot(normal, side);
+            }
+            let tangent = tangent.get_normalized();
+            let w = if Vec3::dot(Vec3::cross(normal, tangent), *v_dir) < 0.0 { -1.0 } else { 1.0 };
+            Vec4::new(tangent.x, tangent.y, tangent.z, w)
+        })
+        .collect()
+}
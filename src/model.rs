@@ -1,20 +1,26 @@
 use crate::maths::{Bool32T, Matrix};
 use crate::sk::MainThreadToken;
 use crate::{
-    material::{Cull, Material, MaterialT},
-    maths::{Bounds, Ray, Vec3},
+    material::{material_has_param, Cull, Material, MaterialParam, MaterialT},
+    maths::{Bounds, Ray, Sphere, Vec3, Vec4},
     mesh::{Mesh, MeshT},
     shader::{Shader, ShaderT},
     system::{IAsset, Log, RenderLayer},
-    util::Color128,
+    util::{Color128, Time},
     StereoKitError,
 };
 use std::{
+    cell::RefCell,
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap, HashSet},
     ffi::{c_char, c_void, CStr, CString},
+    hash::{Hash, Hasher},
     path::Path,
     ptr::{null_mut, NonNull},
 };
 
+/// The file extensions [`Model::from_memory_with_format`] can load.
+pub const MODEL_FORMATS: [&str; 5] = ["obj", "stl", "ply", "gltf", "glb"];
+
 /// A Model is a collection of meshes, materials, and transforms that make up a visual element! This is a great way to
 /// group together complex objects that have multiple parts in them, and in fact, most model formats are composed this
 /// way already!
@@ -134,6 +140,117 @@ impl IAsset for Model {
     }
 }
 
+/// Non canonical structure giving the values that override the materials of a Model for a single draw call, like a
+/// Unity MaterialPropertyBlock. The color tint is given to the draw call itself. The named values are only applied to
+/// the materials having a parameter with this name and type, the others ignore them.
+///
+/// StereoKit has no per draw material parameters, so a material using named values is drawn with a copy made the
+/// first time these values are met, then reused for the next draws with the same values. A copy that was not drawn
+/// during the last frame is released, so animated values don't pile up copies.
+///
+/// see also [`Model::draw_with_overrides`]
+#[derive(Debug, Default, Clone)]
+pub struct MaterialOverrides {
+    /// The color multiplied with the materials, in linear space. None keeps the materials unchanged.
+    pub color_tint: Option<Color128>,
+    /// The float parameters to override, by name.
+    pub floats: BTreeMap<String, f32>,
+    /// The vector4 or color parameters to override, by name.
+    pub vec4s: BTreeMap<String, Vec4>,
+}
+
+/// The copies of the materials drawn with [`MaterialOverrides`], by source material and hash of the values.
+#[derive(Default)]
+struct MaterialOverrideCopies {
+    frame: u64,
+    copies: HashMap<(usize, u64), MaterialOverrideCopy>,
+}
+
+struct MaterialOverrideCopy {
+    /// Kept so the address of the source can't be reused by another material while the copy is cached.
+    _source: Material,
+    copy: Material,
+    last_frame: u64,
+}
+
+thread_local! {
+    static MATERIAL_OVERRIDES: RefCell<MaterialOverrideCopies> = RefCell::new(MaterialOverrideCopies::default());
+}
+
+impl MaterialOverrides {
+    /// Overrides nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the color tint of the draw call.
+    pub fn color_tint(&mut self, color_linear: impl Into<Color128>) -> &mut Self {
+        self.color_tint = Some(color_linear.into());
+        self
+    }
+
+    /// Override the float parameter `name`.
+    pub fn float(&mut self, name: impl AsRef<str>, value: f32) -> &mut Self {
+        self.floats.insert(name.as_ref().to_string(), value);
+        self
+    }
+
+    /// Override the vector4 or color parameter `name`.
+    pub fn vec4(&mut self, name: impl AsRef<str>, value: impl Into<Vec4>) -> &mut Self {
+        self.vec4s.insert(name.as_ref().to_string(), value.into());
+        self
+    }
+
+    /// Get the material to use instead of `material`, which is `material` itself if none of the named values are
+    /// parameters of it.
+    fn material_for(&self, material: &Material) -> Material {
+        let has_param = |name: &str, param_type: MaterialParam| {
+            let c_str = CString::new(name).unwrap();
+            unsafe { material_has_param(material.0.as_ptr(), c_str.as_ptr(), param_type) != 0 }
+        };
+        let floats: Vec<(&String, &f32)> =
+            self.floats.iter().filter(|(name, _)| has_param(name, MaterialParam::Float)).collect();
+        let vec4s: Vec<(&String, &Vec4)> = self
+            .vec4s
+            .iter()
+            .filter(|(name, _)| has_param(name, MaterialParam::Vec4) || has_param(name, MaterialParam::Color128))
+            .collect();
+        if floats.is_empty() && vec4s.is_empty() {
+            return material.clone_ref();
+        }
+
+        let mut hasher = DefaultHasher::new();
+        for (name, value) in &floats {
+            (name, value.to_bits()).hash(&mut hasher);
+        }
+        for (name, value) in &vec4s {
+            (name, [value.x.to_bits(), value.y.to_bits(), value.z.to_bits(), value.w.to_bits()]).hash(&mut hasher);
+        }
+        let key = (material.0.as_ptr() as usize, hasher.finish());
+        let frame = Time::get_frame();
+        MATERIAL_OVERRIDES.with_borrow_mut(|cache| {
+            // Only the copies used by the previous frame may still be used.
+            if cache.frame != frame {
+                cache.frame = frame;
+                cache.copies.retain(|_, entry| entry.last_frame + 1 >= frame);
+            }
+            let entry = cache.copies.entry(key).or_insert_with(|| {
+                let copy = material.copy();
+                let mut params = copy.get_all_param_info();
+                for (name, value) in floats {
+                    params.set_float(name, *value);
+                }
+                for (name, value) in vec4s {
+                    params.set_vec4(name, *value);
+                }
+                MaterialOverrideCopy { _source: material.clone_ref(), copy, last_frame: frame }
+            });
+            entry.last_frame = frame;
+            entry.copy.clone_ref()
+        })
+    }
+}
+
 impl Default for Model {
     /// Create an empty model
     /// <https://stereokit.net/Pages/StereoKit/Model/Model.html>
@@ -184,6 +301,63 @@ impl Model {
         }
     }
 
+    /// Non canonical function loading a Model from a file stored in memory, with the format given explicitly instead
+    /// of coming from a file name. This is handy with assets read from an archive. The supported formats are
+    /// [`MODEL_FORMATS`]: obj, stl, ply (ASCII), gltf and glb. As with [`Model::from_memory`], gltf files referencing
+    /// other files won't load well.
+    /// * memory - The content of the file.
+    /// * ext - The extension of the file, with or without its leading dot. The case is ignored.
+    /// * id - The id of the new Model asset.
+    /// * shader - The shader to use for the model’s materials! If None, this will automatically determine the best
+    ///   shader available to use.
+    ///
+    /// Returns StereoKitError::Unsupported if the format is unknown, or StereoKitError::ModelFromMem if the data
+    /// doesn't match a glb file or can't be loaded.
+    ///
+    /// see also [`Model::from_memory`] [`crate::tex::Tex::from_memory_with_format`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Matrix, model::Model, StereoKitError};
+    ///
+    /// // These bytes could come from an archive.
+    /// let bytes = std::fs::read("assets/center.glb").expect("the asset should exist");
+    ///
+    /// let model = Model::from_memory_with_format(&bytes, ".GLB", "archive/center", None)
+    ///     .expect("a glb should load from memory");
+    /// assert_eq!(model.get_id(), "archive/center");
+    /// assert!(model.get_nodes().get_count() > 0);
+    ///
+    /// assert!(matches!(Model::from_memory_with_format(&bytes, "fbx", "archive/fbx", None),
+    ///                  Err(StereoKitError::Unsupported(_, _))));
+    /// assert!(Model::from_memory_with_format(b"not a glb", "glb", "archive/bad", None).is_err());
+    ///
+    /// filename_scr = "screenshots/model_from_memory_with_format.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     model.draw(token, Matrix::IDENTITY, None, None);
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/model_from_memory_with_format.jpeg" alt="screenshot" width="200">
+    pub fn from_memory_with_format(
+        memory: &[u8],
+        ext: impl AsRef<str>,
+        id: impl AsRef<str>,
+        shader: Option<Shader>,
+    ) -> Result<Model, StereoKitError> {
+        let ext = ext.as_ref().trim_start_matches('.').to_lowercase();
+        if !MODEL_FORMATS.contains(&ext.as_str()) {
+            return Err(StereoKitError::Unsupported(id.as_ref().to_owned(), ext));
+        }
+        if ext == "glb" && !memory.starts_with(b"glTF") {
+            return Err(StereoKitError::ModelFromMem(id.as_ref().to_owned(), "not a glb file".to_owned()));
+        }
+        let file_name = format!("{}.{}", id.as_ref(), ext);
+        let mut model = Self::from_memory(&file_name, memory, shader)?;
+        model.id(id);
+        Ok(model)
+    }
+
     /// Loads a list of mesh and material subsets from a .obj, .stl, .ply (ASCII), .gltf, or .glb file.
     ///
     /// **Important**: The model is loaded only once. If you open the same file a second time, it will return the model
@@ -325,6 +499,68 @@ impl Model {
         };
     }
 
+    /// Non canonical function adding the model to the render queue of this frame with some values overriding its
+    /// materials for this draw only. The same model can then be drawn several times with different tints or
+    /// parameters without modifying its materials.
+    /// * overrides - The color tint and the named values to apply, unknown parameters are ignored.
+    /// * layer - if None has default value of Layer0
+    ///
+    /// see also [`MaterialOverrides`] [`Model::draw`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, maths::{Matrix, Vec3, Vec4}, mesh::Mesh,
+    ///                      model::{MaterialOverrides, Model}, system::{AssetType, Assets}, util::named_colors};
+    ///
+    /// let model = Model::from_mesh(Mesh::generate_cube(Vec3::ONE * 0.4, None), Material::pbr());
+    ///
+    /// let mut red = MaterialOverrides::new();
+    /// red.color_tint(named_colors::RED).float("roughness", 0.1).float("not_a_param", 3.0);
+    /// let mut blue = MaterialOverrides::new();
+    /// blue.color_tint(named_colors::BLUE).vec4("tex_trans", Vec4::new(0.0, 0.0, 2.0, 2.0));
+    ///
+    /// let materials_before = Assets::all_of_type(AssetType::Material).count();
+    ///
+    /// filename_scr = "screenshots/model_draw_with_overrides.jpeg";
+    /// number_of_steps = 20;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     model.draw_with_overrides(token, Matrix::t(Vec3::X * -0.25), &red, None);
+    ///     model.draw_with_overrides(token, Matrix::t(Vec3::X * 0.25), &blue, None);
+    ///     // A value changing at each frame needs a new copy at each frame.
+    ///     let mut animated = MaterialOverrides::new();
+    ///     animated.float("roughness", iter as f32 / 20.0);
+    ///     model.draw_with_overrides(token, Matrix::ts(Vec3::Y * 0.3, Vec3::ONE * 0.2), &animated, None);
+    /// );
+    /// // Only the copies drawn by the last frames are kept: red, blue and the last animated ones.
+    /// assert!(Assets::all_of_type(AssetType::Material).count() <= materials_before + 4);
+    /// // The materials of the model are left untouched.
+    /// let material = model.get_nodes().visuals().next().unwrap().get_material().unwrap();
+    /// assert_eq!(material.get_id(), Material::pbr().get_id());
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/model_draw_with_overrides.jpeg" alt="screenshot" width="200">
+    pub fn draw_with_overrides(
+        &self,
+        token: &MainThreadToken,
+        transform: impl Into<Matrix>,
+        overrides: &MaterialOverrides,
+        layer: Option<RenderLayer>,
+    ) {
+        if overrides.floats.is_empty() && overrides.vec4s.is_empty() {
+            self.draw(token, transform, overrides.color_tint, layer);
+            return;
+        }
+        let transform = transform.into();
+        for node in self.get_nodes().visuals() {
+            if !node.get_visible() {
+                continue;
+            }
+            let (Some(mesh), Some(material)) = (node.get_mesh(), node.get_material()) else { continue };
+            let material = overrides.material_for(&material);
+            mesh.draw(token, &material, node.get_model_transform() * transform, overrides.color_tint, layer);
+        }
+    }
+
     /// Examines the visuals as they currently are, and rebuilds the bounds based on that! This is normally done automatically,
     /// but if you modify a Mesh that this Model is using, the Model can’t see it, and you should call this manually.
     /// <https://stereokit.net/Pages/StereoKit/Model/RecalculateBounds.html>
@@ -359,6 +595,43 @@ impl Model {
         unsafe { model_get_bounds(self.0.as_ptr()) }
     }
 
+    /// Non canonical function giving a sphere containing the Model, for fast distance based culling or level of
+    /// detail. The sphere is computed from the vertices of the visual nodes when their meshes keep their data, which
+    /// is usually tighter than the bounds. It is computed each time, so it follows the changes of the geometry.
+    ///
+    /// see also [`Model::get_bounds`] [`crate::mesh::Mesh::get_bounding_sphere`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, maths::Vec3, mesh::Mesh, model::Model};
+    ///
+    /// let model = Model::from_mesh(Mesh::generate_sphere(0.4, None), Material::default());
+    /// let sphere = model.get_bounding_sphere();
+    /// assert!(Vec3::distance(sphere.center, Vec3::ZERO) < 0.01);
+    /// assert!((sphere.radius - 0.2).abs() < 0.01);
+    /// // Much tighter than the sphere around the bounds.
+    /// assert!(sphere.radius < model.get_bounds().dimensions.length() / 2.0);
+    /// ```
+    pub fn get_bounding_sphere(&self) -> Sphere {
+        let from_bounds = Sphere::from_bounds(self.get_bounds());
+        let mut points: Vec<Vec3> = vec![];
+        for node in self.get_nodes().visuals() {
+            let Some(mesh) = node.get_mesh() else { continue };
+            if !mesh.get_keep_data() || mesh.get_vert_count() == 0 {
+                return from_bounds;
+            }
+            let transform = node.get_model_transform();
+            points.extend(mesh.get_verts().iter().map(|vertex| transform.transform_point(vertex.pos)));
+        }
+        let from_points = Sphere::from_points(&points);
+        if !points.is_empty() && from_points.radius < from_bounds.radius {
+            from_points
+        } else {
+            from_bounds
+        }
+    }
+
     /// Get the nodes
     /// <https://stereokit.net/Pages/StereoKit/ModelNodeCollection.html>
     ///
@@ -375,6 +648,54 @@ impl Model {
         Anims::from(self)
     }
 
+    /// Non canonical function iterating the drawable nodes of the model with their mesh and material. The nodes
+    /// without a mesh or a material are skipped.
+    ///
+    /// see also [`Nodes::visuals`] [`Model::get_mesh_count`] [`Model::get_material_count`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::model::Model;
+    ///
+    /// // 4 meshes sharing a material.
+    /// let model = Model::from_file("mobiles.gltf", None).unwrap().copy();
+    /// let visuals: Vec<_> = model.visuals().collect();
+    /// assert_eq!(visuals.len(), 4);
+    /// assert_eq!(visuals.len() as i32, model.get_nodes().get_visual_count());
+    /// for (node, mesh, material) in visuals.iter() {
+    ///     assert!(node.get_name().is_some());
+    ///     assert!(!mesh.get_id().is_empty());
+    ///     assert!(!material.get_id().is_empty());
+    /// }
+    /// assert_eq!(model.get_mesh_count(), 4);
+    /// assert_eq!(model.get_material_count(), 1);
+    ///
+    /// assert_eq!(Model::new().visuals().count(), 0);
+    /// assert_eq!(Model::new().get_mesh_count(), 0);
+    /// ```
+    pub fn visuals(&self) -> impl Iterator<Item = (ModelNode<'_>, Mesh, Material)> {
+        NodeIter::visuals_from(self).filter_map(|node| Some((node, node.get_mesh()?, node.get_material()?)))
+    }
+
+    /// Non canonical function counting the distinct meshes used by the visual nodes of the model. A mesh used by
+    /// several nodes is counted once.
+    ///
+    /// see also [`Model::visuals`]
+    pub fn get_mesh_count(&self) -> usize {
+        let ids: HashSet<String> = self.visuals().map(|(_, mesh, _)| mesh.get_id().to_string()).collect();
+        ids.len()
+    }
+
+    /// Non canonical function counting the distinct materials used by the visual nodes of the model. A material used
+    /// by several nodes is counted once.
+    ///
+    /// see also [`Model::visuals`]
+    pub fn get_material_count(&self) -> usize {
+        let ids: HashSet<String> = self.visuals().map(|(_, _, material)| material.get_id().to_string()).collect();
+        ids.len()
+    }
+
     /// Checks the intersection point of a ray and the Solid flagged Meshes in the Model’s visual nodes. Ray must
     /// be in model space, intersection point will be in model space too. You can use the inverse of the mesh’s world
     /// transform matrix to bring the ray into model space, see the example in the docs!
@@ -0,0 +1,300 @@
+use crate::system::Log;
+use std::cell::RefCell;
+#[cfg(target_os = "android")]
+use std::time::{Duration, Instant};
+
+/// The permissions an app may need to ask the user for. On Android, each permission maps to the manifest name given by
+/// [`Permission::get_android_name`], checked with `Activity.checkSelfPermission` and asked for with
+/// `Activity.requestPermissions` through JNI. On platforms without a permission system, every permission is considered
+/// as granted.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// For access to microphone data, this is typically an interactive permission that the user will need to approve.
+    Microphone,
+    /// For access to the camera of the device.
+    Camera,
+    /// For access to eye gaze data. This is typically an interactive permission that the user will need to approve.
+    EyeInput,
+    /// For access to hand tracking data.
+    HandTracking,
+    /// For access to facial expression data.
+    FaceTracking,
+    /// For access to the scene data (walls, floor, furnitures) of the room the user is in.
+    Scene,
+}
+
+/// The state of a permission, as reported by [`Permission::status`] or given to the callback of
+/// [`Permission::request_async`]. On Android, only `PackageManager.PERMISSION_GRANTED` is reported as `Granted`. A
+/// permission whose dialog was dismissed, or whose dialog never showed up because the user refused it permanently, is
+/// reported as `Denied`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PermissionState {
+    /// The user has approved this permission, or the platform doesn't need it.
+    Granted,
+    /// The permission has not been approved, or the user refused it.
+    Denied,
+}
+
+type OnPermissionsResult = Box<dyn FnMut(&[(Permission, PermissionState)])>;
+
+/// A permission request waiting for the answer of the user.
+#[cfg_attr(not(target_os = "android"), allow(dead_code))]
+struct PendingPermission {
+    permissions: Vec<Permission>,
+    on_result: OnPermissionsResult,
+    #[cfg(target_os = "android")]
+    asked_at: Instant,
+    #[cfg(target_os = "android")]
+    dialog_shown: bool,
+}
+
+thread_local! {
+    static PENDING_PERMISSIONS: RefCell<Vec<PendingPermission>> = const { RefCell::new(Vec::new()) };
+}
+
+/// If the permission dialog didn't show up after this delay, the permission is considered as denied. This happens when
+/// the user has already refused the permission permanently.
+#[cfg(target_os = "android")]
+const DIALOG_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl Permission {
+    /// The name of this permission in the Android manifest.
+    pub fn get_android_name(&self) -> &'static str {
+        match self {
+            Permission::Microphone => "android.permission.RECORD_AUDIO",
+            Permission::Camera => "android.permission.CAMERA",
+            Permission::EyeInput => "com.oculus.permission.EYE_TRACKING",
+            Permission::HandTracking => "com.oculus.permission.HAND_TRACKING",
+            Permission::FaceTracking => "com.oculus.permission.FACE_TRACKING",
+            Permission::Scene => "com.oculus.permission.USE_SCENE",
+        }
+    }
+
+    /// Non canonical function giving the current state of a permission. This can be polled while a request is
+    /// ongoing. On platforms without a permission system this is always [`PermissionState::Granted`].
+    /// * permission - The permission to check.
+    ///
+    /// see also [`Permission::request_async`]
+    pub fn status(permission: Permission) -> PermissionState {
+        #[cfg(target_os = "android")]
+        {
+            android::check_self_permission(permission)
+        }
+        #[cfg(not(target_os = "android"))]
+        {
+            let _ = permission;
+            PermissionState::Granted
+        }
+    }
+
+    /// Non canonical function asking the user for a permission. On Android, this calls `Activity.requestPermissions`
+    /// if the permission is not granted yet. As NativeActivity doesn't forward `onRequestPermissionsResult`, the answer
+    /// is polled at each step: `on_result` is called on the main thread once the permission is granted, once the app
+    /// gets the focus back after the dialog, or after 5 seconds if the dialog never showed up (permission refused
+    /// permanently). If the permission is already granted, or if the platform has no permission system, `on_result` is
+    /// called right away with [`PermissionState::Granted`].
+    /// * permission - The permission to ask for. It must be declared in the Android manifest.
+    /// * on_result - Called once with the answer of the user.
+    ///
+    /// see also [`Permission::status`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::permission::{Permission, PermissionState};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// let result = Rc::new(Cell::new(None));
+    /// let result_cb = result.clone();
+    /// Permission::request_async(Permission::Microphone, move |state| result_cb.set(Some(state)));
+    ///
+    /// // On desktop the callback is called before returning.
+    /// assert_eq!(result.get(), Some(PermissionState::Granted));
+    /// assert_eq!(Permission::status(Permission::Microphone), PermissionState::Granted);
+    /// ```
+    pub fn request_async(permission: Permission, mut on_result: impl FnMut(PermissionState) + 'static) {
+        Self::request_many(&[permission], move |results| {
+            if let Some((_, state)) = results.first() {
+                on_result(*state)
+            }
+        });
+    }
+
+    /// Non canonical function asking the user for many permissions with a single dialog. Permissions already granted
+    /// are not asked again, and duplicates are removed. `on_result` is called once, on the main thread, with the
+    /// state of each permission, in the order of `permissions`. On platforms without a permission system, all the
+    /// permissions are granted right away.
+    /// * permissions - The permissions to ask for. They must be declared in the Android manifest.
+    /// * on_result - Called once with the state of each permission.
+    ///
+    /// see also [`Permission::request_async`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::permission::{Permission, PermissionState};
+    /// use std::{cell::RefCell, rc::Rc};
+    ///
+    /// let results = Rc::new(RefCell::new(vec![]));
+    /// let results_cb = results.clone();
+    /// Permission::request_many(&[Permission::Microphone, Permission::Camera, Permission::Microphone], move |states| {
+    ///     results_cb.borrow_mut().extend_from_slice(states)
+    /// });
+    ///
+    /// assert_eq!(
+    ///     *results.borrow(),
+    ///     vec![(Permission::Microphone, PermissionState::Granted), (Permission::Camera, PermissionState::Granted)]
+    /// );
+    /// ```
+    pub fn request_many(
+        permissions: &[Permission],
+        mut on_result: impl FnMut(&[(Permission, PermissionState)]) + 'static,
+    ) {
+        let mut unique = Vec::with_capacity(permissions.len());
+        for permission in permissions {
+            if !unique.contains(permission) {
+                unique.push(*permission);
+            }
+        }
+        let to_ask: Vec<Permission> = unique
+            .iter()
+            .copied()
+            .filter(|permission| Self::status(*permission) != PermissionState::Granted)
+            .collect();
+        if to_ask.is_empty() {
+            on_result(&Self::get_states(&unique));
+            return;
+        }
+        #[cfg(target_os = "android")]
+        {
+            if !android::request_permissions(&to_ask) {
+                on_result(&Self::get_states(&unique));
+                return;
+            }
+            PENDING_PERMISSIONS.with_borrow_mut(|pending| {
+                pending.push(PendingPermission {
+                    permissions: unique,
+                    on_result: Box::new(on_result),
+                    asked_at: Instant::now(),
+                    dialog_shown: false,
+                })
+            });
+        }
+        #[cfg(not(target_os = "android"))]
+        on_result(&Self::get_states(&unique));
+    }
+
+    fn get_states(permissions: &[Permission]) -> Vec<(Permission, PermissionState)> {
+        permissions.iter().map(|permission| (*permission, Self::status(*permission))).collect()
+    }
+
+    /// Resolve the pending requests whose answer is known. This is called at each step by Sk. As NativeActivity doesn't
+    /// forward onRequestPermissionsResult, a refused permission is detected when the app gets the focus back after the
+    /// dialog.
+    pub(crate) fn check_pending(app_focused: bool) {
+        let resolved: Vec<PendingPermission> = PENDING_PERMISSIONS.with_borrow_mut(|pending| {
+            if pending.is_empty() {
+                return vec![];
+            }
+            let mut resolved = vec![];
+            let mut index = 0;
+            while index < pending.len() {
+                let request = &mut pending[index];
+                let all_granted =
+                    request.permissions.iter().all(|permission| Self::status(*permission) == PermissionState::Granted);
+                if all_granted || Self::is_refused(request, app_focused) {
+                    resolved.push(pending.remove(index));
+                } else {
+                    index += 1;
+                }
+            }
+            resolved
+        });
+        // Callbacks are called outside of the borrow, so they can ask for another permission.
+        for mut request in resolved {
+            let states = Self::get_states(&request.permissions);
+            Log::diag(format!("Permissions : {:?}", states));
+            (request.on_result)(&states);
+        }
+    }
+
+    #[cfg(target_os = "android")]
+    fn is_refused(request: &mut PendingPermission, app_focused: bool) -> bool {
+        if !app_focused {
+            request.dialog_shown = true;
+            false
+        } else {
+            request.dialog_shown || request.asked_at.elapsed() > DIALOG_TIMEOUT
+        }
+    }
+
+    #[cfg(not(target_os = "android"))]
+    fn is_refused(_request: &mut PendingPermission, _app_focused: bool) -> bool {
+        true
+    }
+}
+
+#[cfg(target_os = "android")]
+mod android {
+    use super::{Permission, PermissionState};
+    use crate::system::Log;
+    use jni::objects::{JObject, JValue};
+
+    /// PackageManager.PERMISSION_GRANTED
+    const PERMISSION_GRANTED: i32 = 0;
+    /// The request code given to Activity.requestPermissions
+    const REQUEST_CODE: i32 = 0x5c;
+
+    fn with_activity<T>(default: T, f: impl FnOnce(&mut jni::JNIEnv, &JObject) -> jni::errors::Result<T>) -> T {
+        let ctx = ndk_context::android_context();
+        let vm = match unsafe { jni::JavaVM::from_raw(ctx.vm() as _) } {
+            Ok(value) => value,
+            Err(e) => {
+                Log::err(format!("permission : no vm !! : {:?}", e));
+                return default;
+            }
+        };
+        let activity = unsafe { JObject::from_raw(ctx.context() as _) };
+        let mut env = match vm.attach_current_thread() {
+            Ok(value) => value,
+            Err(e) => {
+                Log::err(format!("permission : no env !! : {:?}", e));
+                return default;
+            }
+        };
+        match f(&mut env, &activity) {
+            Ok(value) => value,
+            Err(e) => {
+                Log::err(format!("permission : jni error !! : {:?}", e));
+                default
+            }
+        }
+    }
+
+    /// Calls `Activity.checkSelfPermission`. Any JNI error is logged and reported as [`PermissionState::Denied`].
+    pub fn check_self_permission(permission: Permission) -> PermissionState {
+        with_activity(PermissionState::Denied, |env, activity| {
+            let name = env.new_string(permission.get_android_name())?;
+            let result = env
+                .call_method(activity, "checkSelfPermission", "(Ljava/lang/String;)I", &[JValue::Object(&name)])?
+                .i()?;
+            Ok(if result == PERMISSION_GRANTED { PermissionState::Granted } else { PermissionState::Denied })
+        })
+    }
+
+    /// Calls `Activity.requestPermissions` with [`REQUEST_CODE`]. The result is not received here but polled by
+    /// `Permission::check_pending`. Returns false if the JNI call failed, so the caller can answer right away.
+    pub fn request_permissions(permissions: &[Permission]) -> bool {
+        with_activity(false, |env, activity| {
+            let string_class = env.find_class("java/lang/String")?;
+            let array = env.new_object_array(permissions.len() as i32, string_class, JObject::null())?;
+            for (index, permission) in permissions.iter().enumerate() {
+                let name = env.new_string(permission.get_android_name())?;
+                env.set_object_array_element(&array, index as i32, name)?;
+            }
+            env.call_method(
+                activity,
+                "requestPermissions",
+                "([Ljava/lang/String;I)V",
+                &[JValue::Object(&array), REQUEST_CODE.into()],
+            )?;
+            Ok(true)
+        })
+    }
+}
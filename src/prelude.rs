@@ -5,7 +5,7 @@ pub use crate::{
 
 #[cfg(feature = "event-loop")]
 pub use crate::{
-    event_loop::{IStepper, StepperAction, StepperId},
+    event_loop::{IStepper, StepperAction, StepperId, ToolEvent},
     IStepper,
 };
 
@@ -1,15 +1,20 @@
 use crate::{
     material::{Material, MaterialT},
-    maths::{Matrix, Rect},
+    maths::{Matrix, Rect, Vec3},
     mesh::{Mesh, MeshT},
     model::{Model, ModelT},
-    system::{assets_releaseref_threadsafe, IAsset, RenderClear, RenderLayer},
+    system::{
+        assets_releaseref_threadsafe, hierarchy_is_enabled, hierarchy_pop, hierarchy_push, hierarchy_to_world,
+        HierarchyParent, IAsset, Input, RenderClear, RenderLayer,
+    },
     tex::{Tex, TexT},
     util::Color128,
     StereoKitError,
 };
 use std::{
     self,
+    cell::RefCell,
+    collections::HashMap,
     ffi::{c_char, c_void, CStr, CString},
     ptr::NonNull,
 };
@@ -25,6 +30,26 @@ use std::{
 pub struct RenderList(pub NonNull<_RenderListT>);
 impl Drop for RenderList {
     fn drop(&mut self) {
+        // The sort of the list and its items waiting to be sorted are released with the last handle to the list.
+        let key = self.0.as_ptr() as usize;
+        let released = RENDER_LIST_HANDLES
+            .try_with(|handles| {
+                let mut handles = handles.borrow_mut();
+                match handles.get_mut(&key) {
+                    Some(count) if *count > 1 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => {
+                        handles.remove(&key);
+                        true
+                    }
+                }
+            })
+            .unwrap_or(false);
+        if released {
+            let _ = RENDER_LIST_SORTS.try_with(|sorts| sorts.borrow_mut().remove(&key));
+        }
         unsafe { assets_releaseref_threadsafe(self.0.as_ptr() as *mut c_void) };
     }
 }
@@ -89,6 +114,56 @@ extern "C" {
 
 }
 
+/// Non canonical enum. The order in which the items added to a [`RenderList`] are submitted when the list is drawn.
+/// <https://stereokit.net/Pages/StereoKit/RenderList.html>
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum RenderSort {
+    /// Items are submitted as soon as they are added, in insertion order.
+    #[default]
+    None,
+    /// The closest items to the camera first. This reduces overdraw of opaque items.
+    FrontToBack,
+    /// The farthest items from the camera first. This is the order transparent items need to blend correctly.
+    BackToFront,
+    /// Items sharing a material are submitted together. This reduces state changes.
+    ByMaterial,
+}
+
+/// An item waiting in a sorted RenderList for its list to be drawn.
+struct SortedItem {
+    asset: SortedAsset,
+    /// The world transform, with the Hierarchy active when the item was added.
+    transform: Matrix,
+    color_linear: Color128,
+    layer: RenderLayer,
+}
+
+enum SortedAsset {
+    Mesh(Mesh, Material),
+    Model(Model, Option<Material>),
+}
+
+impl SortedItem {
+    fn material_key(&self) -> usize {
+        match &self.asset {
+            SortedAsset::Mesh(_, material) => material.0.as_ptr() as usize,
+            SortedAsset::Model(_, Some(material)) => material.0.as_ptr() as usize,
+            SortedAsset::Model(model, None) => model.0.as_ptr() as usize,
+        }
+    }
+}
+
+#[derive(Default)]
+struct SortedItems {
+    sort: Option<RenderSort>,
+    items: Vec<SortedItem>,
+}
+
+thread_local! {
+    static RENDER_LIST_SORTS: RefCell<HashMap<usize, SortedItems>> = RefCell::new(HashMap::new());
+    static RENDER_LIST_HANDLES: RefCell<HashMap<usize, usize>> = RefCell::new(HashMap::new());
+}
+
 impl Default for RenderList {
     fn default() -> Self {
         Self::new()
@@ -111,7 +186,13 @@ impl RenderList {
     ///
     /// see also [`crate::render_list::render_list_create`]
     pub fn new() -> Self {
-        RenderList(NonNull::new(unsafe { render_list_create() }).unwrap())
+        RenderList::from_ptr(NonNull::new(unsafe { render_list_create() }).unwrap())
+    }
+
+    /// Wraps a referenced list, counting the handles so the sort of the list lives as long as the asset.
+    pub(crate) fn from_ptr(list: NonNull<_RenderListT>) -> RenderList {
+        RENDER_LIST_HANDLES.with(|handles| *handles.borrow_mut().entry(list.as_ptr() as usize).or_default() += 1);
+        RenderList(list)
     }
 
     /// Looks for a RenderList matching the given id!
@@ -122,7 +203,7 @@ impl RenderList {
         let c_str = CString::new(id.as_ref())?;
         let render_list = NonNull::new(unsafe { render_list_find(c_str.as_ptr()) });
         match render_list {
-            Some(render_list) => Ok(RenderList(render_list)),
+            Some(render_list) => Ok(RenderList::from_ptr(render_list)),
             None => Err(StereoKitError::RenderListFind(id.as_ref().to_owned(), "not found".to_owned())),
         }
     }
@@ -133,7 +214,7 @@ impl RenderList {
     ///
     /// see also [`crate::render_list::render_list_find()`]
     pub fn clone_ref(&self) -> RenderList {
-        RenderList(
+        RenderList::from_ptr(
             NonNull::new(unsafe { render_list_find(render_list_get_id(self.0.as_ptr())) })
                 .expect("<asset>::clone_ref failed!"),
         )
@@ -158,12 +239,14 @@ impl RenderList {
         unsafe { CStr::from_ptr(render_list_get_id(self.0.as_ptr())) }.to_str().unwrap()
     }
 
-    /// The number of Mesh/Material pairs that have been submitted to the render list so far this frame.
+    /// The number of Mesh/Material pairs that have been submitted to the render list so far this frame. The items
+    /// waiting to be sorted are counted as one pair each.
     /// <https://stereokit.net/Pages/StereoKit/RenderList/Count.html>
     ///
     /// see also [`crate::render_list::render_list_item_count`]
     pub fn get_count(&self) -> i32 {
-        unsafe { render_list_item_count(self.0.as_ptr()) }
+        let waiting = RENDER_LIST_SORTS.with(|sorts| sorts.borrow().get(&self.key()).map_or(0, |s| s.items.len()));
+        unsafe { render_list_item_count(self.0.as_ptr()) + waiting as i32 }
     }
 
     /// This is the number of items in the RenderList before it was most recently cleared. If this is a list that is
@@ -180,6 +263,11 @@ impl RenderList {
     ///
     /// see also [`crate::render_list::render_list_clear`]
     pub fn clear(&mut self) {
+        RENDER_LIST_SORTS.with(|sorts| {
+            if let Some(sorted) = sorts.borrow_mut().get_mut(&self.key()) {
+                sorted.items.clear();
+            }
+        });
         unsafe { render_list_clear(self.0.as_ptr()) }
     }
 
@@ -206,11 +294,22 @@ impl RenderList {
         layer: Option<RenderLayer>,
     ) {
         let layer = layer.unwrap_or(RenderLayer::Layer0);
+        let (mesh, material) = (mesh.as_ref(), material.as_ref());
+        if self.is_sorting() {
+            let asset = SortedAsset::Mesh(mesh.clone_ref(), material.clone_ref());
+            self.push_sorted(SortedItem {
+                asset,
+                transform: Self::to_world(transform.into()),
+                color_linear: color_linear.into(),
+                layer,
+            });
+            return;
+        }
         unsafe {
             render_list_add_mesh(
                 self.0.as_ptr(),
-                mesh.as_ref().0.as_ptr(),
-                material.as_ref().0.as_ptr(),
+                mesh.0.as_ptr(),
+                material.0.as_ptr(),
                 transform.into(),
                 color_linear.into(),
                 layer,
@@ -240,6 +339,16 @@ impl RenderList {
         layer: Option<RenderLayer>,
     ) {
         let layer = layer.unwrap_or(RenderLayer::Layer0);
+        if self.is_sorting() {
+            let asset = SortedAsset::Model(model.as_ref().clone_ref(), material_override);
+            self.push_sorted(SortedItem {
+                asset,
+                transform: Self::to_world(transform.into()),
+                color_linear: color_linear.into(),
+                layer,
+            });
+            return;
+        }
         match material_override {
             Some(material) => unsafe {
                 render_list_add_model_mat(
@@ -283,6 +392,35 @@ impl RenderList {
     ///   target is unaffected by the viewport, so this will clean the entire surface!
     ///
     /// see also [`crate::render_list::render_list_draw_now`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, maths::{Matrix, Rect, Vec3}, mesh::Mesh,
+    ///                      render_list::RenderList, system::RenderLayer, tex::Tex, util::{Color32, named_colors}};
+    ///
+    /// let cube = Mesh::generate_cube(Vec3::ONE * 0.2, None);
+    /// let mut render_list = RenderList::new();
+    /// // A red cube on the left for the main camera, a blue one on the right for a mirror.
+    /// render_list.add_mesh(&cube, Material::unlit(), Matrix::t(Vec3::new(-0.3, 0.0, 0.0)), named_colors::RED,
+    ///                      Some(RenderLayer::Layer1));
+    /// render_list.add_mesh(&cube, Material::unlit(), Matrix::t(Vec3::new(0.3, 0.0, 0.0)), named_colors::BLUE,
+    ///                      Some(RenderLayer::Layer2));
+    /// let render_tex = Tex::render_target(64, 64, None, None, None).unwrap();
+    ///
+    /// let mut pixels = vec![Color32::default(); 64 * 64];
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == 0 {
+    ///         // Only the layer of the main camera is drawn.
+    ///         render_list.draw_now(&render_tex, Matrix::t(Vec3::Z), Matrix::perspective(90.0, 1.0, 0.01, 10.0),
+    ///                              None, None, Rect::new(0.0, 0.0, 1.0, 1.0), Some(RenderLayer::Layer1));
+    ///         assert!(render_tex.get_color_data::<Color32>(&pixels, 0));
+    ///     }
+    /// );
+    /// let (left, right) = (pixels[32 * 64 + 22], pixels[32 * 64 + 42]);
+    /// assert!(left.r > 200 && left.b < 50);
+    /// assert_eq!(right.a, 0);
+    /// ```
     #[allow(clippy::too_many_arguments)]
     pub fn draw_now(
         &mut self,
@@ -297,11 +435,13 @@ impl RenderList {
         let layer_filter = layer_filter.unwrap_or(RenderLayer::all());
         let clear = clear.unwrap_or(RenderClear::All);
         let clear_color = clear_color.unwrap_or_default();
+        let camera = camera.into();
+        self.submit_sorted(camera.get_translation());
         unsafe {
             render_list_draw_now(
                 self.0.as_ptr(),
                 to_rendertarget.as_ref().0.as_ptr(),
-                camera.into(),
+                camera,
                 projection.into(),
                 clear_color,
                 clear,
@@ -316,7 +456,7 @@ impl RenderList {
     ///
     /// see also [`crate::render_list::render_get_primary_list`]
     pub fn primary() -> Self {
-        RenderList(NonNull::new(unsafe { render_get_primary_list() }).unwrap())
+        RenderList::from_ptr(NonNull::new(unsafe { render_get_primary_list() }).unwrap())
     }
 
     /// All draw calls that don't specify a render list will get submitted to the active RenderList at the top of the
@@ -326,6 +466,7 @@ impl RenderList {
     ///
     /// see also [`crate::render_list::render_list_push`]
     pub fn push(&mut self) {
+        self.submit_sorted(Input::get_head().position);
         unsafe { render_list_push(self.0.as_ptr()) }
     }
 
@@ -336,4 +477,188 @@ impl RenderList {
     pub fn pop() {
         unsafe { render_list_pop() }
     }
+
+    /// Non canonical function setting the order in which the items of this list are submitted. When it is not
+    /// [`RenderSort::None`], the items added are kept until the list is drawn with [`RenderList::draw_now`] or pushed
+    /// with [`RenderList::push`], then sorted relative to the camera position (the head for `push`). The sort is
+    /// stable, so items with the same key keep their insertion order.
+    ///
+    /// The kept items are drawn with the Hierarchy that was active when they were added. Lists that are drawn another
+    /// way (like [`RenderList::primary`]) must keep [`RenderSort::None`], the default, or their items would never be
+    /// submitted. The sort and the kept items are forgotten when the last handle of the list is dropped.
+    ///
+    /// see also [`RenderSort`] [`RenderList::get_sorted_indices`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::{Material, Transparency}, maths::{Matrix, Vec3}, mesh::Mesh,
+    ///                      render_list::{RenderList, RenderSort}, system::Hierarchy, util::named_colors};
+    ///
+    /// let sphere = Mesh::generate_sphere(0.2, None);
+    /// let mut render_list = RenderList::new();
+    /// render_list.set_sort(RenderSort::BackToFront);
+    /// assert_eq!(render_list.get_sort(), RenderSort::BackToFront);
+    ///
+    /// for z in [-1.0, -3.0, -2.0] {
+    ///     render_list.add_mesh(&sphere, Material::unlit(), Matrix::t(Vec3::new(0.0, 0.0, z)), named_colors::WHITE,
+    ///                          None);
+    /// }
+    /// assert_eq!(render_list.get_count(), 3);
+    ///
+    /// // Seen from the origin, the farthest sphere is drawn first.
+    /// assert_eq!(render_list.get_sorted_indices(Vec3::ZERO), vec![1, 2, 0]);
+    ///
+    /// render_list.set_sort(RenderSort::FrontToBack);
+    /// assert_eq!(render_list.get_sorted_indices(Vec3::ZERO), vec![0, 2, 1]);
+    ///
+    /// // Same material for all of them, the insertion order is kept.
+    /// render_list.set_sort(RenderSort::ByMaterial);
+    /// assert_eq!(render_list.get_sorted_indices(Vec3::ZERO), vec![0, 1, 2]);
+    ///
+    /// // Without a sort, even transparent items are submitted immediately.
+    /// let mut transparent = Material::unlit().copy();
+    /// transparent.transparency(Transparency::Blend);
+    /// let mut unsorted = RenderList::new();
+    /// unsorted.add_mesh(&sphere, &transparent, Matrix::IDENTITY, named_colors::WHITE, None);
+    /// assert_eq!(unsorted.get_sort(), RenderSort::None);
+    /// assert!(unsorted.get_sorted_indices(Vec3::ZERO).is_empty());
+    ///
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == 0 {
+    ///         // The kept items are placed with the Hierarchy active when they are added.
+    ///         render_list.clear();
+    ///         render_list.set_sort(RenderSort::BackToFront);
+    ///         Hierarchy::push(token, Matrix::t(Vec3::new(0.0, 0.0, -10.0)), None);
+    ///         render_list.add_mesh(&sphere, Material::unlit(), Matrix::IDENTITY, named_colors::RED, None);
+    ///         Hierarchy::pop(token);
+    ///         render_list.add_mesh(&sphere, Material::unlit(), Matrix::t(Vec3::new(0.0, 0.0, -5.0)),
+    ///                              named_colors::WHITE, None);
+    ///         assert_eq!(render_list.get_sorted_indices(Vec3::ZERO), vec![0, 1]);
+    ///     }
+    /// );
+    ///
+    /// // The sort belongs to the list, not to one of its handles.
+    /// let mut sorted_list = RenderList::new();
+    /// sorted_list.id("sorted_list").set_sort(RenderSort::FrontToBack);
+    /// let same_list = RenderList::find("sorted_list").expect("the list should be found");
+    /// assert_eq!(same_list.get_sort(), RenderSort::FrontToBack);
+    /// drop(same_list);
+    /// assert_eq!(RenderList::find("sorted_list").unwrap().get_sort(), RenderSort::FrontToBack);
+    /// assert_eq!(sorted_list.get_sort(), RenderSort::FrontToBack);
+    /// ```
+    pub fn set_sort(&mut self, sort: RenderSort) {
+        RENDER_LIST_SORTS.with(|sorts| sorts.borrow_mut().entry(self.key()).or_default().sort = Some(sort));
+    }
+
+    /// Non canonical function giving the order in which the items of this list are submitted.
+    /// Default is [`RenderSort::None`].
+    ///
+    /// see also [`RenderList::set_sort`]
+    pub fn get_sort(&self) -> RenderSort {
+        RENDER_LIST_SORTS
+            .with(|sorts| sorts.borrow().get(&self.key()).and_then(|sorted| sorted.sort))
+            .unwrap_or_default()
+    }
+
+    /// Non canonical function giving the order in which the items waiting to be sorted will be submitted if the list
+    /// was drawn from `camera_position`, as indices in their insertion order.
+    /// * camera_position - The position the distances are measured from.
+    ///
+    /// see also [`RenderList::set_sort`]
+    pub fn get_sorted_indices(&self, camera_position: impl Into<Vec3>) -> Vec<usize> {
+        let camera_position = camera_position.into();
+        RENDER_LIST_SORTS.with(|sorts| match sorts.borrow().get(&self.key()) {
+            Some(sorted) => Self::sort_indices(sorted, camera_position),
+            None => vec![],
+        })
+    }
+
+    fn key(&self) -> usize {
+        self.0.as_ptr() as usize
+    }
+
+    fn is_sorting(&self) -> bool {
+        self.get_sort() != RenderSort::None
+    }
+
+    /// The transform in world space, with the Hierarchy active now.
+    fn to_world(transform: Matrix) -> Matrix {
+        if unsafe { hierarchy_is_enabled() } == 0 {
+            return transform;
+        }
+        match unsafe { hierarchy_to_world().as_ref() } {
+            Some(hierarchy) => transform * *hierarchy,
+            None => transform,
+        }
+    }
+
+    fn push_sorted(&mut self, item: SortedItem) {
+        RENDER_LIST_SORTS.with(|sorts| sorts.borrow_mut().entry(self.key()).or_default().items.push(item));
+    }
+
+    fn sort_indices(sorted: &SortedItems, camera_position: Vec3) -> Vec<usize> {
+        let items = &sorted.items;
+        let distances: Vec<f32> = items
+            .iter()
+            .map(|item| Vec3::distance_sq(item.transform.get_translation(), camera_position))
+            .collect();
+        let mut indices: Vec<usize> = (0..items.len()).collect();
+        match sorted.sort.unwrap_or_default() {
+            RenderSort::None => (),
+            RenderSort::FrontToBack => indices.sort_by(|a, b| distances[*a].total_cmp(&distances[*b])),
+            RenderSort::BackToFront => indices.sort_by(|a, b| distances[*b].total_cmp(&distances[*a])),
+            RenderSort::ByMaterial => indices.sort_by_key(|index| items[*index].material_key()),
+        }
+        indices
+    }
+
+    /// Submit the items waiting to be sorted, sorted from the camera position.
+    fn submit_sorted(&mut self, camera_position: Vec3) {
+        let Some(sorted) = RENDER_LIST_SORTS.with(|sorts| {
+            let mut sorts = sorts.borrow_mut();
+            let sorted = sorts.get_mut(&self.key())?;
+            let items = std::mem::take(&mut sorted.items);
+            Some(SortedItems { sort: sorted.sort, items })
+        }) else {
+            return;
+        };
+        // The transforms are already in world space, the Hierarchy active now must not apply to them.
+        unsafe { hierarchy_push(&Matrix::IDENTITY, HierarchyParent::Ignore) };
+        for index in Self::sort_indices(&sorted, camera_position) {
+            let item = &sorted.items[index];
+            match &item.asset {
+                SortedAsset::Mesh(mesh, material) => unsafe {
+                    render_list_add_mesh(
+                        self.0.as_ptr(),
+                        mesh.0.as_ptr(),
+                        material.0.as_ptr(),
+                        item.transform,
+                        item.color_linear,
+                        item.layer,
+                    )
+                },
+                SortedAsset::Model(model, Some(material)) => unsafe {
+                    render_list_add_model_mat(
+                        self.0.as_ptr(),
+                        model.0.as_ptr(),
+                        material.0.as_ptr(),
+                        item.transform,
+                        item.color_linear,
+                        item.layer,
+                    )
+                },
+                SortedAsset::Model(model, None) => unsafe {
+                    render_list_add_model(
+                        self.0.as_ptr(),
+                        model.0.as_ptr(),
+                        item.transform,
+                        item.color_linear,
+                        item.layer,
+                    )
+                },
+            }
+        }
+        unsafe { hierarchy_pop() };
+    }
 }
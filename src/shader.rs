@@ -1,6 +1,7 @@
-use crate::{system::IAsset, StereoKitError};
+use crate::{system::IAsset, tools::build_tools::compile_hlsl_file, StereoKitError};
 use std::{
     ffi::{c_void, CStr, CString},
+    fs,
     path::Path,
     ptr::NonNull,
 };
@@ -92,6 +93,71 @@ impl Shader {
         ))
     }
 
+    /// Non canonical function that compiles a HLSL source at runtime with the skshaderc tool used by
+    /// `cargo compile_sks`, then loads the result. This is only available on platforms where skshaderc is present
+    /// (so not on Android).
+    /// * source - The HLSL source of the shader. `#include "stereokit.hlsli"` is available.
+    /// * id - If None the id will be the one set by StereoKit.
+    ///
+    /// Returns [`StereoKitError::ShaderCompile`] with the diagnostics of the compiler if the compilation failed.
+    ///
+    /// see also [`crate::tools::build_tools::compile_hlsl_file`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Matrix, mesh::Mesh, material::Material, shader::Shader};
+    ///
+    /// let source = r#"
+    /// #include "stereokit.hlsli"
+    /// struct vsIn { float4 pos : SV_Position; };
+    /// struct psIn { float4 pos : SV_Position; uint view_id : SV_RenderTargetArrayIndex; };
+    /// psIn vs(vsIn input, uint id : SV_InstanceID) {
+    ///     psIn o;
+    ///     o.view_id = id % sk_view_count;
+    ///     id        = id / sk_view_count;
+    ///     float3 world = mul(float4(input.pos.xyz, 1), sk_inst[id].world).xyz;
+    ///     o.pos = mul(float4(world, 1), sk_viewproj[o.view_id]);
+    ///     return o;
+    /// }
+    /// float4 ps(psIn input) : SV_TARGET { return float4(1, 0, 1, 1); }
+    /// "#;
+    ///
+    /// let shader = Shader::from_hlsl(source, Some("playground_shader")).expect("skshaderc should compile the source");
+    /// assert_eq!(shader.get_id(), "playground_shader");
+    /// let material = Material::new(&shader, None);
+    /// let cube = Mesh::generate_cube([0.5, 0.5, 0.5], None);
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     cube.draw(token, &material, Matrix::IDENTITY, None, None);
+    /// );
+    ///
+    /// // An invalid source gives the diagnostics of the compiler.
+    /// match Shader::from_hlsl("not a shader", Some("invalid_shader")) {
+    ///     Err(StereoKitError::ShaderCompile(diagnostics)) => assert!(!diagnostics.is_empty()),
+    ///     Err(other) => panic!("unexpected error {other}"),
+    ///     Ok(_) => panic!("an invalid source must not compile"),
+    /// }
+    /// ```
+    pub fn from_hlsl(source: impl AsRef<str>, id: Option<&str>) -> Result<Shader, StereoKitError> {
+        let work_dir = std::env::temp_dir().join(format!("sk_shader_{}", std::process::id()));
+        fs::create_dir_all(&work_dir).map_err(|err| StereoKitError::ShaderCompile(err.to_string()))?;
+        let hlsl_file = work_dir.join(format!("{}.hlsl", id.unwrap_or("from_hlsl").replace(['/', '\\'], "_")));
+        fs::write(&hlsl_file, source.as_ref()).map_err(|err| StereoKitError::ShaderCompile(err.to_string()))?;
+
+        let compiled = compile_hlsl_file(&hlsl_file, &work_dir, &[]).and_then(|sks_file| {
+            let data = fs::read(&sks_file).map_err(|err| err.to_string());
+            let _ = fs::remove_file(&sks_file);
+            data
+        });
+        let _ = fs::remove_file(&hlsl_file);
+
+        let mut shader = Shader::from_memory(&compiled.map_err(StereoKitError::ShaderCompile)?)?;
+        if let Some(id) = id {
+            shader.id(id);
+        }
+        Ok(shader)
+    }
+
     /// Looks for a shader asset that’s already loaded, matching the given id!
     /// <https://stereokit.net/Pages/StereoKit/Shader/Find.html>
     ///
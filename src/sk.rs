@@ -1,7 +1,13 @@
 use crate::{
-    maths::Bool32T,
-    system::{BackendOpenXR, Log, LogLevel},
-    tools::os_api::get_assets_dir,
+    interactor::Interactor,
+    maths::{Bool32T, Pose, Quat, Vec3},
+    permission::Permission,
+    sound::SoundInst,
+    system::{Assets, Backend, BackendOpenXR, BackendXRType, Input, Log, LogLevel, World},
+    tools::os_api::{
+        get_all_display_refresh_rates, get_assets_dir, get_display_refresh_rate, set_display_refresh_rate,
+    },
+    util::{Device, Scheduler, Time},
     StereoKitError,
 };
 #[cfg(target_os = "android")]
@@ -9,12 +15,13 @@ use crate::{
 use android_activity::{AndroidApp, MainEvent, PollEvent};
 use openxr_sys::pfn::DestroyInstance;
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     ffi::{c_char, c_void, CStr, CString},
     fmt::{self, Formatter},
     path::Path,
     ptr::null_mut,
     rc::Rc,
+    sync::Mutex,
 };
 #[cfg(target_os = "android")]
 #[cfg(feature = "event-loop")]
@@ -699,6 +706,15 @@ impl MainThreadToken {
     }
 }
 
+thread_local! {
+    /// The timestep set with [`Sk::set_fixed_timestep`] and the total time it has produced so far.
+    static FIXED_TIMESTEP: Cell<Option<(f64, f64)>> = const { Cell::new(None) };
+}
+
+/// The quit asked with [`Sk::quit`], given to StereoKit at the start of the next step unless it's cancelled. This is
+/// shared by all the threads, so a quit asked from another thread reaches the main loop.
+static QUIT_REQUEST: Mutex<Option<QuitReason>> = Mutex::new(None);
+
 /// This class contains functions for running the StereoKit library!
 /// <https://stereokit.net/Pages/StereoKit/SK.html>
 pub struct Sk {
@@ -817,13 +833,65 @@ impl Sk {
     ///
     /// see also [`crate::sk::sk_step`]
     pub fn step(&self) -> Option<&MainThreadToken> {
+        Self::apply_quit_request();
         if unsafe { sk_step(None) } == 0 {
             return None;
         }
+        Self::apply_fixed_timestep();
+        Interactor::step_customs();
+        Self::step_hooks(self.get_app_focus() == AppFocus::Active);
 
         Some(&self.token)
     }
 
+    /// Run the per step work of the non canonical features (permissions, asset callbacks, sound tracks, input history
+    /// and recording, scheduler). This is called by every step function, after the steppers and before the user's
+    /// step.
+    /// * app_focused - Is the app focused during this step.
+    pub(crate) fn step_hooks(app_focused: bool) {
+        Permission::check_pending(app_focused);
+        Assets::step_callbacks();
+        SoundInst::step_tracks();
+        Input::step_history();
+        Input::step_recording();
+        Scheduler::step();
+    }
+
+    /// Non canonical function making [`Time`] report a constant delta at each step, whatever the real time elapsed.
+    /// This makes animations and tests reproducible. `None` goes back to the real timing. The total time keeps
+    /// going from its current value.
+    /// * timestep - The seconds of each step, or None for the real timing.
+    ///
+    /// see also [`Time::set_time`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(1.0 / 60.0); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::util::Time;
+    ///
+    /// let mut totals = vec![];
+    /// number_of_steps = 10;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     assert!((Time::get_stepf() - 1.0 / 60.0).abs() < 1e-6);
+    ///     totals.push(Time::get_total());
+    /// );
+    /// for pair in totals.windows(2) {
+    ///     assert!((pair[1] - pair[0] - 1.0 / 60.0).abs() < 1e-9);
+    /// }
+    /// ```
+    pub fn set_fixed_timestep(&mut self, timestep: Option<f32>) {
+        FIXED_TIMESTEP.set(timestep.map(|timestep| (timestep as f64, Time::get_total_unscaled())));
+    }
+
+    /// Override the time of this step if a fixed timestep is set. This is called after each sk_step.
+    pub(crate) fn apply_fixed_timestep() {
+        if let Some((timestep, total)) = FIXED_TIMESTEP.get() {
+            let total = total + timestep;
+            Time::set_time(total, timestep);
+            FIXED_TIMESTEP.set(Some((timestep, total)));
+        }
+    }
+
     pub fn main_thread_token(&mut self) -> &MainThreadToken {
         &self.token
     }
@@ -847,6 +915,51 @@ impl Sk {
         unsafe { sk_app_focus() }
     }
 
+    /// Non canonical function resetting the origin of the world at the current position and heading of the head. This
+    /// is done through [`World::origin_offset`] so the reference space chosen at initialization is kept and subsequent
+    /// poses are given from the new origin. Only the heading (yaw) of the head is used, so the floor stays horizontal.
+    /// * mode - With [`OriginMode::Local`] the origin is moved to the head. With [`OriginMode::Floor`] or
+    ///   [`OriginMode::Stage`] the origin stays at the floor level, beneath the head.
+    ///
+    /// see also [`World::origin_offset`] [`World::get_origin_offset`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Quat, Vec3}, sk::OriginMode, system::{Input, World}};
+    ///
+    /// number_of_steps = 6;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == 1 {
+    ///         // The head is moved away from the origin.
+    ///         World::origin_offset(Pose::new([1.0, 0.5, -2.0], Some(Quat::from_angles(0.0, 90.0, 0.0))));
+    ///     } else if iter == 3 {
+    ///         assert!(Vec3::distance(Input::get_head().position, Vec3::ZERO) > 0.5);
+    ///         sk.recenter(OriginMode::Local);
+    ///     } else if iter == 5 {
+    ///         let head = Input::get_head();
+    ///         assert!(Vec3::distance(head.position, Vec3::ZERO) < 0.01);
+    ///         assert!(Vec3::distance(head.get_forward(), Vec3::FORWARD) < 0.01);
+    ///     }
+    /// );
+    /// ```
+    pub fn recenter(&self, mode: OriginMode) {
+        let offset = World::get_origin_offset();
+        // The head in the base reference space.
+        let head = Input::get_head() * offset.to_matrix(None);
+        let forward = head.get_forward();
+        let orientation = if forward.x.abs() + forward.z.abs() > f32::EPSILON {
+            Quat::look_dir(Vec3::new(forward.x, 0.0, forward.z))
+        } else {
+            offset.orientation
+        };
+        let position = match mode {
+            OriginMode::Local => head.position,
+            OriginMode::Floor | OriginMode::Stage => Vec3::new(head.position.x, offset.position.y, head.position.z),
+        };
+        World::origin_offset(Pose::new(position, Some(orientation)));
+    }
+
     /// Return a clone of SkInfo smart pointer
     /// <https://stereokit.net/Pages/StereoKit/SK.html>
     pub fn get_sk_info_clone(&self) -> Rc<RefCell<SkInfo>> {
@@ -886,15 +999,16 @@ impl Sk {
         unsafe { CStr::from_ptr(sk_version_name()) }.to_str().unwrap()
     }
 
-    /// Lets StereoKit know it should quit! It’ll finish the current frame, and after that Step will return that it
-    /// wants to exit.
+    /// Lets StereoKit know it should quit! The request is shared by all the threads and given to StereoKit before its
+    /// next step, whatever loop steps it, so until the end of the current frame the quit can be vetoed with
+    /// [`Sk::cancel_quit`]. As StereoKit finishes the frame in which it receives the quit, one more frame is stepped
+    /// after the one calling this function before Step returns that it wants to exit.
     /// <https://stereokit.net/Pages/StereoKit/SK/Quit.html>
     /// * quit_reason - if None has default value of QuitReason::User
     ///
-    /// see also [`crate::sk::sk_quit`]
+    /// see also [`crate::sk::sk_quit`] [`Sk::quit_requested`]
     pub fn quit(&self, quit_reason: Option<QuitReason>) {
-        let quit_reason = quit_reason.unwrap_or(QuitReason::User);
-        unsafe { sk_quit(quit_reason) }
+        *QUIT_REQUEST.lock().unwrap() = Some(quit_reason.unwrap_or(QuitReason::User));
     }
 
     /// This tells the reason why StereoKit has quit and
@@ -904,6 +1018,182 @@ impl Sk {
         unsafe { sk_get_quit_reason() }
     }
 
+    /// Non canonical function telling if a quit was asked with [`Sk::quit`] during this frame. StereoKit will stop at
+    /// the next step, unless [`Sk::cancel_quit`] is called before.
+    ///
+    /// see also [`Sk::quit_reason`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{sk::QuitReason, util::Time};
+    ///
+    /// let mut frames = vec![];
+    /// number_of_steps = 5;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == 1 {
+    ///         sk.quit(Some(QuitReason::User));
+    ///         assert!(sk.quit_requested());
+    ///         assert_eq!(sk.quit_reason(), QuitReason::User);
+    ///         // A confirm dialog would veto the quit here.
+    ///         assert!(sk.cancel_quit());
+    ///     }
+    ///     assert!(!sk.quit_requested());
+    ///     frames.push(Time::get_frame());
+    /// );
+    /// // The app kept stepping after the cancelled quit.
+    /// assert!(frames.len() >= 6);
+    /// assert!(frames.windows(2).all(|pair| pair[1] > pair[0]));
+    /// ```
+    pub fn quit_requested(&self) -> bool {
+        QUIT_REQUEST.lock().unwrap().is_some()
+    }
+
+    /// Non canonical function giving the reason of the quit asked during this frame if any, or the reason why
+    /// StereoKit has quit.
+    ///
+    /// see also [`Sk::quit_requested`] [`Sk::get_quit_reason`]
+    pub fn quit_reason(&self) -> QuitReason {
+        let quit_request = *QUIT_REQUEST.lock().unwrap();
+        quit_request.unwrap_or_else(|| self.get_quit_reason())
+    }
+
+    /// Non canonical function vetoing the quit asked with [`Sk::quit`] during this frame, to show a confirm dialog
+    /// for example. A quit initiated by StereoKit itself or by the runtime can't be cancelled.
+    ///
+    /// Returns true if a quit was cancelled.
+    ///
+    /// see also [`Sk::quit_requested`]
+    pub fn cancel_quit(&self) -> bool {
+        QUIT_REQUEST.lock().unwrap().take().is_some()
+    }
+
+    /// Give the quit asked with [`Sk::quit`] to StereoKit. This must be called before each sk_step.
+    pub(crate) fn apply_quit_request() {
+        let quit_request = QUIT_REQUEST.lock().unwrap().take();
+        if let Some(quit_reason) = quit_request {
+            unsafe { sk_quit(quit_reason) }
+        }
+    }
+
+    /// Non canonical function giving the name of the device, as OpenXR reports it in XrSystemProperties. This is never
+    /// empty: outside of XR it's "Simulator" with a flatscreen display and "Offscreen" without display.
+    ///
+    /// see also [`Device::get_name`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::sk::DisplayMode;
+    ///
+    /// let device_name = sk.get_device_name();
+    /// let runtime_name = sk.get_runtime_name();
+    /// assert!(!device_name.is_empty());
+    /// assert!(!runtime_name.is_empty());
+    /// if sk.get_active_display_mode() != DisplayMode::MixedReality {
+    ///     Log::info(format!("Running on {device_name} ({runtime_name})"));
+    /// }
+    /// ```
+    pub fn get_device_name(&self) -> String {
+        match Device::get_name() {
+            Ok(name) if !name.is_empty() => name.to_string(),
+            _ => self.get_name_placeholder().to_string(),
+        }
+    }
+
+    /// Non canonical function giving the name of the OpenXR runtime, from the OpenXR instance properties. This is
+    /// never empty: outside of XR it's "Simulator" with a flatscreen display and "Offscreen" without display.
+    ///
+    /// see also [`Device::get_runtime`]
+    pub fn get_runtime_name(&self) -> String {
+        match Device::get_runtime() {
+            Ok(name) if !name.is_empty() && name != "None" => name.to_string(),
+            _ => self.get_name_placeholder().to_string(),
+        }
+    }
+
+    /// The name given to the device and the runtime when they are unknown.
+    fn get_name_placeholder(&self) -> &'static str {
+        match self.get_active_display_mode() {
+            DisplayMode::MixedReality => "Unknown",
+            DisplayMode::Flatscreen => "Simulator",
+            DisplayMode::None => "Offscreen",
+        }
+    }
+
+    /// Non canonical function giving the tracking origin in use. This is the origin requested in
+    /// [`SkSettings::origin`], unless the runtime doesn't support it and StereoKit fell back to another one.
+    ///
+    /// see also [`World::get_origin_mode`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::sk::OriginMode;
+    ///
+    /// // test_init_sk uses the default origin.
+    /// assert_eq!(sk.get_settings().origin, OriginMode::Local);
+    /// assert_eq!(sk.get_tracking_origin(), sk.get_settings().origin);
+    /// ```
+    pub fn get_tracking_origin(&self) -> OriginMode {
+        World::get_origin_mode()
+    }
+
+    /// Non canonical function listing the display refresh rates supported by the headset. This needs the OpenXR
+    /// extension XR_FB_display_refresh_rate to be requested with [`BackendOpenXR::request_ext`] before initialization.
+    ///
+    /// Returns an empty list if the extension is not available (Simulator, Offscreen, ...).
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// // Offscreen mode has no display refresh rate.
+    /// assert!(sk.available_refresh_rates().is_empty());
+    /// assert_eq!(sk.current_refresh_rate(), 0.0);
+    /// assert!(sk.set_refresh_rate(72.0).is_err());
+    /// ```
+    pub fn available_refresh_rates(&self) -> Vec<f32> {
+        if !Self::refresh_rate_available() {
+            return vec![];
+        }
+        get_all_display_refresh_rates(false)
+    }
+
+    /// Non canonical function giving the current display refresh rate of the headset. This needs the OpenXR extension
+    /// XR_FB_display_refresh_rate to be requested with [`BackendOpenXR::request_ext`] before initialization.
+    ///
+    /// Returns 0.0 if the extension is not available.
+    pub fn current_refresh_rate(&self) -> f32 {
+        if !Self::refresh_rate_available() {
+            return 0.0;
+        }
+        get_display_refresh_rate().unwrap_or(0.0)
+    }
+
+    /// Non canonical function requesting a display refresh rate to the headset. The rate must be one of
+    /// [`Sk::available_refresh_rates`]. This needs the OpenXR extension XR_FB_display_refresh_rate to be requested with
+    /// [`BackendOpenXR::request_ext`] before initialization.
+    /// * hz - The refresh rate wanted. A value within 0.5hz of a supported rate selects this rate.
+    ///
+    /// Returns an error naming the nearest supported rate if `hz` is not supported.
+    pub fn set_refresh_rate(&self, hz: f32) -> Result<(), StereoKitError> {
+        let rates = self.available_refresh_rates();
+        let Some(nearest) = rates.iter().copied().min_by(|a, b| (a - hz).abs().total_cmp(&(b - hz).abs())) else {
+            return Err(StereoKitError::RefreshRate(hz, "XR_FB_display_refresh_rate is not available".into()));
+        };
+        if (nearest - hz).abs() > 0.5 {
+            return Err(StereoKitError::RefreshRate(hz, format!("not supported, nearest rate is {nearest}")));
+        }
+        if !set_display_refresh_rate(nearest, true) {
+            return Err(StereoKitError::RefreshRate(hz, "xrRequestDisplayRefreshRateFB failed".into()));
+        }
+        Ok(())
+    }
+
+    fn refresh_rate_available() -> bool {
+        Backend::xr_type() == BackendXRType::OpenXR && BackendOpenXR::ext_enabled("XR_FB_display_refresh_rate")
+    }
+
     /// Cleans up all StereoKit initialized systems. Release your own StereoKit created assets before calling this. This
     /// is for cleanup only, and should not be used to exit the application, use SK.Quit for that instead. Calling this
     /// function is unnecessary if using SK.Run, as it is called automatically there.
@@ -1070,9 +1360,12 @@ impl Sk {
     /// see also [`crate::sk::sk_step`]
     #[deprecated(since = "0.40.0", note = "see SkClosure::about_to_wait() instead")]
     pub fn step_looped<F: FnMut(&mut Sk)>(&mut self, on_step: &mut F) -> bool {
+        Self::apply_quit_request();
         if unsafe { sk_step(None) } == 0 {
             return false;
         }
+        Self::apply_fixed_timestep();
+        Interactor::step_customs();
         if !self.steppers.step(&mut self.token) {
             self.quit(None)
         };
@@ -1080,6 +1373,7 @@ impl Sk {
         while let Some(mut action) = self.actions.pop_front() {
             action();
         }
+        Self::step_hooks(self.get_app_focus() == AppFocus::Active);
 
         on_step(self);
 
@@ -1,9 +1,12 @@
 use crate::{
     maths::{Bool32T, Vec3},
-    system::IAsset,
+    system::{IAsset, Log},
+    util::Time,
     StereoKitError,
 };
 use std::{
+    cell::RefCell,
+    collections::HashMap,
     ffi::{CStr, CString},
     path::Path,
     ptr::NonNull,
@@ -184,6 +187,59 @@ impl Sound {
         unsafe { sound_play(self.0.as_ptr(), at.into(), volume) }
     }
 
+    /// Non canonical function playing the sound like [`Sound::play`], keeping what is needed to loop or filter the
+    /// instance with [`SoundInst::set_loop`], [`SoundInst::set_loop_points`], [`SoundInst::set_lowpass`] or
+    /// [`SoundInst::set_highpass`]. What is kept is released once the instance is finished and doesn't loop.
+    /// * volume - if None will have default value of 1.0
+    ///
+    /// see also [`SoundInst::set_loop`] [`Sound::play_with_callback`]
+    pub fn play_tracked(&self, at: impl Into<Vec3>, volume: Option<f32>) -> SoundInst {
+        let inst = self.play(at, volume);
+        SOUND_TRACKS.with_borrow_mut(|tracks| tracks.insert(inst.key(), SoundTrack::new(self, inst)));
+        inst
+    }
+
+    /// Non canonical function playing the sound like [`Sound::play`], then calling `on_complete` at the first step
+    /// where this non looping instance is finished. If the instance is stopped with [`SoundInst::stop`] or made
+    /// looping with [`SoundInst::set_loop`], the callback is dropped without being called.
+    /// * volume - if None will have default value of 1.0
+    ///
+    /// see also [`SoundInst::set_loop`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(1.0 / 60.0); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec3, sound::Sound};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// unsafe extern "C" fn bip(sample_time: f32) -> f32 {
+    ///     (sample_time * 880.0 * std::f32::consts::TAU).sin() * 0.5
+    /// }
+    /// let sound = Sound::generate(bip, 0.05).unwrap();
+    /// let completed = Rc::new(Cell::new(false));
+    /// let completed_in_callback = completed.clone();
+    /// sound.play_with_callback(Vec3::ZERO, None, move || completed_in_callback.set(true));
+    ///
+    /// number_of_steps = 10;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    /// );
+    /// assert!(completed.get());
+    /// ```
+    pub fn play_with_callback(
+        &self,
+        at: impl Into<Vec3>,
+        volume: Option<f32>,
+        on_complete: impl FnOnce() + 'static,
+    ) -> SoundInst {
+        let inst = self.play_tracked(at, volume);
+        SOUND_TRACKS.with_borrow_mut(|tracks| {
+            if let Some(track) = tracks.get_mut(&inst.key()) {
+                track.on_complete = Some(Box::new(on_complete));
+            }
+        });
+        inst
+    }
+
     /// <https://stereokit.net/Pages/StereoKit/Sound/Decibels.html>
     ///
     /// see also [`crate::sound::sound_set_decibels`]
@@ -298,6 +354,139 @@ impl Sound {
     }
 }
 
+/// What is needed to loop, filter or call back a sound instance, see [`SoundInst::step_tracks`].
+struct SoundTrack {
+    sound: Sound,
+    inst: SoundInst,
+    started: f64,
+    looping: bool,
+    loop_points: Option<(f32, f32)>,
+    on_complete: Option<Box<dyn FnOnce()>>,
+    lowpass: Option<f32>,
+    highpass: Option<f32>,
+    /// The stream played instead of the sound, once a filter has been set.
+    filter: Option<TrackFilter>,
+}
+
+/// The stream playing the filtered samples of a sound, see [`SoundInst::set_lowpass`].
+struct TrackFilter {
+    stream: Sound,
+    dry_samples: Vec<f32>,
+    /// The next dry sample to filter and write to the stream.
+    cursor: usize,
+    lowpass: Biquad,
+    highpass: Biquad,
+}
+
+/// How far ahead of the playback the filtered samples are written to their stream, in seconds.
+const FILTER_LEAD: f32 = 0.1;
+
+impl SoundTrack {
+    fn new(sound: &Sound, inst: SoundInst) -> Self {
+        Self {
+            sound: sound.clone_ref(),
+            inst,
+            started: Time::get_total_unscaled(),
+            looping: false,
+            loop_points: None,
+            on_complete: None,
+            lowpass: None,
+            highpass: None,
+            filter: None,
+        }
+    }
+
+    /// The sample where a loop restarts.
+    fn start_sample(&self) -> usize {
+        let start = self.loop_points.map_or(0.0, |(start, _)| start);
+        (start * Biquad::SAMPLE_RATE) as usize
+    }
+
+    /// The sample where the playback ends or loops.
+    fn end_sample(&self) -> usize {
+        let end = self.loop_points.map(|(_, end)| end).unwrap_or_else(|| self.sound.get_duration());
+        (end * Biquad::SAMPLE_RATE) as usize
+    }
+
+    /// Update the filters to the cutoffs. The first time, the playing instance is replaced by one of a stream where
+    /// the filtered samples are written from where the playback is, then only the filter coefficients change.
+    ///
+    /// Returns the new instance if the playing one has been replaced.
+    fn apply_filters(&mut self) -> Option<SoundInst> {
+        let lowpass = Biquad::lowpass(self.lowpass.unwrap_or(f32::INFINITY));
+        let highpass = Biquad::highpass(self.highpass.unwrap_or(f32::INFINITY));
+        if let Some(filter) = self.filter.as_mut() {
+            filter.lowpass = lowpass.with_state_of(&filter.lowpass);
+            filter.highpass = highpass.with_state_of(&filter.highpass);
+            return None;
+        }
+        if lowpass.is_bypass() && highpass.is_bypass() || unsafe { sound_inst_is_playing(self.inst) } == 0 {
+            return None;
+        }
+
+        let played = ((Time::get_total_unscaled() - self.started) * Biquad::SAMPLE_RATE as f64) as usize;
+        self.play_stream(played)
+    }
+
+    /// Replace the playing instance by one of a stream where the samples of the sound are written, filtered, from
+    /// `cursor`. The new instance keeps the position and the volume of the replaced one.
+    /// * cursor - The first sample to write to the stream.
+    ///
+    /// Returns the new instance, or None if the samples of the sound can't be streamed.
+    fn play_stream(&mut self, cursor: usize) -> Option<SoundInst> {
+        let count = self.sound.get_total_samples();
+        let mut dry_samples = vec![0.0; count as usize];
+        let read = self.sound.read_samples(dry_samples.as_mut_ptr(), count) as usize;
+        if read == 0 {
+            Log::warn(format!("SoundInst: the samples of {} can't be read to be streamed", self.sound.get_id()));
+            return None;
+        }
+        dry_samples.truncate(read);
+        let Ok(stream) = Sound::create_stream(FILTER_LEAD * 4.0) else {
+            Log::warn(format!("SoundInst: no stream can play the samples of {}", self.sound.get_id()));
+            return None;
+        };
+        let lowpass = Biquad::lowpass(self.lowpass.unwrap_or(f32::INFINITY));
+        let highpass = Biquad::highpass(self.highpass.unwrap_or(f32::INFINITY));
+        let mut filter = TrackFilter { stream, cursor: cursor.min(dry_samples.len()), dry_samples, lowpass, highpass };
+        filter.feed(self.end_sample());
+        let inst = unsafe {
+            let (at, volume) = (sound_inst_get_pos(self.inst), sound_inst_get_volume(self.inst));
+            if sound_inst_is_playing(self.inst) != 0 {
+                sound_inst_stop(self.inst);
+            }
+            sound_play(filter.stream.0.as_ptr(), at, volume)
+        };
+        self.inst = inst;
+        self.filter = Some(filter);
+        Some(inst)
+    }
+}
+
+impl TrackFilter {
+    /// Filter and write the dry samples the stream needs to stay [`FILTER_LEAD`] ahead of the playback.
+    /// * end - The sample where the playback ends or loops.
+    fn feed(&mut self, end: usize) {
+        let lead = (FILTER_LEAD * Biquad::SAMPLE_RATE) as usize;
+        let unread = self.stream.get_unread_samples() as usize;
+        let next = (self.cursor + lead.saturating_sub(unread)).min(end).min(self.dry_samples.len());
+        if next <= self.cursor {
+            return;
+        }
+        let mut samples = self.dry_samples[self.cursor..next].to_vec();
+        self.lowpass.process(&mut samples);
+        self.highpass.process(&mut samples);
+        self.stream.write_samples(samples.as_ptr(), samples.len() as u64);
+        self.cursor = next;
+    }
+}
+
+thread_local! {
+    /// The instances played with [`Sound::play_tracked`] or [`Sound::play_with_callback`], by the key of the instance
+    /// given to the user. When a loop restarts, `inst` is the instance currently playing.
+    static SOUND_TRACKS: RefCell<HashMap<(u16, i16), SoundTrack>> = RefCell::new(HashMap::new());
+}
+
 extern "C" {
     pub fn sound_inst_stop(sound_inst: SoundInst);
     pub fn sound_inst_is_playing(sound_inst: SoundInst) -> Bool32T;
@@ -321,7 +510,9 @@ impl SoundInst {
     ///
     /// see also [`crate::sound::sound_inst_stop`]
     pub fn stop(self) {
-        unsafe { sound_inst_stop(self) }
+        let current = self.current();
+        SOUND_TRACKS.with_borrow_mut(|tracks| tracks.remove(&self.key()));
+        unsafe { sound_inst_stop(current) }
     }
 
     /// The 3D position in world space this sound instance is currently playing at. If this instance is no longer
@@ -330,7 +521,7 @@ impl SoundInst {
     ///
     /// see also [`crate::sound::sound_inst_set_pos`]
     pub fn position(&mut self, at: impl Into<Vec3>) -> &mut Self {
-        unsafe { sound_inst_set_pos(*self, at.into()) }
+        unsafe { sound_inst_set_pos(self.current(), at.into()) }
         self
     }
 
@@ -339,7 +530,7 @@ impl SoundInst {
     ///
     /// see also [`crate::sound::sound_inst_set_volume`]
     pub fn volume(&mut self, volume: f32) -> &mut Self {
-        unsafe { sound_inst_set_volume(*self, volume) }
+        unsafe { sound_inst_set_volume(self.current(), volume) }
         self
     }
 
@@ -349,7 +540,7 @@ impl SoundInst {
     ///
     /// see also [`crate::sound::sound_inst_get_pos`]
     pub fn get_position(&self) -> Vec3 {
-        unsafe { sound_inst_get_pos(*self) }
+        unsafe { sound_inst_get_pos(self.current()) }
     }
 
     /// The volume multiplier of this Sound instance! A number between 0 and 1, where 0 is silent, and 1 is full volume.
@@ -357,7 +548,7 @@ impl SoundInst {
     ///
     /// see also [`crate::sound::sound_inst_get_volume`]
     pub fn get_volume(&self) -> f32 {
-        unsafe { sound_inst_get_volume(*self) }
+        unsafe { sound_inst_get_volume(self.current()) }
     }
 
     /// The maximum intensity of the sound data since the last frame, as a value from 0-1. This is unaffected by its 3d
@@ -366,7 +557,7 @@ impl SoundInst {
     ///
     /// see also [`crate::sound::sound_inst_get_intensity`]
     pub fn get_intensity(&self) -> f32 {
-        unsafe { sound_inst_get_intensity(*self) }
+        unsafe { sound_inst_get_intensity(self.current()) }
     }
 
     /// Is this Sound instance currently playing? For streaming assets, this will be true even if they don’t have any
@@ -375,6 +566,394 @@ impl SoundInst {
     ///
     /// see also [`crate::sound::sound_inst_is_playing`]
     pub fn is_playing(&self) -> bool {
-        unsafe { sound_inst_is_playing(*self) != 0 }
+        unsafe { sound_inst_is_playing(self.current()) != 0 }
+    }
+
+    /// Non canonical function making this instance restart when it reaches its end (or the end of its loop points).
+    /// The SoundInst keeps controlling the sound after each restart. This only works for the instances played with
+    /// [`Sound::play_tracked`] or [`Sound::play_with_callback`], and drops the completion callback if any.
+    /// * enabled - true to loop, false to let the instance finish.
+    ///
+    /// see also [`SoundInst::set_loop_points`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(1.0 / 60.0); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec3, sound::Sound};
+    ///
+    /// let sound = Sound::click();
+    /// let mut inst = sound.play_tracked(Vec3::ZERO, Some(0.5));
+    /// inst.set_loop(true).set_loop_points(0.0, 100.0);
+    /// assert_eq!(inst.get_loop_points(), Some((0.0, sound.get_duration())));
+    ///
+    /// number_of_steps = 20;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    /// );
+    /// inst.stop();
+    /// assert_eq!(inst.get_loop_points(), None);
+    /// ```
+    pub fn set_loop(&mut self, enabled: bool) -> &mut Self {
+        self.with_track(|track| {
+            track.looping = enabled;
+            if enabled {
+                track.on_complete = None;
+            }
+        });
+        self
+    }
+
+    /// Non canonical function setting the part of the sound this instance loops on, once [`SoundInst::set_loop`] is
+    /// enabled. The values are clamped to the duration of the sound. The first play goes from the beginning to the end
+    /// point, then each loop restarts from the start point. StereoKit can't start an instance in the middle of a
+    /// sound, so with a start point above 0 the instance is replaced by one of a stream at the first restart, like with
+    /// [`SoundInst::set_lowpass`].
+    /// * start_sec - Where the loop starts, in seconds.
+    /// * end_sec - Where the loop ends, in seconds.
+    ///
+    /// see also [`SoundInst::set_loop`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(1.0 / 60.0); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec3, sound::Sound};
+    ///
+    /// let sound = Sound::click();
+    /// let duration = sound.get_duration();
+    /// let mut inst = sound.play_tracked(Vec3::ZERO, Some(0.5));
+    /// // Play the whole click once, then loop on its second half.
+    /// inst.set_loop(true).set_loop_points(duration / 2.0, duration + 1.0);
+    /// assert_eq!(inst.get_loop_points(), Some((duration / 2.0, duration)));
+    ///
+    /// number_of_steps = 20;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    /// );
+    /// assert!(inst.get_loop());
+    /// inst.stop();
+    /// ```
+    pub fn set_loop_points(&mut self, start_sec: f32, end_sec: f32) -> &mut Self {
+        self.with_track(|track| {
+            let duration = track.sound.get_duration();
+            let end_sec = end_sec.clamp(0.0, duration);
+            let start_sec = start_sec.clamp(0.0, end_sec);
+            track.loop_points = Some((start_sec, end_sec));
+        });
+        self
+    }
+
+    /// Non canonical function getting the clamped loop points of this instance, if any.
+    ///
+    /// see also [`SoundInst::set_loop_points`]
+    pub fn get_loop_points(&self) -> Option<(f32, f32)> {
+        SOUND_TRACKS.with_borrow(|tracks| tracks.get(&self.key()).and_then(|track| track.loop_points))
+    }
+
+    /// Non canonical function telling if this instance loops.
+    ///
+    /// see also [`SoundInst::set_loop`]
+    pub fn get_loop(&self) -> bool {
+        SOUND_TRACKS.with_borrow(|tracks| tracks.get(&self.key()).is_some_and(|track| track.looping))
+    }
+
+    /// Non canonical function muffling this instance with a low-pass filter, like a sound behind a wall. StereoKit has
+    /// no filter, so the first filter set replaces the instance by one of a stream, starting where the playback was,
+    /// and this SoundInst is updated to control it. At each step, the samples of the sound are filtered by a
+    /// [`Biquad`] and written a little ahead of the playback. Changing the cutoffs afterward only changes the filter, so
+    /// this can be called at each frame. This only works for the instances played with [`Sound::play_tracked`] or
+    /// [`Sound::play_with_callback`], of sounds that aren't streams.
+    /// * cutoff_hz - The frequency above which the sound is attenuated. At or beyond 24000Hz, the Nyquist frequency,
+    ///   the filter is bypassed.
+    ///
+    /// see also [`SoundInst::set_highpass`] [`SoundInst::clear_filters`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(1.0 / 60.0); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec3, sound::Sound};
+    ///
+    /// unsafe extern "C" fn high_tone(sample_time: f32) -> f32 {
+    ///     (sample_time * 8000.0 * std::f32::consts::TAU).sin() * 0.5
+    /// }
+    /// let sound = Sound::generate(high_tone, 2.0).unwrap();
+    /// let mut inst = sound.play_tracked(Vec3::ZERO, Some(0.5));
+    /// let dry_key = (inst._id, inst._slot);
+    /// inst.set_lowpass(800.0);
+    /// assert_eq!(inst.get_lowpass(), Some(800.0));
+    /// assert_eq!(inst.get_highpass(), None);
+    /// // The instance now plays the stream of the filtered samples, and this SoundInst controls it.
+    /// let filtered_key = (inst._id, inst._slot);
+    /// assert_ne!(filtered_key, dry_key);
+    /// assert!(inst.is_playing());
+    /// inst.volume(0.4);
+    /// assert_eq!(inst.get_volume(), 0.4);
+    ///
+    /// // The 8000Hz tone is attenuated in the samples the instance plays.
+    /// let rms = |samples: &[f32]| (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt();
+    /// let stream = inst.get_sound().expect("the stream of the filtered samples");
+    /// let mut samples = vec![0.0; 4800];
+    /// let read = stream.read_samples(samples.as_mut_ptr(), 4800) as usize;
+    /// assert!(read > 1000);
+    /// let dry_rms = 0.5 * std::f32::consts::FRAC_1_SQRT_2;
+    /// assert!(rms(&samples[read / 2..read]) < dry_rms * 0.1);
+    ///
+    /// number_of_steps = 5;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == 2 {
+    ///         // Changing a cutoff doesn't replace the instance, this can be done at each frame.
+    ///         inst.set_highpass(200.0);
+    ///         assert_eq!((inst._id, inst._slot), filtered_key);
+    ///     }
+    /// );
+    /// assert_eq!(inst.get_highpass(), Some(200.0));
+    /// inst.clear_filters();
+    /// assert_eq!(inst.get_lowpass(), None);
+    /// assert!(inst.is_playing());
+    /// inst.stop();
+    /// assert!(!inst.is_playing());
+    /// ```
+    pub fn set_lowpass(&mut self, cutoff_hz: f32) -> &mut Self {
+        self.with_filters(|track| track.lowpass = Some(cutoff_hz));
+        self
+    }
+
+    /// Non canonical function removing the low frequencies of this instance with a high-pass filter. See
+    /// [`SoundInst::set_lowpass`] for how it works.
+    /// * cutoff_hz - The frequency under which the sound is attenuated. At or beyond 24000Hz, the Nyquist frequency,
+    ///   the filter is bypassed.
+    ///
+    /// see also [`SoundInst::set_lowpass`] [`SoundInst::clear_filters`]
+    pub fn set_highpass(&mut self, cutoff_hz: f32) -> &mut Self {
+        self.with_filters(|track| track.highpass = Some(cutoff_hz));
+        self
+    }
+
+    /// Non canonical function removing the filters of [`SoundInst::set_lowpass`] and [`SoundInst::set_highpass`], the
+    /// instance goes on playing the dry samples.
+    ///
+    /// see also [`SoundInst::set_lowpass`]
+    pub fn clear_filters(&mut self) -> &mut Self {
+        let filtered =
+            SOUND_TRACKS.with_borrow(|tracks| tracks.get(&self.key()).is_some_and(|track| track.filter.is_some()));
+        if filtered {
+            self.with_filters(|track| {
+                track.lowpass = None;
+                track.highpass = None;
+            });
+        }
+        self
+    }
+
+    /// Non canonical function getting the cutoff of the low-pass filter of this instance, if any.
+    ///
+    /// see also [`SoundInst::set_lowpass`]
+    pub fn get_lowpass(&self) -> Option<f32> {
+        SOUND_TRACKS.with_borrow(|tracks| tracks.get(&self.key()).and_then(|track| track.lowpass))
+    }
+
+    /// Non canonical function getting the cutoff of the high-pass filter of this instance, if any.
+    ///
+    /// see also [`SoundInst::set_highpass`]
+    pub fn get_highpass(&self) -> Option<f32> {
+        SOUND_TRACKS.with_borrow(|tracks| tracks.get(&self.key()).and_then(|track| track.highpass))
+    }
+
+    /// Non canonical function giving the sound played by an instance played with [`Sound::play_tracked`] or
+    /// [`Sound::play_with_callback`]. Once a filter is set, this is the stream the filtered samples are written to.
+    ///
+    /// see also [`SoundInst::set_lowpass`]
+    pub fn get_sound(&self) -> Option<Sound> {
+        SOUND_TRACKS.with_borrow(|tracks| {
+            tracks.get(&self.key()).map(|track| match &track.filter {
+                Some(filter) => filter.stream.clone_ref(),
+                None => track.sound.clone_ref(),
+            })
+        })
+    }
+
+    fn key(&self) -> (u16, i16) {
+        (self._id, self._slot)
+    }
+
+    /// The instance currently playing for this one, which differs once a loop has restarted.
+    fn current(&self) -> SoundInst {
+        SOUND_TRACKS.with_borrow(|tracks| tracks.get(&self.key()).map(|track| track.inst).unwrap_or(*self))
+    }
+
+    fn with_track(&self, action: impl FnOnce(&mut SoundTrack)) {
+        let key = self.key();
+        SOUND_TRACKS.with_borrow_mut(|tracks| match tracks.get_mut(&key) {
+            Some(track) => action(track),
+            None => Log::warn("SoundInst: only the instances played with Sound::play_tracked can loop or be filtered"),
+        });
+    }
+
+    /// Change the cutoffs of the track, then filter. If the instance playing has been replaced by the stream of the
+    /// filtered samples, this SoundInst becomes the new instance.
+    fn with_filters(&mut self, change: impl FnOnce(&mut SoundTrack)) {
+        let mut replaced = None;
+        self.with_track(|track| {
+            change(track);
+            replaced = track.apply_filters();
+        });
+        if let Some(inst) = replaced {
+            SOUND_TRACKS.with_borrow_mut(|tracks| {
+                if let Some(track) = tracks.remove(&self.key()) {
+                    tracks.insert(inst.key(), track);
+                }
+            });
+            *self = inst;
+        }
+    }
+
+    /// Write the samples of the filtered instances, restart the looping instances and call the callbacks of the finished ones. This is called at each step by Sk.
+    pub(crate) fn step_tracks() {
+        let now = Time::get_total_unscaled();
+        // Callbacks are taken out of the RefCell, so they can play other sounds.
+        let mut completed = vec![];
+        SOUND_TRACKS.with_borrow_mut(|tracks| {
+            tracks.retain(|_, track| {
+                let playing = unsafe { sound_inst_is_playing(track.inst) != 0 };
+                let start_sample = track.start_sample();
+                let end_sample = track.end_sample();
+                if let Some(filter) = track.filter.as_mut() {
+                    filter.feed(end_sample);
+                }
+                let finished = !playing || now - track.started >= end_sample as f64 / Biquad::SAMPLE_RATE as f64;
+                if !finished {
+                    return true;
+                }
+                if track.looping {
+                    // StereoKit can't start an instance in the middle of a sound, so a loop that doesn't restart from
+                    // the beginning is played by a stream.
+                    let mut restart = start_sample;
+                    if let Some(filter) = track.filter.as_mut() {
+                        // The stream goes on playing, with the samples of the loop start.
+                        filter.cursor = start_sample;
+                        filter.feed(end_sample);
+                    } else if start_sample == 0 || track.play_stream(start_sample).is_none() {
+                        let (at, volume) =
+                            unsafe { (sound_inst_get_pos(track.inst), sound_inst_get_volume(track.inst)) };
+                        if playing {
+                            unsafe { sound_inst_stop(track.inst) };
+                        }
+                        track.inst = unsafe { sound_play(track.sound.0.as_ptr(), at, volume) };
+                        restart = 0;
+                    }
+                    // `started` is when the sound would have been at its first sample.
+                    track.started = now - restart as f64 / Biquad::SAMPLE_RATE as f64;
+                    true
+                } else {
+                    if playing {
+                        unsafe { sound_inst_stop(track.inst) };
+                    }
+                    completed.extend(track.on_complete.take());
+                    false
+                }
+            })
+        });
+        for on_complete in completed {
+            on_complete();
+        }
+    }
+}
+
+/// Non canonical structure filtering samples at 48000Hz with a second order filter, from the cookbook of Robert
+/// Bristow-Johnson with a Q of 1/√2. This is what [`SoundInst::set_lowpass`] and [`SoundInst::set_highpass`] use, and
+/// it can filter the samples written to a stream sound.
+/// ### Examples
+/// ```
+/// use stereokit_rust::sound::Biquad;
+///
+/// let sine = |frequency: f32| -> Vec<f32> {
+///     (0..4800).map(|i| (i as f32 / Biquad::SAMPLE_RATE * frequency * std::f32::consts::TAU).sin()).collect()
+/// };
+/// // The second half, once the filter is settled.
+/// let rms = |samples: &[f32]| (samples[2400..].iter().map(|s| s * s).sum::<f32>() / 2400.0).sqrt();
+///
+/// let (mut low, mut high) = (sine(100.0), sine(10000.0));
+/// let (dry_low, dry_high) = (rms(&low), rms(&high));
+/// Biquad::lowpass(1000.0).process(&mut low);
+/// Biquad::lowpass(1000.0).process(&mut high);
+/// assert!(rms(&low) > dry_low * 0.95);
+/// assert!(rms(&high) < dry_high * 0.05);
+///
+/// // At or beyond Nyquist, the filter is bypassed.
+/// let mut bypassed = sine(10000.0);
+/// Biquad::lowpass(24000.0).process(&mut bypassed);
+/// assert_eq!(bypassed, sine(10000.0));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Biquad {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+    x1: f32,
+    x2: f32,
+    y1: f32,
+    y2: f32,
+}
+
+impl Biquad {
+    /// StereoKit plays all the sounds at 48000 samples per second.
+    pub const SAMPLE_RATE: f32 = 48000.0;
+
+    /// A filter attenuating the frequencies above `cutoff_hz`. The filter is bypassed if `cutoff_hz` is not between 0
+    /// and 24000Hz (the Nyquist frequency).
+    pub fn lowpass(cutoff_hz: f32) -> Self {
+        Self::new(cutoff_hz, |cos| ((1.0 - cos) / 2.0, 1.0 - cos))
+    }
+
+    /// A filter attenuating the frequencies below `cutoff_hz`. The filter is bypassed if `cutoff_hz` is not between 0
+    /// and 24000Hz (the Nyquist frequency).
+    pub fn highpass(cutoff_hz: f32) -> Self {
+        Self::new(cutoff_hz, |cos| ((1.0 + cos) / 2.0, -(1.0 + cos)))
+    }
+
+    /// `b0_b1` gives b0 (which is also b2) and b1 from the cosine of the cutoff pulsation.
+    fn new(cutoff_hz: f32, b0_b1: impl Fn(f32) -> (f32, f32)) -> Self {
+        let mut filter = Self { b0: 1.0, b1: 0.0, b2: 0.0, a1: 0.0, a2: 0.0, x1: 0.0, x2: 0.0, y1: 0.0, y2: 0.0 };
+        if cutoff_hz.is_nan() || cutoff_hz <= 0.0 || cutoff_hz >= Self::SAMPLE_RATE / 2.0 {
+            return filter;
+        }
+        let w0 = std::f32::consts::TAU * cutoff_hz / Self::SAMPLE_RATE;
+        let alpha = w0.sin() * std::f32::consts::FRAC_1_SQRT_2;
+        let a0 = 1.0 + alpha;
+        let (b0, b1) = b0_b1(w0.cos());
+        filter.b0 = b0 / a0;
+        filter.b1 = b1 / a0;
+        filter.b2 = b0 / a0;
+        filter.a1 = -2.0 * w0.cos() / a0;
+        filter.a2 = (1.0 - alpha) / a0;
+        filter
+    }
+
+    /// Is this filter letting all the samples through unchanged?
+    pub fn is_bypass(&self) -> bool {
+        self.b0 == 1.0 && self.b1 == 0.0 && self.b2 == 0.0 && self.a1 == 0.0 && self.a2 == 0.0
+    }
+
+    /// Filter the samples in place. The state of the filter is kept, so consecutive chunks of a stream can be given.
+    pub fn process(&mut self, samples: &mut [f32]) {
+        if self.is_bypass() {
+            return;
+        }
+        for sample in samples.iter_mut() {
+            let x = *sample;
+            let y = self.b0 * x + self.b1 * self.x1 + self.b2 * self.x2 - self.a1 * self.y1 - self.a2 * self.y2;
+            (self.x2, self.x1, self.y2, self.y1) = (self.x1, x, self.y1, y);
+            *sample = y;
+        }
+    }
+
+    /// This filter with the previous samples of `other`, so the coefficients can change without a click.
+    fn with_state_of(mut self, other: &Biquad) -> Self {
+        (self.x1, self.x2, self.y1, self.y2) = (other.x1, other.x2, other.y1, other.y2);
+        self
+    }
+
+    /// Forget the previous samples, before filtering an unrelated stream.
+    pub fn reset(&mut self) {
+        (self.x1, self.x2, self.y1, self.y2) = (0.0, 0.0, 0.0, 0.0);
     }
 }
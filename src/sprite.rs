@@ -1,12 +1,13 @@
 use crate::{
-    maths::{Matrix, Vec2},
+    maths::{Matrix, Recti, Vec2, Vec4},
     sk::MainThreadToken,
     system::{IAsset, TextAlign},
-    tex::{Tex, TexT},
+    tex::{Tex, TexFormat, TexT, TexType},
     util::Color32,
     StereoKitError,
 };
 use std::{
+    collections::HashMap,
     ffi::{c_char, CStr, CString},
     path::Path,
     ptr::NonNull,
@@ -334,3 +335,252 @@ impl Sprite {
         Sprite(NonNull::new(unsafe { sprite_find(cstr_id.as_ptr()) }).unwrap())
     }
 }
+
+/// The default maximum width and height of the texture built by an [`AtlasBuilder`].
+pub const DEFAULT_ATLAS_MAX_SIZE: usize = 2048;
+
+/// Non canonical structure packing many small images into a single texture at runtime. The images are sorted by
+/// height and placed on shelves, the atlas being the smallest power of two square able to receive them all.
+///
+/// StereoKit sprites can't reference a part of a texture, so the builder doesn't create any Sprite. Each image gets an
+/// [`AtlasRegion`] giving its place in the packed texture, and the UV transform to draw it with a material using the
+/// atlas (see [`crate::material::Material::tex_transform`]).
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{maths::Vec2, sprite::AtlasBuilder, util::{named_colors, Color32}};
+///
+/// let colors = [named_colors::RED, named_colors::GREEN, named_colors::BLUE, named_colors::YELLOW];
+/// let mut builder = AtlasBuilder::new("icons");
+/// for (index, color) in colors.iter().enumerate() {
+///     builder.add_colors(format!("icon{index}"), 16, 16, &[*color; 16 * 16]).unwrap();
+/// }
+///
+/// let (size, _) = builder.pack().unwrap();
+/// let (atlas, regions) = builder.build().unwrap();
+/// assert_eq!(atlas.get_width(), Some(size));
+///
+/// let pixels = vec![Color32::default(); size * size];
+/// assert!(atlas.get_color_data::<Color32>(&pixels, 0));
+/// // The pixel of the atlas at a UV of each image has the color of the image.
+/// let sample = |uv: Vec2| pixels[(uv.y * size as f32) as usize * size + (uv.x * size as f32) as usize];
+/// for (index, color) in colors.iter().enumerate() {
+///     let region = &regions[&format!("icon{index}")];
+///     assert_eq!((region.rect.width, region.rect.height), (16, 16));
+///     for uv in [Vec2::new(0.01, 0.01), Vec2::new(0.5, 0.5), Vec2::new(0.99, 0.7)] {
+///         assert_eq!(sample(region.to_atlas_uv(uv)), *color);
+///     }
+/// }
+///
+/// // An image bigger than the atlas can't be packed.
+/// builder.max_size(32).add_colors("too_big", 64, 8, &[named_colors::WHITE; 64 * 8]).unwrap();
+/// match builder.build() {
+///     Err(error) => assert!(error.to_string().contains("too_big")),
+///     Ok(_) => panic!("the atlas should not be built"),
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AtlasBuilder {
+    id: String,
+    max_size: usize,
+    padding: usize,
+    srgb: bool,
+    images: Vec<AtlasImage>,
+}
+
+/// The place of an image in the texture built by an [`AtlasBuilder`].
+#[derive(Debug, Copy, Clone)]
+pub struct AtlasRegion {
+    /// The pixels of the image in the atlas texture.
+    pub rect: Recti,
+    /// The UV transform of the image in the atlas, XY being the offset and ZW the scale. This is the value expected
+    /// by [`crate::material::Material::tex_transform`].
+    pub uv_transform: Vec4,
+}
+
+impl AtlasRegion {
+    fn new(rect: Recti, atlas_size: usize) -> Self {
+        let size = atlas_size as f32;
+        let uv_transform =
+            Vec4::new(rect.x as f32 / size, rect.y as f32 / size, rect.width as f32 / size, rect.height as f32 / size);
+        Self { rect, uv_transform }
+    }
+
+    /// Convert a UV of the image into a UV of the atlas texture.
+    pub fn to_atlas_uv(&self, uv: impl Into<Vec2>) -> Vec2 {
+        let uv = uv.into();
+        let transform = self.uv_transform;
+        Vec2::new(transform.x + uv.x * transform.z, transform.y + uv.y * transform.w)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AtlasImage {
+    name: String,
+    width: usize,
+    height: usize,
+    pixels: Vec<Color32>,
+}
+
+impl AtlasBuilder {
+    /// Create an empty builder.
+    /// * id - The id of the atlas texture.
+    pub fn new(id: impl AsRef<str>) -> Self {
+        Self {
+            id: id.as_ref().to_string(),
+            max_size: DEFAULT_ATLAS_MAX_SIZE,
+            padding: 1,
+            srgb: true,
+            images: vec![],
+        }
+    }
+
+    /// The maximum width and height of the atlas texture. Default is [`DEFAULT_ATLAS_MAX_SIZE`].
+    pub fn max_size(&mut self, max_size: usize) -> &mut Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// The number of transparent pixels left at the right and at the bottom of each image, to avoid bleeding
+    /// between neighbours when filtering. Default is 1.
+    pub fn padding(&mut self, padding: usize) -> &mut Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Are the colors of the atlas in the sRGB color space. Default is true.
+    pub fn srgb(&mut self, srgb: bool) -> &mut Self {
+        self.srgb = srgb;
+        self
+    }
+
+    /// Add an image from its pixels. An image with the same name is replaced.
+    ///
+    /// Returns an error if `colors` doesn't contain `width` x `height` pixels.
+    pub fn add_colors(
+        &mut self,
+        name: impl AsRef<str>,
+        width: usize,
+        height: usize,
+        colors: &[Color32],
+    ) -> Result<&mut Self, StereoKitError> {
+        let name = name.as_ref();
+        if width == 0 || height == 0 || colors.len() != width * height {
+            return Err(StereoKitError::SpriteAtlas(
+                name.into(),
+                format!("{} colors for a size of {}x{}", colors.len(), width, height),
+            ));
+        }
+        self.images.retain(|image| image.name != name);
+        self.images.push(AtlasImage { name: name.into(), width, height, pixels: colors.to_vec() });
+        Ok(self)
+    }
+
+    /// Add an image from a loaded texture. The pixels are read back from the GPU, so the texture must be a 32 bits
+    /// RGBA texture. An image with the same name is replaced.
+    pub fn add_tex(&mut self, name: impl AsRef<str>, tex: impl AsRef<Tex>) -> Result<&mut Self, StereoKitError> {
+        let name = name.as_ref();
+        let tex = tex.as_ref();
+        let error = |reason: String| Err(StereoKitError::SpriteAtlas(name.into(), reason));
+        match tex.get_format() {
+            Some(TexFormat::RGBA32) | Some(TexFormat::RGBA32Linear) => (),
+            format => return error(format!("tex {} has the unsupported format {:?}", tex.get_id(), format)),
+        }
+        let (Some(width), Some(height)) = (tex.get_width(), tex.get_height()) else {
+            return error(format!("tex {} has no size", tex.get_id()));
+        };
+        let pixels = vec![Color32::default(); width * height];
+        if !tex.get_color_data::<Color32>(&pixels, 0) {
+            return error(format!("unable to read the pixels of tex {}", tex.get_id()));
+        }
+        self.add_colors(name, width, height, &pixels)
+    }
+
+    /// Add an image from the memory of an image file (jpg, png, tga, bmp, psd, gif, hdr, pic, ktx2). The image is
+    /// loaded immediately. An image with the same name is replaced.
+    pub fn add_memory(&mut self, name: impl AsRef<str>, data: &[u8]) -> Result<&mut Self, StereoKitError> {
+        let name = name.as_ref();
+        let mut tex = Tex::new(TexType::Image, TexFormat::RGBA32, format!("{}/{}/memory", self.id, name));
+        tex.set_memory(data, self.srgb, 1, 0);
+        self.add_tex(name, tex)
+    }
+
+    /// Compute where each image goes in the atlas, without creating any asset.
+    ///
+    /// Returns the width and height of the square atlas and the region of each image, or an error naming the first
+    /// image that doesn't fit in [`AtlasBuilder::max_size`].
+    pub fn pack(&self) -> Result<(usize, HashMap<String, Recti>), StereoKitError> {
+        let mut order: Vec<&AtlasImage> = self.images.iter().collect();
+        order.sort_by(|a, b| b.height.cmp(&a.height).then(b.width.cmp(&a.width)));
+
+        let mut size = 1;
+        for image in &order {
+            let (width, height) = (image.width + self.padding, image.height + self.padding);
+            if width.max(height) > self.max_size {
+                return Err(StereoKitError::SpriteAtlas(
+                    image.name.clone(),
+                    format!("{}x{} is bigger than the atlas max size {}", image.width, image.height, self.max_size),
+                ));
+            }
+            size = size.max(width.max(height).next_power_of_two());
+        }
+        let mut size = size.min(self.max_size);
+        loop {
+            match self.pack_shelves(&order, size) {
+                Ok(regions) => return Ok((size, regions)),
+                Err(image) if size >= self.max_size => {
+                    return Err(StereoKitError::SpriteAtlas(
+                        image.name.clone(),
+                        format!("no room left in an atlas of {}x{}", self.max_size, self.max_size),
+                    ))
+                }
+                Err(_) => size = (size * 2).min(self.max_size),
+            }
+        }
+    }
+
+    fn pack_shelves<'a>(
+        &self,
+        order: &[&'a AtlasImage],
+        size: usize,
+    ) -> Result<HashMap<String, Recti>, &'a AtlasImage> {
+        let mut regions = HashMap::new();
+        let (mut x, mut y, mut shelf_height) = (0, 0, 0);
+        for image in order {
+            let (width, height) = (image.width + self.padding, image.height + self.padding);
+            if x + width > size {
+                y += shelf_height;
+                x = 0;
+                shelf_height = 0;
+            }
+            if x + width > size || y + height > size {
+                return Err(*image);
+            }
+            regions.insert(image.name.clone(), Recti::new(x as i32, y as i32, image.width as i32, image.height as i32));
+            x += width;
+            shelf_height = shelf_height.max(height);
+        }
+        Ok(regions)
+    }
+
+    /// Pack all the images in a single texture.
+    ///
+    /// Returns the atlas texture and the region of each image by name, or an error naming the first image that
+    /// doesn't fit.
+    pub fn build(&self) -> Result<(Tex, HashMap<String, AtlasRegion>), StereoKitError> {
+        let (size, rects) = self.pack()?;
+        let mut atlas_pixels = vec![Color32::BLACK_TRANSPARENT; size * size];
+        for image in &self.images {
+            let rect = rects[&image.name];
+            for (row, line) in image.pixels.chunks(image.width).enumerate() {
+                let start = (rect.y as usize + row) * size + rect.x as usize;
+                atlas_pixels[start..start + image.width].copy_from_slice(line);
+            }
+        }
+        let mut atlas = Tex::from_color32(&atlas_pixels, size, size, self.srgb)?;
+        atlas.id(&self.id);
+        let regions = rects.into_iter().map(|(name, rect)| (name, AtlasRegion::new(rect, size))).collect();
+        Ok((atlas, regions))
+    }
+}
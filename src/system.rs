@@ -2,8 +2,8 @@ use crate::{
     anchor::{Anchor, _AnchorT},
     font::{Font, FontT, _FontT},
     material::{Material, MaterialT, _MaterialT},
-    maths::{ray_from_mouse, Bool32T, Matrix, Pose, Quat, Ray, Rect, Vec2, Vec3},
-    mesh::{Mesh, MeshT, _MeshT},
+    maths::{ray_from_mouse, Bool32T, Bounds, Matrix, Pose, Quat, Ray, Rect, Vec2, Vec3, Vec4},
+    mesh::{Mesh, MeshT, Vertex, _MeshT},
     model::{Model, ModelT, _ModelT},
     render_list::{RenderList, _RenderListT},
     shader::{Shader, ShaderT, _ShaderT},
@@ -11,15 +11,33 @@ use crate::{
     sound::{Sound, SoundT, _SoundT},
     sprite::{Sprite, _SpriteT},
     tex::{Tex, TexFormat, TexT, _TexT},
-    util::{Color128, Color32, SphericalHarmonics},
+    util::{
+        device_display_get_height, device_display_get_width, Color128, Color32, Device, DisplayType, Platform,
+        SphericalHarmonics, Time,
+    },
     StereoKitError,
 };
+use openxr_sys::{
+    pfn::{
+        CreateHandTrackerEXT, DestroyHandTrackerEXT, EnumerateReferenceSpaces, EnumerateViewConfigurationViews,
+        EnumerateViewConfigurations, GetHandMeshFB, LocateViews,
+    },
+    Fovf, HandEXT, HandJointEXT, HandJointSetEXT, HandTrackerCreateInfoEXT, HandTrackerEXT, HandTrackingMeshFB,
+    Instance, Posef, ReferenceSpaceType, Result as XrResult, Session, Space, StructureType, SystemId, Time as XrTime,
+    Vector2f, Vector3f, Vector4f, Vector4sFB, View, ViewConfigurationType, ViewConfigurationView, ViewLocateInfo,
+    ViewState, ViewStateFlags,
+};
 use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, VecDeque},
+    f32::consts::FRAC_1_SQRT_2,
     ffi::{c_char, c_ushort, c_void, CStr, CString},
     fmt,
+    marker::PhantomData,
     mem::{size_of, transmute_copy},
-    path::Path,
+    path::{Path, PathBuf},
     ptr::{null, null_mut, NonNull},
+    sync::mpsc::{self, Receiver, TryRecvError},
 };
 
 /// All StereoKit assets implement this interface! This is mostly to help group and hold Asset objects, and is
@@ -89,6 +107,25 @@ pub enum AssetType {
 ///  <https://stereokit.net/Pages/StereoKit/Assets.html>
 pub struct Assets;
 
+type OnProgress = Box<dyn FnMut(f32)>;
+type OnAllLoaded = Box<dyn FnOnce()>;
+
+/// The callbacks registered with [`Assets::on_progress`] and [`Assets::on_all_loaded`].
+#[derive(Default)]
+struct AssetsCallbacks {
+    next_id: u64,
+    on_progress: Vec<(u64, OnProgress)>,
+    on_all_loaded: Vec<OnAllLoaded>,
+    /// The ids of the on_progress callbacks taken out by [`Assets::step_callbacks`] while they are called.
+    stepping: Vec<u64>,
+    /// The ids removed by a callback while they were taken out. They are removed at the end of the step.
+    removed: Vec<u64>,
+}
+
+thread_local! {
+    static ASSETS_CALLBACKS: RefCell<AssetsCallbacks> = RefCell::new(AssetsCallbacks::default());
+}
+
 pub type AssetT = *mut c_void;
 
 extern "C" {
@@ -143,6 +180,25 @@ impl fmt::Display for Asset {
     }
 }
 
+impl Asset {
+    /// The StereoKit pointer of this asset, or None for Asset::None and Asset::Solid.
+    fn as_asset_t(&self) -> Option<AssetT> {
+        match self {
+            Asset::None | Asset::Solid(_) => None,
+            Asset::Mesh(v) => Some(v.0.as_ptr() as AssetT),
+            Asset::Tex(v) => Some(v.0.as_ptr() as AssetT),
+            Asset::Shader(v) => Some(v.0.as_ptr() as AssetT),
+            Asset::Material(v) => Some(v.0.as_ptr() as AssetT),
+            Asset::Model(v) => Some(v.0.as_ptr() as AssetT),
+            Asset::Font(v) => Some(v.0.as_ptr() as AssetT),
+            Asset::Sprite(v) => Some(v.0.as_ptr() as AssetT),
+            Asset::Sound(v) => Some(v.0.as_ptr() as AssetT),
+            Asset::Anchor(v) => Some(v.0.as_ptr() as AssetT),
+            Asset::RenderList(v) => Some(v.0.as_ptr() as AssetT),
+        }
+    }
+}
+
 /// Iterator on assets
 ///
 /// see also [Assets::all][Assets::type]
@@ -202,7 +258,9 @@ impl AssetIter {
             AssetType::Sound => Asset::Sound(Sound(NonNull::new(c_id as *mut _SoundT).unwrap())),
             AssetType::Solid => todo!("Solids are deprecated!"),
             AssetType::Anchor => Asset::Anchor(Anchor(NonNull::new(c_id as *mut _AnchorT).unwrap())),
-            AssetType::RenderList => Asset::RenderList(RenderList(NonNull::new(c_id as *mut _RenderListT).unwrap())),
+            AssetType::RenderList => {
+                Asset::RenderList(RenderList::from_ptr(NonNull::new(c_id as *mut _RenderListT).unwrap()))
+            }
         }
     }
 
@@ -275,6 +333,368 @@ impl Assets {
     pub fn block_for_priority(priority: i32) {
         unsafe { assets_block_for_priority(priority) }
     }
+
+    /// Non canonical function giving the completion ratio of the asset loading tasks, from 0.0 to 1.0. This is 1.0 when
+    /// there is nothing to load.
+    ///
+    /// see also [`Assets::current_task`] [`Assets::total_tasks`]
+    pub fn get_progress() -> f32 {
+        let total = Self::total_tasks();
+        if total <= 0 {
+            1.0
+        } else {
+            (Self::current_task() as f32 / total as f32).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Non canonical function registering a callback called at each step, on the main thread, with the completion
+    /// ratio of the asset loading tasks (see [`Assets::get_progress`]). This is handy to drive a loading bar.
+    /// * callback - Called at each step with a ratio from 0.0 to 1.0.
+    ///
+    /// Returns an id to give to [`Assets::on_progress_remove`].
+    /// see also [`Assets::on_all_loaded`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{system::Assets, tex::Tex};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// let progress = Rc::new(Cell::new(0.0));
+    /// let progress_cb = progress.clone();
+    /// let progress_id = Assets::on_progress(move |ratio| progress_cb.set(ratio));
+    /// let all_loaded = Rc::new(Cell::new(false));
+    /// let all_loaded_cb = all_loaded.clone();
+    /// Assets::on_all_loaded(move || all_loaded_cb.set(true));
+    /// let calls = Rc::new(Cell::new(0));
+    /// let calls_cb = calls.clone();
+    /// let self_id = Rc::new(Cell::new(0));
+    /// let self_id_cb = self_id.clone();
+    /// self_id.set(Assets::on_progress(move |_| {
+    ///     calls_cb.set(calls_cb.get() + 1);
+    ///     assert!(Assets::on_progress_remove(self_id_cb.get()));
+    /// }));
+    ///
+    /// let textures: Vec<Tex> = ["bump_large.ktx2", "bump_large_inverse.ktx2", "bump_large_tiles.ktx2"]
+    ///     .iter()
+    ///     .map(|file| Tex::from_file(format!("textures/water/{file}"), false, None).unwrap())
+    ///     .collect();
+    ///
+    /// number_of_steps = 4;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == 1 {
+    ///         Assets::block_for_priority(i32::MAX);
+    ///     }
+    /// );
+    /// assert_eq!(progress.get(), 1.0);
+    /// assert!(all_loaded.get());
+    /// assert!(Assets::on_progress_remove(progress_id));
+    /// assert!(!Assets::on_progress_remove(progress_id));
+    ///
+    /// // A callback can remove itself, it was called only once.
+    /// assert_eq!(calls.get(), 1);
+    /// assert!(!Assets::on_progress_remove(self_id.get()));
+    /// ```
+    pub fn on_progress(callback: impl FnMut(f32) + 'static) -> u64 {
+        ASSETS_CALLBACKS.with_borrow_mut(|callbacks| {
+            callbacks.next_id += 1;
+            let id = callbacks.next_id;
+            callbacks.on_progress.push((id, Box::new(callback)));
+            id
+        })
+    }
+
+    /// Non canonical function removing a callback registered with [`Assets::on_progress`].
+    /// * id - The id returned by [`Assets::on_progress`].
+    ///
+    /// Returns true if the callback was found. This can be called from a progress callback, even to remove itself.
+    pub fn on_progress_remove(id: u64) -> bool {
+        ASSETS_CALLBACKS.with_borrow_mut(|callbacks| {
+            if callbacks.stepping.contains(&id) && !callbacks.removed.contains(&id) {
+                callbacks.removed.push(id);
+                return true;
+            }
+            let count = callbacks.on_progress.len();
+            callbacks.on_progress.retain(|(callback_id, _)| *callback_id != id);
+            count != callbacks.on_progress.len()
+        })
+    }
+
+    /// Non canonical function registering a callback called once, on the main thread, at the first step where all the
+    /// asset loading tasks are completed.
+    /// * callback - Called once all the assets are loaded.
+    ///
+    /// see also [`Assets::on_progress`]
+    pub fn on_all_loaded(callback: impl FnOnce() + 'static) {
+        ASSETS_CALLBACKS.with_borrow_mut(|callbacks| callbacks.on_all_loaded.push(Box::new(callback)));
+    }
+
+    /// Call the callbacks of [`Assets::on_progress`] and [`Assets::on_all_loaded`]. This is called at each step by Sk.
+    pub(crate) fn step_callbacks() {
+        // Callbacks are taken out of the RefCell, so they can register other callbacks.
+        let (mut on_progress, on_all_loaded) = ASSETS_CALLBACKS.with_borrow_mut(|callbacks| {
+            if callbacks.on_progress.is_empty() && callbacks.on_all_loaded.is_empty() {
+                return (vec![], vec![]);
+            }
+            let progress = Self::get_progress();
+            let on_all_loaded = if progress >= 1.0 { std::mem::take(&mut callbacks.on_all_loaded) } else { vec![] };
+            callbacks.stepping = callbacks.on_progress.iter().map(|(id, _)| *id).collect();
+            (std::mem::take(&mut callbacks.on_progress), on_all_loaded)
+        });
+        if on_progress.is_empty() && on_all_loaded.is_empty() {
+            return;
+        }
+        let progress = Self::get_progress();
+        for (id, callback) in on_progress.iter_mut() {
+            // A previous callback may have removed this one.
+            if !ASSETS_CALLBACKS.with_borrow(|callbacks| callbacks.removed.contains(id)) {
+                callback(progress);
+            }
+        }
+        for callback in on_all_loaded {
+            callback();
+        }
+        ASSETS_CALLBACKS.with_borrow_mut(|callbacks| {
+            let removed = std::mem::take(&mut callbacks.removed);
+            callbacks.stepping.clear();
+            on_progress.retain(|(id, _)| !removed.contains(id));
+            on_progress.append(&mut callbacks.on_progress);
+            callbacks.on_progress = on_progress;
+        });
+    }
+
+    /// Non canonical function releasing one reference of the given asset, so its memory can be reclaimed
+    /// mid-session. This is meant for assets no Rust handle owns anymore, like a handle given up with
+    /// `std::mem::forget`. To free an asset you hold a handle of, simply drop the handle.
+    /// * asset - The asset to release, usually found with [`Assets::all`].
+    ///
+    /// Returns false if the asset is Asset::None or Asset::Solid.
+    ///
+    /// see also [`Assets::unload_by_id`] [crate::system::asset_release]
+    ///    # Safety
+    ///    The reference released must not be owned by a Rust handle (Model, Tex, Mesh, ...) other than `asset`. Such a
+    ///    handle would release it a second time when dropped, and may use the asset once it is destroyed.
+    pub unsafe fn unload(asset: &Asset) -> bool {
+        match asset.as_asset_t() {
+            Some(asset_t) => {
+                unsafe { asset_release(asset_t) };
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Non canonical function releasing one reference of the asset with the given id. See [`Assets::unload`].
+    /// * id - The id of the asset to release.
+    ///
+    /// Returns true if the asset was found.
+    ///
+    /// see also [`Assets::unload`] [crate::system::asset_release]
+    ///    # Safety
+    ///    Same as [`Assets::unload`]: no Rust handle of the asset may be alive, or outlive this call.
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{system::Assets, tex::{Tex, TexFormat, TexType}, util::named_colors::RED};
+    ///
+    /// let mut tex = Tex::gen_color(RED, 16, 16, TexType::Image, TexFormat::RGBA32);
+    /// tex.id("tex_to_unload");
+    /// // The handle is given up, so no Rust handle outlives the release: only StereoKit references the texture now.
+    /// std::mem::forget(tex);
+    /// Assets::block_for_priority(i32::MAX);
+    /// assert!(Assets::all().any(|asset| asset.to_string() == "Tex : tex_to_unload"));
+    ///
+    /// // Safety: the only reference left is the one given up with std::mem::forget.
+    /// assert!(unsafe { Assets::unload_by_id("tex_to_unload") });
+    /// Assets::block_for_priority(i32::MAX);
+    /// assert!(!Assets::all().any(|asset| asset.to_string() == "Tex : tex_to_unload"));
+    /// assert!(!unsafe { Assets::unload_by_id("tex_to_unload") });
+    /// ```
+    pub unsafe fn unload_by_id(id: impl AsRef<str>) -> bool {
+        let found = Assets::all().find(|asset| {
+            asset.as_asset_t().is_some_and(|asset_t| {
+                let c_id = unsafe { asset_get_id(asset_t) };
+                !c_id.is_null() && unsafe { CStr::from_ptr(c_id) }.to_str() == Ok(id.as_ref())
+            })
+        });
+        // The asset found keeps a reference until the end of this function.
+        match found {
+            Some(asset) => unsafe { Self::unload(&asset) },
+            None => false,
+        }
+    }
+
+    /// Non canonical function starting the loading of the given files from a worker thread, so the main thread is not
+    /// blocked while the files are read. The kind of asset is guessed from the file extension (see
+    /// [`Assets::TEXTURE_FORMATS`], [`Assets::MODEL_FORMATS`] and [`Assets::SOUND_FORMATS`]), files with another
+    /// extension or failing to load are logged and skipped. Textures are loaded as sRGB data with the default priority.
+    /// * paths - The files to load, relative to the assets folder or absolute.
+    ///
+    /// Returns a handle to poll from the main thread until the assets are available.
+    /// see also [`AssetLoadHandle::poll`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::system::{Asset, AssetState, Assets};
+    /// use std::{path::PathBuf, time::{Duration, Instant}};
+    ///
+    /// let mut handle = None;
+    /// let mut assets = None;
+    /// let mut given_twice = false;
+    /// let mut drop_duration = Duration::ZERO;
+    /// number_of_steps = 100;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == 0 {
+    ///         // Dropping a handle doesn't wait for the loading thread, the assets are released when it's done.
+    ///         let start = Instant::now();
+    ///         drop(Assets::preload_async(vec![PathBuf::from("textures/water/bump_large_tiles.ktx2")]));
+    ///         drop_duration = start.elapsed();
+    ///     }
+    ///     let handle = handle.get_or_insert_with(|| {
+    ///         Assets::preload_async(vec![
+    ///             PathBuf::from("textures/water/bump_large.ktx2"),
+    ///             PathBuf::from("textures/water/bump_large_inverse.ktx2"),
+    ///         ])
+    ///     });
+    ///     if assets.is_none() {
+    ///         assets = handle.poll(token);
+    ///         if assets.is_none() {
+    ///             std::thread::sleep(Duration::from_millis(20));
+    ///         }
+    ///     } else if handle.poll(token).is_some() {
+    ///         given_twice = true;
+    ///     }
+    /// );
+    /// let assets = assets.expect("the textures should be loaded");
+    /// assert_eq!(assets.len(), 2);
+    /// for asset in assets {
+    ///     match asset {
+    ///         Asset::Tex(tex) => assert_eq!(tex.get_asset_state(), AssetState::Loaded),
+    ///         other => panic!("{other} is not a texture"),
+    ///     }
+    /// }
+    /// // The result is only given once.
+    /// assert!(!given_twice);
+    /// assert!(drop_duration < Duration::from_millis(100));
+    /// ```
+    pub fn preload_async(paths: Vec<PathBuf>) -> AssetLoadHandle {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut loaded = vec![];
+            for path in paths {
+                let extension = path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .map(|ext| format!(".{}", ext.to_lowercase()))
+                    .unwrap_or_default();
+                let extension = extension.as_str();
+                // The handles are forgotten here and rebuilt on the main thread by AssetLoadHandle::poll.
+                let asset = if Self::TEXTURE_FORMATS.contains(&extension) {
+                    Tex::from_file(&path, true, None).map(|tex| {
+                        let ptr = tex.0.as_ptr() as usize;
+                        std::mem::forget(tex);
+                        (AssetType::Tex, ptr)
+                    })
+                } else if Self::MODEL_FORMATS.contains(&extension) {
+                    Model::from_file(&path, None).map(|model| {
+                        let ptr = model.0.as_ptr() as usize;
+                        std::mem::forget(model);
+                        (AssetType::Model, ptr)
+                    })
+                } else if Self::SOUND_FORMATS.contains(&extension) {
+                    Sound::from_file(&path).map(|sound| {
+                        let ptr = sound.0.as_ptr() as usize;
+                        std::mem::forget(sound);
+                        (AssetType::Sound, ptr)
+                    })
+                } else {
+                    Log::warn(format!("Assets::preload_async: unsupported file type {path:?}"));
+                    continue;
+                };
+                match asset {
+                    Ok(asset) => loaded.push(asset),
+                    Err(err) => Log::warn(format!("Assets::preload_async: {err}")),
+                }
+            }
+            // If the handle has been dropped, the message is dropped too and releases the assets.
+            let _ = sender.send(LoadedAssets(loaded));
+        });
+        AssetLoadHandle { receiver, assets: None, done: false }
+    }
+}
+
+/// Non canonical structure returned by [`Assets::preload_async`] to get the assets loaded from a worker thread.
+/// see also [`Assets::preload_async`]
+#[derive(Debug)]
+pub struct AssetLoadHandle {
+    receiver: Receiver<LoadedAssets>,
+    assets: Option<Vec<Asset>>,
+    done: bool,
+}
+
+impl AssetLoadHandle {
+    /// Converts the raw pointers sent by the worker thread into assets owning their reference.
+    fn to_assets(mut loaded: LoadedAssets) -> Vec<Asset> {
+        std::mem::take(&mut loaded.0)
+            .into_iter()
+            .filter_map(|(asset_type, ptr)| match asset_type {
+                AssetType::Tex => NonNull::new(ptr as *mut _TexT).map(|ptr| Asset::Tex(Tex(ptr))),
+                AssetType::Model => NonNull::new(ptr as *mut _ModelT).map(|ptr| Asset::Model(Model(ptr))),
+                AssetType::Sound => NonNull::new(ptr as *mut _SoundT).map(|ptr| Asset::Sound(Sound(ptr))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Checks if the worker thread and StereoKit's async loaders are done with all the assets. Textures that failed to
+    /// load are given anyway, check their [`AssetState`].
+    /// * token - The assets can only be used on the main thread.
+    ///
+    /// Returns the assets once, at the first call where they are all loaded, then None.
+    /// see also [`Assets::preload_async`]
+    pub fn poll(&mut self, _token: &MainThreadToken) -> Option<Vec<Asset>> {
+        if self.done {
+            return None;
+        }
+        if self.assets.is_none() {
+            match self.receiver.try_recv() {
+                Ok(loaded) => self.assets = Some(Self::to_assets(loaded)),
+                Err(TryRecvError::Empty) => return None,
+                Err(TryRecvError::Disconnected) => {
+                    Log::err("AssetLoadHandle::poll: the loading thread stopped before the end");
+                    self.assets = Some(vec![]);
+                }
+            }
+        }
+        let loading = self.assets.as_ref().is_some_and(|assets| {
+            assets.iter().any(|asset| match asset {
+                Asset::Tex(tex) => {
+                    matches!(tex.get_asset_state(), AssetState::None | AssetState::Loading | AssetState::LoadedMeta)
+                }
+                _ => false,
+            })
+        });
+        if loading {
+            return None;
+        }
+        self.done = true;
+        self.assets.take()
+    }
+}
+
+/// The references taken by the worker thread of [`Assets::preload_async`]. They are released if the message is dropped
+/// before reaching [`AssetLoadHandle::poll`], so dropping the handle doesn't have to wait for the worker thread.
+#[derive(Debug)]
+struct LoadedAssets(Vec<(AssetType, usize)>);
+
+impl Drop for LoadedAssets {
+    fn drop(&mut self) {
+        if !self.0.is_empty() {
+            drop(AssetLoadHandle::to_assets(LoadedAssets(std::mem::take(&mut self.0))));
+        }
+    }
 }
 
 /// This describes what technology is being used to power StereoKit’s XR backend.
@@ -488,6 +908,49 @@ impl BackendOpenXR {
         unsafe { backend_openxr_get_time() }
     }
 
+    /// Non canonical function giving the XrTime at which the current frame is predicted to be displayed, in
+    /// nanoseconds. This is the predictedDisplayTime of the XrFrameState StereoKit received from xrWaitFrame, the value
+    /// to use for extrapolating poses to the presentation moment.
+    ///
+    /// Returns 0 if StereoKit is not running an OpenXR session.
+    ///
+    /// see also [`BackendOpenXR::predicted_display_period`] [`BackendOpenXR::time`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::system::{Backend, BackendOpenXR, BackendXRType};
+    ///
+    /// // Offscreen mode doesn't use OpenXR, there is no session.
+    /// assert_ne!(Backend::xr_type(), BackendXRType::OpenXR);
+    /// assert_eq!(BackendOpenXR::session(), 0);
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     assert_eq!(BackendOpenXR::predicted_display_time(), 0);
+    ///     assert_eq!(BackendOpenXR::predicted_display_period(), 0);
+    /// );
+    /// ```
+    pub fn predicted_display_time() -> i64 {
+        if Backend::xr_type() != BackendXRType::OpenXR || Self::session() == 0 {
+            return 0;
+        }
+        Self::time()
+    }
+
+    /// Non canonical function giving an estimate of the interval between two displayed frames, in nanoseconds.
+    /// StereoKit doesn't expose the predictedDisplayPeriod of the XrFrameState, so this returns the unscaled duration
+    /// of the last step ([`Time::get_step_unscaled`]). It matches the display period as long as the app keeps up with
+    /// the frame rate of the runtime, and is longer when frames are missed.
+    ///
+    /// Returns 0 if StereoKit is not running an OpenXR session.
+    ///
+    /// see also [`BackendOpenXR::predicted_display_time`]
+    pub fn predicted_display_period() -> i64 {
+        if Backend::xr_type() != BackendXRType::OpenXR || Self::session() == 0 {
+            return 0;
+        }
+        (Time::get_step_unscaled() * 1_000_000_000.0) as i64
+    }
+
     /// Tells StereoKit to request only the extensions that are absolutely critical to StereoKit. You can still request
     /// extensions via OpenXR.RequestExt, and this can be used to opt-in to extensions that StereoKit would normally
     /// request automatically.
@@ -584,6 +1047,149 @@ impl BackendOpenXR {
     pub fn set_hand_joint_scale(joint_scale_factor: f32) {
         unsafe { backend_openxr_set_hand_joint_scale(joint_scale_factor) }
     }
+
+    /// Non canonical function listing the reference spaces the OpenXR session supports (VIEW, LOCAL, STAGE, ...).
+    ///
+    /// Returns an empty list if StereoKit is not running on OpenXR.
+    /// see also [`BackendOpenXR::view_configuration`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::system::{Backend, BackendOpenXR, BackendXRType};
+    ///
+    /// // Offscreen mode doesn't use OpenXR.
+    /// assert_ne!(Backend::xr_type(), BackendXRType::OpenXR);
+    /// assert_eq!(BackendOpenXR::instance(), 0);
+    /// assert!(BackendOpenXR::reference_spaces().is_empty());
+    /// assert!(BackendOpenXR::view_configuration().is_none());
+    /// ```
+    pub fn reference_spaces() -> Vec<ReferenceSpaceType> {
+        if Backend::xr_type() != BackendXRType::OpenXR || Self::session() == 0 {
+            return vec![];
+        }
+        let Some(enumerate) = Self::get_function::<EnumerateReferenceSpaces>("xrEnumerateReferenceSpaces") else {
+            return vec![];
+        };
+        let session = Session::from_raw(Self::session());
+        let mut count = 0u32;
+        if unsafe { enumerate(session, 0, &mut count, null_mut()) } != XrResult::SUCCESS {
+            return vec![];
+        }
+        let mut spaces = vec![ReferenceSpaceType::VIEW; count as usize];
+        match unsafe { enumerate(session, count, &mut count, spaces.as_mut_ptr()) } {
+            XrResult::SUCCESS => {
+                spaces.truncate(count as usize);
+                spaces
+            }
+            otherwise => {
+                Log::err(format!("xrEnumerateReferenceSpaces failed: {otherwise}"));
+                vec![]
+            }
+        }
+    }
+
+    /// Non canonical function describing the views of the device: PRIMARY_STEREO if the runtime supports it, or the
+    /// first view configuration the runtime prefers.
+    ///
+    /// Returns None if StereoKit is not running on OpenXR.
+    /// see also [`BackendOpenXR::reference_spaces`]
+    pub fn view_configuration() -> Option<ViewConfig> {
+        if Backend::xr_type() != BackendXRType::OpenXR || Self::instance() == 0 {
+            return None;
+        }
+        let enumerate_types = Self::get_function::<EnumerateViewConfigurations>("xrEnumerateViewConfigurations")?;
+        let enumerate_views =
+            Self::get_function::<EnumerateViewConfigurationViews>("xrEnumerateViewConfigurationViews")?;
+        let instance = Instance::from_raw(Self::instance());
+        let system_id = SystemId::from_raw(Self::system_id());
+
+        let mut count = 0u32;
+        if unsafe { enumerate_types(instance, system_id, 0, &mut count, null_mut()) } != XrResult::SUCCESS {
+            return None;
+        }
+        let mut types = vec![ViewConfigurationType::PRIMARY_STEREO; count as usize];
+        match unsafe { enumerate_types(instance, system_id, count, &mut count, types.as_mut_ptr()) } {
+            XrResult::SUCCESS => (),
+            otherwise => {
+                Log::err(format!("xrEnumerateViewConfigurations failed: {otherwise}"));
+                return None;
+            }
+        }
+        types.truncate(count as usize);
+        let view_configuration_type = if types.contains(&ViewConfigurationType::PRIMARY_STEREO) {
+            ViewConfigurationType::PRIMARY_STEREO
+        } else {
+            *types.first()?
+        };
+
+        let mut view_count = 0u32;
+        if unsafe { enumerate_views(instance, system_id, view_configuration_type, 0, &mut view_count, null_mut()) }
+            != XrResult::SUCCESS
+        {
+            return None;
+        }
+        let empty_view = ViewConfigurationView {
+            ty: ViewConfigurationView::TYPE,
+            next: null_mut(),
+            recommended_image_rect_width: 0,
+            max_image_rect_width: 0,
+            recommended_image_rect_height: 0,
+            max_image_rect_height: 0,
+            recommended_swapchain_sample_count: 0,
+            max_swapchain_sample_count: 0,
+        };
+        let mut views = vec![empty_view; view_count as usize];
+        match unsafe {
+            enumerate_views(
+                instance,
+                system_id,
+                view_configuration_type,
+                view_count,
+                &mut view_count,
+                views.as_mut_ptr(),
+            )
+        } {
+            XrResult::SUCCESS => (),
+            otherwise => {
+                Log::err(format!("xrEnumerateViewConfigurationViews failed: {otherwise}"));
+                return None;
+            }
+        }
+        let view = views.first()?;
+        Some(ViewConfig {
+            view_configuration_type,
+            view_count,
+            recommended_width: view.recommended_image_rect_width,
+            recommended_height: view.recommended_image_rect_height,
+            recommended_samples: view.recommended_swapchain_sample_count,
+            max_width: view.max_image_rect_width,
+            max_height: view.max_image_rect_height,
+            max_samples: view.max_swapchain_sample_count,
+        })
+    }
+}
+
+/// Non canonical structure describing the views of an OpenXR device, given by [`BackendOpenXR::view_configuration`].
+/// The sizes are the ones of the first view, all the views of a device usually share them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ViewConfig {
+    /// The view configuration used, PRIMARY_STEREO for a headset.
+    pub view_configuration_type: ViewConfigurationType,
+    /// The number of views, 2 for a headset.
+    pub view_count: u32,
+    /// The width in pixels the runtime recommends for the image of a view.
+    pub recommended_width: u32,
+    /// The height in pixels the runtime recommends for the image of a view.
+    pub recommended_height: u32,
+    /// The number of MSAA samples the runtime recommends.
+    pub recommended_samples: u32,
+    /// The maximum width in pixels of the image of a view.
+    pub max_width: u32,
+    /// The maximum height in pixels of the image of a view.
+    pub max_height: u32,
+    /// The maximum number of MSAA samples.
+    pub max_samples: u32,
 }
 
 /// This class contains variables that may be useful for interop with the Android operating system, or other Android
@@ -718,6 +1324,30 @@ pub enum HierarchyParent {
 /// <https://stereokit.net/Pages/StereoKit/Hierarchy.html>
 pub struct Hierarchy;
 
+thread_local! {
+    static HIERARCHY_SCOPE_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A transform pushed on the [`Hierarchy`] stack by [`Hierarchy::push_scoped`] and popped when this guard is dropped.
+/// It can't leave the main thread. Nested scopes must be dropped in the reverse order of their creation, which is what
+/// happens for local variables.
+#[must_use = "the transform is popped as soon as the HierarchyScope is dropped"]
+pub struct HierarchyScope<'a> {
+    depth: usize,
+    _token: &'a MainThreadToken,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for HierarchyScope<'_> {
+    fn drop(&mut self) {
+        let current = HIERARCHY_SCOPE_DEPTH.with(|depth| depth.replace(self.depth - 1));
+        if current != self.depth {
+            Log::err(format!("HierarchyScope dropped at depth {} while the top scope is {}", self.depth, current));
+        }
+        unsafe { hierarchy_pop() }
+    }
+}
+
 extern "C" {
     pub fn hierarchy_push(transform: *const Matrix, parent_behavior: HierarchyParent);
     pub fn hierarchy_pop();
@@ -779,6 +1409,70 @@ impl Hierarchy {
         unsafe { hierarchy_push(&transform.into(), parent_behavior) }
     }
 
+    /// Non canonical function pushing a transform Matrix onto the stack like [`Hierarchy::push`], but the returned
+    /// guard pops it when dropped, so there is no Pop call to forget.
+    /// * transform - The transform to combine with the current Hierarchy stack.
+    /// * parent_behavior - How this matrix combines with the parent matrix below it. If None, has default value
+    ///   "Inherit"
+    ///
+    /// see also [`crate::system::hierarchy_push`] [`HierarchyScope`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Matrix, Quat, Vec3}, system::Hierarchy};
+    ///
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     let world_point = Vec3::new(1.0, 2.0, 3.0);
+    ///     {
+    ///         let _outer = Hierarchy::push_scoped(token, Matrix::t(Vec3::X), None);
+    ///         assert_eq!(Hierarchy::to_local_point(token, world_point), Vec3::new(0.0, 2.0, 3.0));
+    ///         {
+    ///             let rotation = Quat::from_angles(0.0, 90.0, 0.0);
+    ///             let _inner = Hierarchy::push_trs(token, [0.0, 1.0, 0.0], rotation, [2.0, 2.0, 2.0], None);
+    ///             let combined = Matrix::trs(&Vec3::Y, &rotation, &(Vec3::ONE * 2.0)) * Matrix::t(Vec3::X);
+    ///             let expected = combined.get_inverse().transform_point(world_point);
+    ///             assert!(Vec3::distance(Hierarchy::to_local_point(token, world_point), expected) < 0.0001);
+    ///         }
+    ///         assert_eq!(Hierarchy::to_local_point(token, world_point), Vec3::new(0.0, 2.0, 3.0));
+    ///     }
+    ///     // The stack is empty again.
+    ///     assert_eq!(Hierarchy::to_local_point(token, world_point), world_point);
+    /// );
+    /// ```
+    pub fn push_scoped<M: Into<Matrix>>(
+        token: &MainThreadToken,
+        transform: M,
+        parent_behavior: Option<HierarchyParent>,
+    ) -> HierarchyScope<'_> {
+        Self::push(token, transform, parent_behavior);
+        let depth = HIERARCHY_SCOPE_DEPTH.with(|depth| {
+            depth.set(depth.get() + 1);
+            depth.get()
+        });
+        HierarchyScope { depth, _token: token, _not_send: PhantomData }
+    }
+
+    /// Non canonical function pushing a transform built from a translation, a rotation and a scale, like
+    /// [`Hierarchy::push_scoped`].
+    /// * translation - The position of the local space.
+    /// * rotation - The orientation of the local space.
+    /// * scale - The scale of the local space.
+    /// * parent_behavior - How this matrix combines with the parent matrix below it. If None, has default value
+    ///   "Inherit"
+    ///
+    /// see also [`crate::maths::Matrix::trs`] [`HierarchyScope`]
+    pub fn push_trs<V: Into<Vec3>>(
+        token: &MainThreadToken,
+        translation: V,
+        rotation: impl Into<Quat>,
+        scale: V,
+        parent_behavior: Option<HierarchyParent>,
+    ) -> HierarchyScope<'_> {
+        let transform = Matrix::trs(&translation.into(), &rotation.into(), &scale.into());
+        Self::push_scoped(token, transform, parent_behavior)
+    }
+
     /// Converts a world space point into the local space of the current Hierarchy stack!
     /// <https://stereokit.net/Pages/StereoKit/Hierarchy/ToLocal.html>
     /// * world_point - A point in world space.
@@ -1058,6 +1752,7 @@ impl Pointer {
 /// <https://stereokit.net/Pages/StereoKit/HandJoint.html>
 #[derive(Debug, Copy, Clone)]
 #[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HandJoint {
     /// The center of the joint’s world space location.
     pub position: Vec3,
@@ -1270,7 +1965,7 @@ impl Hand {
 
 /// Represents an input from an XR headset’s controller!
 /// <https://stereokit.net/Pages/StereoKit/ControllerKey.html>
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(u32)]
 pub enum ControllerKey {
     /// Doesn’t represent a key, generally means this item has not been set to any particular value!
@@ -1562,6 +2257,123 @@ pub enum Key {
     Divide = 111,
 }
 
+impl Key {
+    /// Every key of this enum, in declaration order.
+    pub const ALL: [Key; 102] = [
+        Key::None,
+        Key::MouseLeft,
+        Key::MouseRight,
+        Key::MouseCenter,
+        Key::MouseForward,
+        Key::MouseBack,
+        Key::Backspace,
+        Key::Tab,
+        Key::Return,
+        Key::Shift,
+        Key::Ctrl,
+        Key::Alt,
+        Key::CapsLock,
+        Key::Esc,
+        Key::Space,
+        Key::End,
+        Key::Home,
+        Key::Left,
+        Key::Right,
+        Key::Up,
+        Key::Down,
+        Key::PageUp,
+        Key::PageDown,
+        Key::PrintScreen,
+        Key::KeyInsert,
+        Key::Del,
+        Key::Key0,
+        Key::Key1,
+        Key::Key2,
+        Key::Key3,
+        Key::Key4,
+        Key::Key5,
+        Key::Key6,
+        Key::Key7,
+        Key::Key8,
+        Key::Key9,
+        Key::A,
+        Key::B,
+        Key::C,
+        Key::D,
+        Key::E,
+        Key::F,
+        Key::G,
+        Key::H,
+        Key::I,
+        Key::J,
+        Key::K,
+        Key::L,
+        Key::M,
+        Key::N,
+        Key::O,
+        Key::P,
+        Key::Q,
+        Key::R,
+        Key::S,
+        Key::T,
+        Key::U,
+        Key::V,
+        Key::W,
+        Key::X,
+        Key::Y,
+        Key::Z,
+        Key::Numpad0,
+        Key::Numpad1,
+        Key::Numpad2,
+        Key::Numpad3,
+        Key::Numpad4,
+        Key::Numpad5,
+        Key::Numpad6,
+        Key::Numpad7,
+        Key::Numpad8,
+        Key::Numpad9,
+        Key::F1,
+        Key::F2,
+        Key::F3,
+        Key::F4,
+        Key::F5,
+        Key::F6,
+        Key::F7,
+        Key::F8,
+        Key::F9,
+        Key::F10,
+        Key::F11,
+        Key::F12,
+        Key::Comma,
+        Key::Period,
+        Key::SlashFwd,
+        Key::SlashBack,
+        Key::Semicolon,
+        Key::Apostrophe,
+        Key::BracketOpen,
+        Key::BracketClose,
+        Key::Minus,
+        Key::Equals,
+        Key::Backtick,
+        Key::LCmd,
+        Key::RCmd,
+        Key::Multiply,
+        Key::Add,
+        Key::Subtract,
+        Key::Decimal,
+        Key::Divide,
+    ];
+
+    /// Non canonical function to find a key from its name (the name of the enum variant, case insensitive).
+    ///
+    /// Returns None if no key has this name.
+    /// see also [`Key::ALL`]
+    pub fn from_name(name: impl AsRef<str>) -> Option<Key> {
+        let name = name.as_ref();
+        Key::ALL.iter().find(|key| format!("{key:?}").eq_ignore_ascii_case(name)).copied()
+    }
+}
+
 /// Input from the system come from this class! Hands, eyes, heads, mice and pointers!
 /// <https://stereokit.net/Pages/StereoKit/Input.html>
 pub struct Input;
@@ -1616,6 +2428,92 @@ extern "C" {
     pub fn input_fire_event(source: InputSource, input_event: BtnState, pointer: *const Pointer);
 }
 
+/// The number of steps kept by [`Input::hand_history`] and [`Input::controller_history`].
+pub const INPUT_HISTORY_FRAMES: usize = 16;
+
+/// The hands and the controllers of a step, left then right.
+struct InputFrame {
+    hands: [Hand; 2],
+    controllers: [Controller; 2],
+}
+
+/// Non canonical structure holding the input of several steps, recorded with [`Input::start_recording`] and replayed
+/// with [`Input::play_recording`] to write reproducible interaction tests.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputRecording {
+    /// One frame per recorded step.
+    pub frames: Vec<InputRecordFrame>,
+}
+
+/// The input of one step of an [`InputRecording`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputRecordFrame {
+    /// The pose of the head. It's recorded but can't be replayed.
+    pub head: Pose,
+    /// The left hand then the right hand.
+    pub hands: [InputRecordHand; 2],
+    /// The left controller then the right controller. They are recorded but can't be replayed.
+    pub controllers: [InputRecordController; 2],
+}
+
+/// A hand of an [`InputRecordFrame`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputRecordHand {
+    /// Is the hand tracked? An untracked hand is not overridden during the replay.
+    pub tracked: bool,
+    /// The 25 joints, as given to [`Input::hand_override`].
+    pub joints: [HandJoint; 25],
+}
+
+/// A controller of an [`InputRecordFrame`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputRecordController {
+    pub tracked: bool,
+    pub pose: Pose,
+    pub aim: Pose,
+    pub trigger: f32,
+    pub grip: f32,
+    pub stick: Vec2,
+    pub stick_click: bool,
+    pub x1: bool,
+    pub x2: bool,
+}
+
+/// The recording in progress and the replay in progress with the index of its next frame.
+#[derive(Default)]
+struct InputRecorder {
+    recording: Option<InputRecording>,
+    playing: Option<(InputRecording, usize)>,
+}
+
+/// The node info keeping the id of the original material of a highlighted controller component.
+const CONTROLLER_HIGHLIGHT_INFO: &str = "sk_rust_highlight_original";
+
+/// The joints of the XR_EXT_hand_tracking default joint set, used by the XR_FB_hand_tracking_mesh skin.
+const HAND_MESH_JOINTS: usize = 26;
+
+/// The hand mesh given by XR_FB_hand_tracking_mesh, skinned on the joints of StereoKit by [`Input::hand_mesh`].
+struct HandMeshFb {
+    mesh: Mesh,
+    /// The inverse of the bind pose of each joint.
+    bind_inverse: Vec<Matrix>,
+    vertices: Vec<Vertex>,
+    blend_indices: Vec<[usize; 4]>,
+    blend_weights: Vec<Vec4>,
+    frame: Option<u64>,
+}
+
+thread_local! {
+    static INPUT_HISTORY: RefCell<VecDeque<InputFrame>> = RefCell::new(VecDeque::with_capacity(INPUT_HISTORY_FRAMES));
+    /// The hand meshes, left then right. None until the first call, then Some(None) if there is no hand mesh.
+    static HAND_MESHES: RefCell<[Option<Option<HandMeshFb>>; 2]> = const { RefCell::new([None, None]) };
+    static INPUT_RECORDER: RefCell<InputRecorder> = RefCell::new(InputRecorder::default());
+}
+
 impl Input {
     /// When StereoKit is rendering the input source, this allows you to override the controller Model SK uses. The
     /// Model SK uses by default may be provided from the OpenXR runtime depending on extension support, but if not, SK
@@ -1673,6 +2571,214 @@ impl Input {
         unsafe { *input_hand(handed) }
     }
 
+    /// Non canonical function giving the hand of a previous step, to compute velocities, extrapolate or record the
+    /// hand. The last [`INPUT_HISTORY_FRAMES`] steps are recorded at the beginning of each step.
+    /// * hand - The left or the right hand.
+    /// * frames_back - How many steps back, 0 being the current step.
+    ///
+    /// Returns the hand, or None if `frames_back` is beyond the recorded steps or `hand` is Handed::Max.
+    /// see also [`Input::hand`] [`Input::controller_history`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Quat, Vec3}, system::{HandJoint, Handed, Input, INPUT_HISTORY_FRAMES}};
+    ///
+    /// let joint = |x: f32| HandJoint { position: Vec3::new(x, 0.0, -0.3), orientation: Quat::IDENTITY, radius: 0.01 };
+    /// number_of_steps = 6;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     // The hand moves 1cm to the right at each step.
+    ///     Input::hand_override(Handed::Right, &[joint(iter as f32 * 0.01); 25]);
+    /// );
+    /// let now = Input::hand_history(Handed::Right, 0).expect("the current step");
+    /// let before = Input::hand_history(Handed::Right, 1).expect("the previous step");
+    /// let moved = now.fingers[1][4].position.x - before.fingers[1][4].position.x;
+    /// assert!((moved - 0.01).abs() < 0.001);
+    ///
+    /// assert!(Input::hand_history(Handed::Right, INPUT_HISTORY_FRAMES).is_none());
+    /// assert!(Input::hand_history(Handed::Max, 0).is_none());
+    /// ```
+    pub fn hand_history(hand: Handed, frames_back: usize) -> Option<Hand> {
+        let index = Self::history_index(hand)?;
+        INPUT_HISTORY.with(|history| history.borrow().iter().rev().nth(frames_back).map(|frame| frame.hands[index]))
+    }
+
+    /// Non canonical function giving the controller of a previous step. The last [`INPUT_HISTORY_FRAMES`] steps are
+    /// recorded at the beginning of each step.
+    /// * handed - The left or the right controller.
+    /// * frames_back - How many steps back, 0 being the current step.
+    ///
+    /// Returns the controller, or None if `frames_back` is beyond the recorded steps or `handed` is Handed::Max.
+    /// see also [`Input::controller`] [`Input::hand_history`]
+    pub fn controller_history(handed: Handed, frames_back: usize) -> Option<Controller> {
+        let index = Self::history_index(handed)?;
+        INPUT_HISTORY
+            .with(|history| history.borrow().iter().rev().nth(frames_back).map(|frame| frame.controllers[index]))
+    }
+
+    fn history_index(handed: Handed) -> Option<usize> {
+        match handed {
+            Handed::Left => Some(0),
+            Handed::Right => Some(1),
+            Handed::Max => None,
+        }
+    }
+
+    /// Record the hands and the controllers for [`Input::hand_history`]. This is called at each step by Sk.
+    pub(crate) fn step_history() {
+        let frame = InputFrame {
+            hands: [Self::hand(Handed::Left), Self::hand(Handed::Right)],
+            controllers: [Self::controller(Handed::Left), Self::controller(Handed::Right)],
+        };
+        INPUT_HISTORY.with(|history| {
+            let mut history = history.borrow_mut();
+            if history.len() == INPUT_HISTORY_FRAMES {
+                history.pop_front();
+            }
+            history.push_back(frame);
+        });
+    }
+
+    /// Non canonical function starting to record the head, the hands and the controllers at each step, until
+    /// [`Input::stop_recording`]. A recording in progress is restarted.
+    ///
+    /// see also [`Input::stop_recording`] [`Input::play_recording`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Quat, Vec3}, system::{HandJoint, Handed, Input}};
+    ///
+    /// let joint = |x: f32| HandJoint { position: Vec3::new(x, 0.0, -0.3), orientation: Quat::IDENTITY, radius: 0.01 };
+    /// let mut recording = None;
+    /// number_of_steps = 16;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     match iter {
+    ///         0 => Input::start_recording(),
+    ///         // The hand moves 1cm to the right at each step.
+    ///         1..=4 => Input::hand_override(Handed::Right, &[joint(iter as f32 * 0.01); 25]),
+    ///         5 => {
+    ///             recording = Some(Input::stop_recording());
+    ///             Input::hand_override(Handed::Right, &[joint(-0.5); 25]);
+    ///         }
+    ///         6 => {
+    ///             Input::play_recording(recording.as_ref().unwrap());
+    ///             assert!(Input::is_playing_recording());
+    ///         }
+    ///         // A frame given at a step is seen at the next one.
+    ///         9..=12 => {
+    ///             let recorded = recording.as_ref().unwrap().frames[iter - 8].hands[1].joints[9].position;
+    ///             let replayed = Input::hand(Handed::Right).fingers[1][4].position;
+    ///             assert!(Vec3::distance(recorded, replayed) < 0.001);
+    ///         }
+    ///         _ => {}
+    ///     }
+    /// );
+    /// assert!(!Input::is_playing_recording());
+    /// let recording = recording.unwrap();
+    /// assert_eq!(recording.frames.len(), 5);
+    /// assert!((recording.frames[4].hands[1].joints[9].position.x - 0.04).abs() < 0.001);
+    /// ```
+    pub fn start_recording() {
+        INPUT_RECORDER.with_borrow_mut(|recorder| recorder.recording = Some(InputRecording::default()));
+    }
+
+    /// Non canonical function stopping the recording started with [`Input::start_recording`].
+    ///
+    /// Returns the recorded steps, empty if there was no recording.
+    /// see also [`Input::start_recording`] [`Input::play_recording`]
+    pub fn stop_recording() -> InputRecording {
+        INPUT_RECORDER.with_borrow_mut(|recorder| recorder.recording.take().unwrap_or_default())
+    }
+
+    /// Non canonical function telling if [`Input::start_recording`] is recording.
+    pub fn is_recording() -> bool {
+        INPUT_RECORDER.with_borrow(|recorder| recorder.recording.is_some())
+    }
+
+    /// Non canonical function replaying a recording, one frame per step, with [`Input::hand_override`]. A frame is
+    /// given at the beginning of a step and is seen by the next step. The hand overrides are cleared at the end of the
+    /// replay. The head and the controllers can't be overridden so they are not replayed.
+    /// * recording - The recording to replay. A replay in progress is replaced.
+    ///
+    /// see also [`Input::start_recording`] [`Input::stop_playing_recording`]
+    pub fn play_recording(recording: &InputRecording) {
+        INPUT_RECORDER.with_borrow_mut(|recorder| recorder.playing = Some((recording.clone(), 0)));
+    }
+
+    /// Non canonical function stopping the replay started with [`Input::play_recording`], and clearing the hand
+    /// overrides.
+    pub fn stop_playing_recording() {
+        if INPUT_RECORDER.with_borrow_mut(|recorder| recorder.playing.take()).is_some() {
+            Self::hand_clear_override(Handed::Left);
+            Self::hand_clear_override(Handed::Right);
+        }
+    }
+
+    /// Non canonical function telling if [`Input::play_recording`] is replaying.
+    pub fn is_playing_recording() -> bool {
+        INPUT_RECORDER.with_borrow(|recorder| recorder.playing.is_some())
+    }
+
+    /// Record and replay the input for [`Input::start_recording`] and [`Input::play_recording`]. This is called at
+    /// each step by Sk.
+    pub(crate) fn step_recording() {
+        let frame = INPUT_RECORDER.with_borrow(|recorder| recorder.recording.is_some()).then(|| InputRecordFrame {
+            head: Self::get_head(),
+            hands: [Handed::Left, Handed::Right].map(|handed| {
+                let hand = Self::hand(handed);
+                InputRecordHand {
+                    tracked: hand.tracked.is_active(),
+                    joints: std::array::from_fn(|i| hand.fingers[i / 5][i % 5]),
+                }
+            }),
+            controllers: [Handed::Left, Handed::Right].map(|handed| {
+                let controller = Self::controller(handed);
+                InputRecordController {
+                    tracked: controller.tracked.is_active(),
+                    pose: controller.pose,
+                    aim: controller.aim,
+                    trigger: controller.trigger,
+                    grip: controller.grip,
+                    stick: controller.stick,
+                    stick_click: controller.stick_click.is_active(),
+                    x1: controller.x1.is_active(),
+                    x2: controller.x2.is_active(),
+                }
+            }),
+        });
+
+        let replayed = INPUT_RECORDER.with_borrow_mut(|recorder| {
+            if let (Some(recording), Some(frame)) = (recorder.recording.as_mut(), frame) {
+                recording.frames.push(frame);
+            }
+            let (recording, next) = recorder.playing.as_mut()?;
+            let replayed = recording.frames.get(*next).copied();
+            *next += 1;
+            if replayed.is_none() {
+                recorder.playing = None;
+            }
+            Some(replayed)
+        });
+
+        match replayed {
+            Some(Some(frame)) => {
+                for (handed, hand) in [Handed::Left, Handed::Right].into_iter().zip(frame.hands) {
+                    if hand.tracked {
+                        Self::hand_override(handed, &hand.joints);
+                    } else {
+                        Self::hand_clear_override(handed);
+                    }
+                }
+            }
+            Some(None) => {
+                Self::hand_clear_override(Handed::Left);
+                Self::hand_clear_override(Handed::Right);
+            }
+            None => {}
+        }
+    }
+
     /// Clear out the override status from Input::hand_override, and restore the user’s control over it again.
     /// <https://stereokit.net/Pages/StereoKit/Input/HandClearOverride.html>
     /// * hand - Which hand are we clearing the override on?
@@ -1781,6 +2887,212 @@ impl Input {
         unsafe { input_hand_visible(hand, visible as Bool32T) };
     }
 
+    /// Non canonical function giving the articulated mesh of a hand, for stylized hand rendering. The mesh comes from
+    /// the XR_FB_hand_tracking_mesh extension, which must be requested before initializing sk with
+    /// [`BackendOpenXR::request_ext`]. Its vertices are skinned in world space on the joints of [`Input::hand`] once
+    /// per frame, so the same Mesh is updated in place and can be kept. Use [`Input::hand_visible`] to hide the hands
+    /// of StereoKit.
+    /// * hand - The hand of the mesh, Left or Right.
+    ///
+    /// Returns None if the runtime doesn't provide a hand mesh, then fall back to the joints.
+    /// see also [`Input::hand`]
+    /// ### Examples
+    /// ```
+    /// // The following line must be added before initializing sk:
+    /// stereokit_rust::system::BackendOpenXR::request_ext("XR_FB_hand_tracking_mesh");
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Matrix, material::Material, system::{BackendOpenXR, Handed, Input}};
+    ///
+    /// let hand_mesh_available = BackendOpenXR::ext_enabled("XR_FB_hand_tracking_mesh");
+    /// if !hand_mesh_available {
+    ///     assert!(Input::hand_mesh(Handed::Left).is_none());
+    ///     assert!(Input::hand_mesh(Handed::Right).is_none());
+    /// }
+    ///
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     for handed in [Handed::Left, Handed::Right] {
+    ///         if let Some(mesh) = Input::hand_mesh(handed) {
+    ///             mesh.draw(token, Material::pbr(), Matrix::IDENTITY, None, None);
+    ///         }
+    ///     }
+    /// );
+    /// ```
+    pub fn hand_mesh(hand: Handed) -> Option<Mesh> {
+        let index = match hand {
+            Handed::Left => 0,
+            Handed::Right => 1,
+            Handed::Max => return None,
+        };
+        HAND_MESHES.with_borrow_mut(|hand_meshes| {
+            let hand_mesh = hand_meshes[index].get_or_insert_with(|| Self::hand_mesh_load(hand));
+            let hand_mesh = hand_mesh.as_mut()?;
+            let frame = Time::get_frame();
+            if hand_mesh.frame != Some(frame) {
+                hand_mesh.frame = Some(frame);
+                Self::hand_mesh_skin(hand_mesh, &Self::hand(hand));
+            }
+            Some(hand_mesh.mesh.clone_ref())
+        })
+    }
+
+    /// Get the bind pose mesh of a hand from XR_FB_hand_tracking_mesh, with a hand tracker of our own.
+    fn hand_mesh_load(hand: Handed) -> Option<HandMeshFb> {
+        if !BackendOpenXR::ext_enabled("XR_FB_hand_tracking_mesh") {
+            return None;
+        }
+        let create_hand_tracker = BackendOpenXR::get_function::<CreateHandTrackerEXT>("xrCreateHandTrackerEXT")?;
+        let destroy_hand_tracker = BackendOpenXR::get_function::<DestroyHandTrackerEXT>("xrDestroyHandTrackerEXT")?;
+        let get_hand_mesh = BackendOpenXR::get_function::<GetHandMeshFB>("xrGetHandMeshFB")?;
+
+        let create_info = HandTrackerCreateInfoEXT {
+            ty: StructureType::HAND_TRACKER_CREATE_INFO_EXT,
+            next: null_mut(),
+            hand: if hand == Handed::Left { HandEXT::LEFT } else { HandEXT::RIGHT },
+            hand_joint_set: HandJointSetEXT::DEFAULT,
+        };
+        let mut tracker = HandTrackerEXT::from_raw(0);
+        let result =
+            unsafe { create_hand_tracker(Session::from_raw(BackendOpenXR::session()), &create_info, &mut tracker) };
+        if result != XrResult::SUCCESS {
+            Log::warn(format!("Input::hand_mesh: xrCreateHandTrackerEXT failed with {result:?}"));
+            return None;
+        }
+
+        // A first call gives the sizes, a second one the data.
+        let mut fb_mesh = HandTrackingMeshFB {
+            ty: StructureType::HAND_TRACKING_MESH_FB,
+            next: null_mut(),
+            joint_capacity_input: 0,
+            joint_count_output: 0,
+            joint_bind_poses: null_mut(),
+            joint_radii: null_mut(),
+            joint_parents: null_mut(),
+            vertex_capacity_input: 0,
+            vertex_count_output: 0,
+            vertex_positions: null_mut(),
+            vertex_normals: null_mut(),
+            vertex_u_vs: null_mut(),
+            vertex_blend_indices: null_mut(),
+            vertex_blend_weights: null_mut(),
+            index_capacity_input: 0,
+            index_count_output: 0,
+            indices: null_mut(),
+        };
+        let mut result = unsafe { get_hand_mesh(tracker, &mut fb_mesh) };
+        let (joint_count, vertex_count, index_count) = (
+            fb_mesh.joint_count_output as usize,
+            fb_mesh.vertex_count_output as usize,
+            fb_mesh.index_count_output as usize,
+        );
+        let mut bind_poses = vec![Posef::IDENTITY; joint_count];
+        let mut radii = vec![0.0f32; joint_count];
+        let mut parents = vec![HandJointEXT::PALM; joint_count];
+        let mut positions = vec![Vector3f::default(); vertex_count];
+        let mut normals = vec![Vector3f::default(); vertex_count];
+        let mut uvs = vec![Vector2f::default(); vertex_count];
+        let mut blend_indices = vec![Vector4sFB::default(); vertex_count];
+        let mut blend_weights = vec![Vector4f::default(); vertex_count];
+        let mut indices = vec![0i16; index_count];
+        if result == XrResult::SUCCESS {
+            fb_mesh.joint_capacity_input = joint_count as u32;
+            fb_mesh.joint_bind_poses = bind_poses.as_mut_ptr();
+            fb_mesh.joint_radii = radii.as_mut_ptr();
+            fb_mesh.joint_parents = parents.as_mut_ptr();
+            fb_mesh.vertex_capacity_input = vertex_count as u32;
+            fb_mesh.vertex_positions = positions.as_mut_ptr();
+            fb_mesh.vertex_normals = normals.as_mut_ptr();
+            fb_mesh.vertex_u_vs = uvs.as_mut_ptr();
+            fb_mesh.vertex_blend_indices = blend_indices.as_mut_ptr();
+            fb_mesh.vertex_blend_weights = blend_weights.as_mut_ptr();
+            fb_mesh.index_capacity_input = index_count as u32;
+            fb_mesh.indices = indices.as_mut_ptr();
+            result = unsafe { get_hand_mesh(tracker, &mut fb_mesh) };
+        }
+        unsafe { destroy_hand_tracker(tracker) };
+        if result != XrResult::SUCCESS || joint_count != HAND_MESH_JOINTS || vertex_count == 0 {
+            Log::warn(format!("Input::hand_mesh: xrGetHandMeshFB failed with {result:?} ({joint_count} joints)"));
+            return None;
+        }
+
+        let bind_inverse = bind_poses
+            .iter()
+            .map(|pose| {
+                let position = Vec3::new(pose.position.x, pose.position.y, pose.position.z);
+                let orientation =
+                    Quat::new(pose.orientation.x, pose.orientation.y, pose.orientation.z, pose.orientation.w);
+                Matrix::tr(&position, &orientation).get_inverse()
+            })
+            .collect();
+        let vertices = (0..vertex_count)
+            .map(|i| {
+                let (position, normal, uv) = (positions[i], normals[i], uvs[i]);
+                Vertex::new(
+                    Vec3::new(position.x, position.y, position.z),
+                    Vec3::new(normal.x, normal.y, normal.z),
+                    Some(Vec2::new(uv.x, uv.y)),
+                    None,
+                )
+            })
+            .collect();
+        let blend_indices = blend_indices
+            .iter()
+            .map(|joints| {
+                [joints.x, joints.y, joints.z, joints.w].map(|joint| (joint.max(0) as usize).min(HAND_MESH_JOINTS - 1))
+            })
+            .collect();
+        let blend_weights =
+            blend_weights.iter().map(|weights| Vec4::new(weights.x, weights.y, weights.z, weights.w)).collect();
+        // The FB mesh is clockwise, as StereoKit.
+        let indices: Vec<u32> = indices.iter().map(|index| *index as u16 as u32).collect();
+
+        let mut mesh = Mesh::new();
+        mesh.set_inds(&indices);
+        Some(HandMeshFb { mesh, bind_inverse, vertices, blend_indices, blend_weights, frame: None })
+    }
+
+    /// The world pose of a joint of the XR_EXT_hand_tracking joint set from the joints of StereoKit. The palm and the
+    /// wrist are not given with the XR orientation, they follow the middle finger metacarpal.
+    fn hand_mesh_joint(hand: &Hand, xr_joint: usize) -> Pose {
+        let joint = match xr_joint {
+            0 | 1 => hand.fingers[2][0],
+            2..=5 => hand.fingers[0][xr_joint - 1],
+            _ => hand.fingers[(xr_joint - 6) / 5 + 1][(xr_joint - 6) % 5],
+        };
+        Pose::new(joint.position, Some(joint.orientation))
+    }
+
+    /// Skin the bind pose vertices on the current joints of the hand, in world space.
+    fn hand_mesh_skin(hand_mesh: &mut HandMeshFb, hand: &Hand) {
+        let skin: Vec<Matrix> = (0..HAND_MESH_JOINTS)
+            .map(|xr_joint| {
+                // The palm and the wrist keep their bind offset to the middle finger metacarpal.
+                let bind_joint = if xr_joint < 2 { 11 } else { xr_joint };
+                let pose = Self::hand_mesh_joint(hand, xr_joint);
+                hand_mesh.bind_inverse[bind_joint] * Matrix::tr(&pose.position, &pose.orientation)
+            })
+            .collect();
+
+        let mut vertices = hand_mesh.vertices.clone();
+        for (i, vertex) in vertices.iter_mut().enumerate() {
+            let joints = hand_mesh.blend_indices[i];
+            let weights = hand_mesh.blend_weights[i];
+            let weights = [weights.x, weights.y, weights.z, weights.w];
+            let total: f32 = weights.iter().sum();
+            if total <= f32::EPSILON {
+                continue;
+            }
+            let (mut position, mut normal) = (Vec3::ZERO, Vec3::ZERO);
+            for (joint, weight) in joints.iter().zip(weights) {
+                position += skin[*joint].transform_point(vertex.pos) * (weight / total);
+                normal += skin[*joint].transform_normal(vertex.norm) * (weight / total);
+            }
+            vertex.pos = position;
+            vertex.norm = normal.get_normalized();
+        }
+        hand_mesh.mesh.set_verts(&vertices, true);
+    }
+
     /// This controls the visibility of StereoKit's finger glow effect on the UI. When true, SK will fill out global
     /// shader variable `sk_fingertip[2]` with the location of the pointer finger's tips. When false, or the hand is
     /// untracked, the location will be set to an unlikely faraway position.
@@ -1831,6 +3143,51 @@ impl Input {
         unsafe { input_key_inject_release(key) };
     }
 
+    /// Non canonical function requesting or hiding a soft keyboard, for a text field of your own. StereoKit surfaces
+    /// the OS provided soft keyboard where available, and its fallback keyboard when not. This is the same as
+    /// [`Platform::keyboard_show`].
+    /// * show - true to show the keyboard, false to hide it.
+    /// * keyboard_type - The kind of text to edit, so the layout of the keyboard can match it (Text, Number, Uri or
+    ///   Password).
+    ///
+    /// see also [`Input::keyboard_visible`] [`Platform::force_fallback_keyboard`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{system::{Input, TextContext}, util::Platform};
+    ///
+    /// // The fallback keyboard is available everywhere, even in the simulator.
+    /// Platform::force_fallback_keyboard(true);
+    /// let (mut shown, mut hidden) = (false, true);
+    /// number_of_steps = 6;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     match iter {
+    ///         0 => Input::show_keyboard(true, TextContext::Number),
+    ///         2 => {
+    ///             shown = Input::keyboard_visible();
+    ///             Input::show_keyboard(false, TextContext::Number);
+    ///         }
+    ///         4 => hidden = !Input::keyboard_visible(),
+    ///         _ => (),
+    ///     }
+    /// );
+    /// assert!(shown);
+    /// assert!(hidden);
+    /// ```
+    pub fn show_keyboard(show: bool, keyboard_type: TextContext) {
+        Platform::keyboard_show(show, keyboard_type)
+    }
+
+    /// Non canonical function checking if a soft keyboard is currently visible. This may be an OS provided keyboard or
+    /// StereoKit’s fallback keyboard, but will not indicate the presence of a physical keyboard. This is the same as
+    /// [`Platform::get_keyboard_visible`].
+    ///
+    /// see also [`Input::show_keyboard`]
+    pub fn keyboard_visible() -> bool {
+        Platform::get_keyboard_visible()
+    }
+
     /// This gets the pointer by filter based index.
     /// <https://stereokit.net/Pages/StereoKit/Input/Pointer.html>
     /// * index - Index of the Pointer.
@@ -1954,6 +3311,99 @@ impl Input {
         }
     }
 
+    /// Non canonical function listing the named visual nodes of the controller Model in use, like the trigger, the
+    /// thumbstick or the buttons when the Model is provided by OpenXR. The names depend on the runtime, SK's fallback
+    /// Model may have few or none.
+    /// * handed - The hand of the controller Model to inspect.
+    ///
+    /// see also [`Input::get_controller_model`] [`Input::highlight_controller_component`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{system::{Handed, Input}, util::named_colors::RED};
+    ///
+    /// // Outside of XR this is the fallback Model.
+    /// let components = Input::get_controller_components(Handed::Right);
+    /// assert!(components.iter().all(|name| !name.is_empty()));
+    ///
+    /// // Unknown component names are ignored.
+    /// Input::highlight_controller_component(Handed::Right, "not_a_component", Some(RED.into()));
+    /// for name in components.iter() {
+    ///     Input::highlight_controller_component(Handed::Right, name, Some(RED.into()));
+    ///     Input::highlight_controller_component(Handed::Right, name, None);
+    /// }
+    ///
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Input::highlight_controller_component(Handed::Left, "trigger", Some(RED.into()));
+    /// );
+    /// ```
+    pub fn get_controller_components(handed: Handed) -> Vec<String> {
+        let model = Self::get_controller_model(handed);
+        let nodes = model.get_nodes();
+        nodes
+            .visuals()
+            .filter_map(|node| node.get_name().filter(|name| !name.is_empty()).map(String::from))
+            .collect()
+    }
+
+    /// Non canonical function tinting a single named node of the controller Model in use, to show the user which
+    /// button to press. The node gets a copy of its material with the color tint, the original material is restored
+    /// when color is None. Unknown component names are ignored with a diagnostic log.
+    /// * handed - The hand of the controller Model to modify.
+    /// * name - The name of the component, see [`Input::get_controller_components`].
+    /// * color - The tint of the highlight, or None to remove the highlight.
+    ///
+    /// see also [`Input::get_controller_components`] [`crate::material::Material::color_tint`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{system::{Handed, Input}, util::named_colors::YELLOW};
+    ///
+    /// let components = Input::get_controller_components(Handed::Left);
+    /// if let Some(name) = components.first() {
+    ///     Input::highlight_controller_component(Handed::Left, name, Some(YELLOW.into()));
+    /// }
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    /// );
+    /// if let Some(name) = components.first() {
+    ///     Input::highlight_controller_component(Handed::Left, name, None);
+    /// }
+    /// ```
+    pub fn highlight_controller_component(handed: Handed, name: impl AsRef<str>, color: Option<Color128>) {
+        let name = name.as_ref();
+        let model = Self::get_controller_model(handed);
+        let nodes = model.get_nodes();
+        let Some(mut node) = nodes.find(name) else {
+            Log::diag(format!("Input::highlight_controller_component: no component {name} on {handed:?} controller"));
+            return;
+        };
+
+        // The id of the original material is kept in the node infos, so the highlight can be changed or removed.
+        let original_id = match node.get_infos().get_info(CONTROLLER_HIGHLIGHT_INFO) {
+            Some(original_id) => Some(original_id.to_string()),
+            None => node.get_material().map(|material| material.get_id().to_string()),
+        };
+        let Some(original) = original_id.as_ref().and_then(|original_id| Material::find(original_id).ok()) else {
+            Log::diag(format!("Input::highlight_controller_component: component {name} has no material"));
+            return;
+        };
+
+        match color {
+            Some(color) => {
+                let mut highlight = original.copy();
+                highlight.color_tint(color);
+                node.material(&highlight);
+                node.get_infos().set_info(CONTROLLER_HIGHLIGHT_INFO, original.get_id());
+            }
+            None => {
+                node.material(&original);
+                node.get_infos().remove_info(CONTROLLER_HIGHLIGHT_INFO);
+            }
+        }
+    }
+
     /// This is the state of the controller’s menu button, this is not attached to any particular hand, so it’s
     /// independent of a left or right controller.
     /// <https://stereokit.net/Pages/StereoKit/Input/ControllerMenuButton.html>
@@ -2023,76 +3473,614 @@ impl Input {
     }
 }
 
-/// Used to represent lines for the line drawing functions! This is just a snapshot of information about each individual
-/// point on a line.
-/// <https://stereokit.net/Pages/StereoKit/LinePoint.html>
-#[derive(Debug, Copy, Clone, PartialEq)]
-#[repr(C)]
-pub struct LinePoint {
-    pub pt: Vec3,
-    pub thickness: f32,
-    pub color: Color32,
+/// The pose sources an [`ActionBinding::Pose`] can read from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ActionPoseSource {
+    /// The grip pose of the controller, see [`Controller::pose`].
+    ControllerGrip,
+    /// The aim pose of the controller, see [`Controller::aim`].
+    ControllerAim,
+    /// The palm pose of the controller, see [`Controller::palm`].
+    ControllerPalm,
+    /// The aim pose of the hand, see [`Hand::aim`].
+    HandAim,
+    /// The palm pose of the hand, see [`Hand::palm`].
+    HandPalm,
 }
 
-/// A line drawing class! This is an easy way to visualize lines or relationships between objects. The current
-/// implementation uses a quad strip that always faces the user, via vertex shader manipulation.
-/// <https://stereokit.net/Pages/StereoKit/Lines.html>
-pub struct Lines;
+/// The analog values an [`ActionBinding::Axis`] can read from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ActionAxis {
+    /// The controller trigger, from 0 to 1.
+    Trigger,
+    /// The controller grip, from 0 to 1.
+    Grip,
+    /// The horizontal axis of the controller stick, from -1 to 1.
+    StickX,
+    /// The vertical axis of the controller stick, from -1 to 1.
+    StickY,
+}
 
-extern "C" {
-    pub fn line_add(start: Vec3, end: Vec3, color_start: Color32, color_end: Color32, thickness: f32);
-    pub fn line_addv(start: LinePoint, end: LinePoint);
-    pub fn line_add_axis(pose: Pose, size: f32);
-    pub fn line_add_list(points: *const Vec3, count: i32, color: Color32, thickness: f32);
-    pub fn line_add_listv(in_arr_points: *const LinePoint, count: i32);
+/// The hand gestures an [`ActionBinding::Gesture`] can read from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ActionGesture {
+    /// The hand is pinching, see [`Hand::pinch`].
+    Pinch,
+    /// The hand is gripping, see [`Hand::grip`].
+    Grip,
 }
 
-impl Lines {
-    /// Adds a line to the environment for the current frame.
-    /// <https://stereokit.net/Pages/StereoKit/Lines/Add.html>
-    /// * color_end - If None, uses color_start.
-    ///
-    /// see also [crate::system::line_add]
-    pub fn add<V: Into<Vec3>>(
-        _token: &MainThreadToken,
-        start: V,
-        end: V,
-        color_start: Color32,
-        color_end: Option<Color32>,
-        thickness: f32,
-    ) {
-        let color_end = color_end.unwrap_or(color_start);
-        unsafe { line_add(start.into(), end.into(), color_start, color_end, thickness) }
-    }
+/// Non canonical structure. A binding between a named action of an [`ActionSet`] and a physical input.
+///
+/// As text, a binding is written as a list of lower case words:
+/// `key G`, `button right x1`, `axis left trigger`, `gesture right pinch`, `pose left controller_aim` or `head`.
+/// see also [`ActionSet`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ActionBinding {
+    /// A keyboard key or a mouse button.
+    Key(Key),
+    /// A controller button. Analog buttons (trigger and grip) are active above half of their range.
+    Button(Handed, ControllerKey),
+    /// An analog value of a controller. It is active if its absolute value is above 0.5.
+    Axis(Handed, ActionAxis),
+    /// A hand gesture. Its float value is the activation of the gesture.
+    Gesture(Handed, ActionGesture),
+    /// A pose of a controller or a hand. It is active while tracked.
+    Pose(Handed, ActionPoseSource),
+    /// The pose of the head. Always active.
+    Head,
+}
 
-    /// Adds a line based on a ray to the environment for the current frame.
-    /// <https://stereokit.net/Pages/StereoKit/Lines/Add.html>
-    /// * color_end - If None, uses color_start.
-    ///
-    /// see also [crate::system::line_add]
-    pub fn add_ray<R: Into<Ray>>(
-        _token: &MainThreadToken,
-        ray: R,
-        length: f32,
-        color_start: Color32,
-        color_end: Option<Color32>,
-        thickness: f32,
-    ) {
-        let color_end = color_end.unwrap_or(color_start);
-        let ray: Ray = ray.into();
-        unsafe { line_add(ray.position, ray.get_at(length), color_start, color_end, thickness) }
+/// The threshold above which an analog input is considered as active.
+const ACTION_ANALOG_THRESHOLD: f32 = 0.5;
+
+impl ActionBinding {
+    /// The current float value of this binding. Buttons and keys are 0.0 or 1.0, analog inputs are their raw value.
+    pub fn float(&self) -> f32 {
+        match *self {
+            ActionBinding::Key(key) => Self::btn_float(Input::key(key)),
+            ActionBinding::Button(_, ControllerKey::Menu) => Self::btn_float(Input::get_controller_menu_button()),
+            ActionBinding::Button(handed, key) => {
+                let controller = Input::controller(handed);
+                match key {
+                    ControllerKey::Trigger => controller.trigger,
+                    ControllerKey::Grip => controller.grip,
+                    ControllerKey::X1_ => Self::btn_float(controller.x1),
+                    ControllerKey::X2 => Self::btn_float(controller.x2),
+                    ControllerKey::Stick => Self::btn_float(controller.stick_click),
+                    _ => 0.0,
+                }
+            }
+            ActionBinding::Axis(handed, axis) => {
+                let controller = Input::controller(handed);
+                match axis {
+                    ActionAxis::Trigger => controller.trigger,
+                    ActionAxis::Grip => controller.grip,
+                    ActionAxis::StickX => controller.stick.x,
+                    ActionAxis::StickY => controller.stick.y,
+                }
+            }
+            ActionBinding::Gesture(handed, gesture) => {
+                let hand = Input::hand(handed);
+                match gesture {
+                    ActionGesture::Pinch => hand.pinch_activation,
+                    ActionGesture::Grip => hand.grip_activation,
+                }
+            }
+            ActionBinding::Pose(_, _) | ActionBinding::Head => {
+                if self.pose().is_some() {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+        }
     }
 
-    /// Adds a line from a list of line points to the environment. This does not close the path, so if you want it
-    /// closed, you’ll have to add an extra point or two at the end yourself!
-    /// <https://stereokit.net/Pages/StereoKit/Lines/Add.html>
-    /// * color_end - If None, uses color_start.
+    /// Is this binding currently active?
+    pub fn is_active(&self) -> bool {
+        match *self {
+            ActionBinding::Key(key) => Input::key(key).is_active(),
+            ActionBinding::Button(_, ControllerKey::Trigger | ControllerKey::Grip) => {
+                self.float() > ACTION_ANALOG_THRESHOLD
+            }
+            ActionBinding::Button(_, _) => self.float() > 0.0,
+            ActionBinding::Axis(_, _) => self.float().abs() > ACTION_ANALOG_THRESHOLD,
+            ActionBinding::Gesture(handed, gesture) => {
+                let hand = Input::hand(handed);
+                match gesture {
+                    ActionGesture::Pinch => hand.pinch.is_active(),
+                    ActionGesture::Grip => hand.grip.is_active(),
+                }
+            }
+            ActionBinding::Pose(_, _) | ActionBinding::Head => self.pose().is_some(),
+        }
+    }
+
+    /// The current pose of this binding if it is a tracked pose binding.
+    pub fn pose(&self) -> Option<Pose> {
+        match *self {
+            ActionBinding::Pose(handed, source) => match source {
+                ActionPoseSource::ControllerGrip
+                | ActionPoseSource::ControllerAim
+                | ActionPoseSource::ControllerPalm => {
+                    let controller = Input::controller(handed);
+                    if !controller.tracked.is_active() {
+                        return None;
+                    }
+                    Some(match source {
+                        ActionPoseSource::ControllerGrip => controller.pose,
+                        ActionPoseSource::ControllerAim => controller.aim,
+                        _ => controller.palm,
+                    })
+                }
+                ActionPoseSource::HandAim | ActionPoseSource::HandPalm => {
+                    let hand = Input::hand(handed);
+                    if !hand.tracked.is_active() {
+                        return None;
+                    }
+                    Some(if source == ActionPoseSource::HandAim { hand.aim } else { hand.palm })
+                }
+            },
+            ActionBinding::Head => Some(Input::get_head()),
+            _ => None,
+        }
+    }
+
+    fn btn_float(state: BtnState) -> f32 {
+        if state.is_active() {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn handed_name(handed: Handed) -> &'static str {
+        if handed == Handed::Left {
+            "left"
+        } else {
+            "right"
+        }
+    }
+}
+
+impl fmt::Display for ActionBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActionBinding::Key(key) => write!(f, "key {key:?}"),
+            ActionBinding::Button(handed, key) => {
+                let key = match key {
+                    ControllerKey::Trigger => "trigger",
+                    ControllerKey::Grip => "grip",
+                    ControllerKey::X1_ => "x1",
+                    ControllerKey::X2 => "x2",
+                    ControllerKey::Stick => "stick",
+                    ControllerKey::Menu => "menu",
+                    ControllerKey::None_ => "none",
+                };
+                write!(f, "button {} {key}", Self::handed_name(*handed))
+            }
+            ActionBinding::Axis(handed, axis) => {
+                let axis = match axis {
+                    ActionAxis::Trigger => "trigger",
+                    ActionAxis::Grip => "grip",
+                    ActionAxis::StickX => "stick_x",
+                    ActionAxis::StickY => "stick_y",
+                };
+                write!(f, "axis {} {axis}", Self::handed_name(*handed))
+            }
+            ActionBinding::Gesture(handed, gesture) => {
+                let gesture = match gesture {
+                    ActionGesture::Pinch => "pinch",
+                    ActionGesture::Grip => "grip",
+                };
+                write!(f, "gesture {} {gesture}", Self::handed_name(*handed))
+            }
+            ActionBinding::Pose(handed, source) => {
+                let source = match source {
+                    ActionPoseSource::ControllerGrip => "controller_grip",
+                    ActionPoseSource::ControllerAim => "controller_aim",
+                    ActionPoseSource::ControllerPalm => "controller_palm",
+                    ActionPoseSource::HandAim => "hand_aim",
+                    ActionPoseSource::HandPalm => "hand_palm",
+                };
+                write!(f, "pose {} {source}", Self::handed_name(*handed))
+            }
+            ActionBinding::Head => write!(f, "head"),
+        }
+    }
+}
+
+impl std::str::FromStr for ActionBinding {
+    type Err = StereoKitError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let error = |reason: &str| StereoKitError::ActionBinding(text.to_string(), reason.to_string());
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let handed = |word: Option<&&str>| match word.map(|w| w.to_ascii_lowercase()).as_deref() {
+            Some("left") => Ok(Handed::Left),
+            Some("right") => Ok(Handed::Right),
+            _ => Err(error("expecting left or right")),
+        };
+        let last = words.get(2).map(|w| w.to_ascii_lowercase()).unwrap_or_default();
+        let binding = match words.first().map(|w| w.to_ascii_lowercase()).as_deref() {
+            Some("key") => {
+                ActionBinding::Key(words.get(1).and_then(Key::from_name).ok_or_else(|| error("unknown key"))?)
+            }
+            Some("button") => {
+                let key = match last.as_str() {
+                    "trigger" => ControllerKey::Trigger,
+                    "grip" => ControllerKey::Grip,
+                    "x1" => ControllerKey::X1_,
+                    "x2" => ControllerKey::X2,
+                    "stick" => ControllerKey::Stick,
+                    "menu" => ControllerKey::Menu,
+                    _ => return Err(error("unknown controller button")),
+                };
+                ActionBinding::Button(handed(words.get(1))?, key)
+            }
+            Some("axis") => {
+                let axis = match last.as_str() {
+                    "trigger" => ActionAxis::Trigger,
+                    "grip" => ActionAxis::Grip,
+                    "stick_x" => ActionAxis::StickX,
+                    "stick_y" => ActionAxis::StickY,
+                    _ => return Err(error("unknown controller axis")),
+                };
+                ActionBinding::Axis(handed(words.get(1))?, axis)
+            }
+            Some("gesture") => {
+                let gesture = match last.as_str() {
+                    "pinch" => ActionGesture::Pinch,
+                    "grip" => ActionGesture::Grip,
+                    _ => return Err(error("unknown hand gesture")),
+                };
+                ActionBinding::Gesture(handed(words.get(1))?, gesture)
+            }
+            Some("pose") => {
+                let source = match last.as_str() {
+                    "controller_grip" => ActionPoseSource::ControllerGrip,
+                    "controller_aim" => ActionPoseSource::ControllerAim,
+                    "controller_palm" => ActionPoseSource::ControllerPalm,
+                    "hand_aim" => ActionPoseSource::HandAim,
+                    "hand_palm" => ActionPoseSource::HandPalm,
+                    _ => return Err(error("unknown pose source")),
+                };
+                ActionBinding::Pose(handed(words.get(1))?, source)
+            }
+            Some("head") => ActionBinding::Head,
+            _ => return Err(error("unknown binding kind")),
+        };
+        let expected_words = match binding {
+            ActionBinding::Head => 1,
+            ActionBinding::Key(_) => 2,
+            _ => 3,
+        };
+        if words.len() != expected_words {
+            return Err(error("wrong number of words"));
+        }
+        Ok(binding)
+    }
+}
+
+/// Non canonical structure. A set of named actions bound to controller buttons, keys or hand gestures. Apps query the
+/// actions by name each frame instead of reading the devices directly, so the bindings can be changed at runtime or
+/// loaded from a settings file.
+///
+/// An action may have many bindings: it is active if one of them is active, its float value is the one with the
+/// largest magnitude and its pose is the first tracked one. The text form (see [`ActionSet::to_text`]) has one
+/// `name = binding` line per binding, empty lines and lines starting with `#` are ignored.
+/// see also [`ActionBinding`] [`Input`]
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::system::{ActionBinding, ActionSet, Handed, Input, Key, ActionAxis};
+///
+/// let mut actions = ActionSet::new();
+/// actions
+///     .bind("grab", ActionBinding::Key(Key::G))
+///     .bind("throttle", ActionBinding::Axis(Handed::Right, ActionAxis::Trigger));
+///
+/// // The bindings can be saved and restored.
+/// let text = actions.to_text();
+/// assert_eq!(text, "grab = key G\nthrottle = axis right trigger\n");
+/// let actions = ActionSet::from_text(&text).expect("the bindings should parse");
+///
+/// let mut grabbed = false;
+/// number_of_steps = 4;
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     match iter {
+///         0 => Input::key_inject_press(Key::G),
+///         2 => {
+///             grabbed = actions.is_active("grab");
+///             Input::key_inject_release(Key::G);
+///         }
+///         _ => (),
+///     }
+///     assert_eq!(actions.float("throttle"), 0.0);
+///     assert!(actions.pose("aim").is_none());
+/// );
+/// assert!(grabbed);
+/// assert!(!actions.is_active("grab"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ActionSet {
+    actions: Vec<(String, Vec<ActionBinding>)>,
+}
+
+impl ActionSet {
+    /// Creates an empty action set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a binding to the action `name`, creating the action if needed. A binding already there is not added twice.
+    pub fn bind(&mut self, name: impl AsRef<str>, binding: ActionBinding) -> &mut Self {
+        let name = name.as_ref();
+        match self.actions.iter_mut().find(|(action, _)| action == name) {
+            Some((_, bindings)) => {
+                if !bindings.contains(&binding) {
+                    bindings.push(binding)
+                }
+            }
+            None => self.actions.push((name.to_string(), vec![binding])),
+        }
+        self
+    }
+
+    /// Removes a binding from the action `name`. Returns true if the binding was there.
+    pub fn unbind(&mut self, name: impl AsRef<str>, binding: ActionBinding) -> bool {
+        let name = name.as_ref();
+        let Some((_, bindings)) = self.actions.iter_mut().find(|(action, _)| action == name) else {
+            return false;
+        };
+        let len = bindings.len();
+        bindings.retain(|b| *b != binding);
+        len != bindings.len()
+    }
+
+    /// Removes the action `name` and all its bindings. Returns true if the action was there.
+    pub fn remove(&mut self, name: impl AsRef<str>) -> bool {
+        let name = name.as_ref();
+        let len = self.actions.len();
+        self.actions.retain(|(action, _)| action != name);
+        len != self.actions.len()
+    }
+
+    /// Removes all the actions.
+    pub fn clear(&mut self) {
+        self.actions.clear();
+    }
+
+    /// The bindings of the action `name`. Empty if this action doesn't exist.
+    pub fn bindings(&self, name: impl AsRef<str>) -> &[ActionBinding] {
+        let name = name.as_ref();
+        self.actions
+            .iter()
+            .find(|(action, _)| action == name)
+            .map(|(_, bindings)| bindings.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// The names of all the actions in this set.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.actions.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Is one of the bindings of the action `name` active this frame? False if this action doesn't exist.
+    pub fn is_active(&self, name: impl AsRef<str>) -> bool {
+        self.bindings(name).iter().any(|binding| binding.is_active())
+    }
+
+    /// The float value of the action `name`, the one with the largest magnitude among its bindings. 0.0 if this
+    /// action doesn't exist.
+    pub fn float(&self, name: impl AsRef<str>) -> f32 {
+        self.bindings(name)
+            .iter()
+            .map(|binding| binding.float())
+            .fold(0.0, |a, b| if b.abs() > a.abs() { b } else { a })
+    }
+
+    /// The pose of the action `name`, from its first tracked pose binding. None if there is no such binding.
+    pub fn pose(&self, name: impl AsRef<str>) -> Option<Pose> {
+        self.bindings(name).iter().find_map(|binding| binding.pose())
+    }
+
+    /// Writes all the bindings as text, one `name = binding` line per binding.
+    /// see also [`ActionSet::from_text`]
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for (name, bindings) in &self.actions {
+            for binding in bindings {
+                text.push_str(&format!("{name} = {binding}\n"));
+            }
+        }
+        text
+    }
+
+    /// Reads an action set from the text written by [`ActionSet::to_text`].
+    ///
+    /// Returns an error on the first line that is not a valid binding.
+    pub fn from_text(text: impl AsRef<str>) -> Result<Self, StereoKitError> {
+        let mut actions = ActionSet::new();
+        for line in text.as_ref().lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((name, binding)) = line.split_once('=') else {
+                return Err(StereoKitError::ActionBinding(line.to_string(), "expecting name = binding".to_string()));
+            };
+            actions.bind(name.trim(), binding.parse()?);
+        }
+        Ok(actions)
+    }
+}
+
+/// Used to represent lines for the line drawing functions! This is just a snapshot of information about each individual
+/// point on a line.
+/// <https://stereokit.net/Pages/StereoKit/LinePoint.html>
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+pub struct LinePoint {
+    pub pt: Vec3,
+    pub thickness: f32,
+    pub color: Color32,
+}
+
+/// A line drawing class! This is an easy way to visualize lines or relationships between objects. The current
+/// implementation uses a quad strip that always faces the user, via vertex shader manipulation.
+/// <https://stereokit.net/Pages/StereoKit/Lines.html>
+pub struct Lines;
+
+extern "C" {
+    pub fn line_add(start: Vec3, end: Vec3, color_start: Color32, color_end: Color32, thickness: f32);
+    pub fn line_addv(start: LinePoint, end: LinePoint);
+    pub fn line_add_axis(pose: Pose, size: f32);
+    pub fn line_add_list(points: *const Vec3, count: i32, color: Color32, thickness: f32);
+    pub fn line_add_listv(in_arr_points: *const LinePoint, count: i32);
+}
+
+impl Lines {
+    /// Adds a line to the environment for the current frame.
+    /// <https://stereokit.net/Pages/StereoKit/Lines/Add.html>
+    /// * color_end - If None, uses color_start.
+    ///
+    /// see also [crate::system::line_add]
+    pub fn add<V: Into<Vec3>>(
+        _token: &MainThreadToken,
+        start: V,
+        end: V,
+        color_start: Color32,
+        color_end: Option<Color32>,
+        thickness: f32,
+    ) {
+        let color_end = color_end.unwrap_or(color_start);
+        unsafe { line_add(start.into(), end.into(), color_start, color_end, thickness) }
+    }
+
+    /// Adds a line based on a ray to the environment for the current frame.
+    /// <https://stereokit.net/Pages/StereoKit/Lines/Add.html>
+    /// * color_end - If None, uses color_start.
+    ///
+    /// see also [crate::system::line_add]
+    pub fn add_ray<R: Into<Ray>>(
+        _token: &MainThreadToken,
+        ray: R,
+        length: f32,
+        color_start: Color32,
+        color_end: Option<Color32>,
+        thickness: f32,
+    ) {
+        let color_end = color_end.unwrap_or(color_start);
+        let ray: Ray = ray.into();
+        unsafe { line_add(ray.position, ray.get_at(length), color_start, color_end, thickness) }
+    }
+
+    /// Adds a line from a list of line points to the environment. This does not close the path, so if you want it
+    /// closed, you’ll have to add an extra point or two at the end yourself!
+    /// <https://stereokit.net/Pages/StereoKit/Lines/Add.html>
+    /// * color_end - If None, uses color_start.
     ///
     /// see also [crate::system::line_add]
     pub fn add_list(_token: &MainThreadToken, points: &[LinePoint]) {
         unsafe { line_add_listv(points.as_ptr(), points.len() as i32) }
     }
 
+    /// Non canonical function adding a cubic Bézier curve to the environment for the current frame. The curve goes
+    /// from p0 to p3, and is pulled toward the control points c0 and c1.
+    /// * color_end - If None, uses color_start. Otherwise the color is interpolated along the curve.
+    /// * segments - If None, the number of segments depends on the spread of the control points (1 per cm, from 4 to
+    ///   64).
+    ///
+    /// see also [`Lines::bezier_points`] [crate::system::line_add_listv]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec3, system::Lines, util::named_colors::{BLUE, RED}};
+    ///
+    /// filename_scr = "screenshots/lines_bezier.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     // An S-curve
+    ///     Lines::add_bezier(token, [-0.5, -0.3, 0.0], [0.5, -0.3, 0.0], [-0.5, 0.3, 0.0], [0.5, 0.3, 0.0],
+    ///                       RED, Some(BLUE), None, 0.02);
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/lines_bezier.jpeg" alt="screenshot" width="200">
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_bezier<V: Into<Vec3>>(
+        token: &MainThreadToken,
+        p0: V,
+        c0: V,
+        c1: V,
+        p3: V,
+        color_start: Color32,
+        color_end: Option<Color32>,
+        segments: Option<i32>,
+        thickness: f32,
+    ) {
+        let points = Self::bezier_points(p0, c0, c1, p3, color_start, color_end, segments, thickness);
+        Self::add_list(token, &points);
+    }
+
+    /// Non canonical function giving the line points of a cubic Bézier curve, as drawn by [`Lines::add_bezier`]. The
+    /// first point is p0 and the last one is p3.
+    /// * color_end - If None, uses color_start. Otherwise the color is interpolated along the curve.
+    /// * segments - If None, the number of segments depends on the spread of the control points (1 per cm, from 4 to
+    ///   64).
+    ///
+    /// see also [`Lines::add_bezier`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::{maths::Vec3, system::Lines, util::named_colors::RED};
+    ///
+    /// let p3 = Vec3::new(0.5, 0.3, 0.0);
+    /// let points = Lines::bezier_points(Vec3::new(-0.5, -0.3, 0.0), Vec3::new(0.5, -0.3, 0.0),
+    ///                                   Vec3::new(-0.5, 0.3, 0.0), p3, RED, None, Some(16), 0.01);
+    /// assert_eq!(points.len(), 17);
+    /// assert_eq!(points[0].pt, Vec3::new(-0.5, -0.3, 0.0));
+    /// assert_eq!(points[16].pt, p3);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn bezier_points<V: Into<Vec3>>(
+        p0: V,
+        c0: V,
+        c1: V,
+        p3: V,
+        color_start: Color32,
+        color_end: Option<Color32>,
+        segments: Option<i32>,
+        thickness: f32,
+    ) -> Vec<LinePoint> {
+        let (p0, c0, c1, p3): (Vec3, Vec3, Vec3, Vec3) = (p0.into(), c0.into(), c1.into(), p3.into());
+        let segments = match segments {
+            Some(segments) => segments.max(1),
+            None => {
+                let spread = Vec3::distance(p0, c0) + Vec3::distance(c0, c1) + Vec3::distance(c1, p3);
+                ((spread * 100.0) as i32).clamp(4, 64)
+            }
+        };
+
+        let mut points = Vec::with_capacity(segments as usize + 1);
+        for i in 0..=segments {
+            let t = i as f32 / segments as f32;
+            let u = 1.0 - t;
+            // The last point is exactly p3.
+            let pt = if i == segments {
+                p3
+            } else {
+                p0 * (u * u * u) + c0 * (3.0 * u * u * t) + c1 * (3.0 * u * t * t) + p3 * (t * t * t)
+            };
+            let color = match color_end {
+                Some(color_end) => Color32::from(Color128::lerp(color_start.into(), color_end.into(), t)),
+                None => color_start,
+            };
+            points.push(LinePoint { pt, thickness, color });
+        }
+        points
+    }
+
     /// Displays an RGB/XYZ axis widget at the pose! Each line is extended along the positive direction of each axis, so
     /// the red line is +X, green is +Y, and blue is +Z. A white line is drawn along -Z to indicate the Forward vector
     /// of the pose (-Z is forward in StereoKit).
@@ -2142,6 +4130,193 @@ impl Lines {
             None => unsafe { line_add_axis(at_pose, size) },
         }
     }
+
+    /// Non canonical function adding the 12 edges of an axis aligned bounding box to the environment for the current
+    /// frame. If the bounds have no size, a small 3 axis cross is drawn at the center instead, so they remain visible.
+    /// * transform - If None, the bounds are drawn in world space. Otherwise each corner of the bounds is transformed
+    ///   by this matrix, so the box follows the rotation and scale of the transform.
+    ///
+    /// see also [`Bounds::corners`] [crate::system::line_add]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Bounds, Matrix, Quat, Vec3}, system::Lines,
+    ///                      util::named_colors::{GREEN, RED, WHITE}};
+    ///
+    /// let unit = Bounds::new(Vec3::new(0.0, 0.0, -1.0), Vec3::new(0.5, 0.5, 0.5));
+    /// let transform = Matrix::trs(&[0.6, 0.0, -1.2].into(), &Quat::from_angles(30.0, 45.0, 0.0), &Vec3::ONE);
+    /// let degenerate = Bounds::new(Vec3::new(-0.6, 0.0, -1.0), Vec3::ZERO);
+    ///
+    /// filename_scr = "screenshots/lines_bounds.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Lines::add_bounds(token, unit, None, WHITE, 0.01);
+    ///     Lines::add_bounds(token, Bounds::new(Vec3::ZERO, Vec3::new(0.3, 0.2, 0.3)), Some(transform), RED, 0.01);
+    ///     // A zero size bounds is drawn as a point marker, it must not panic.
+    ///     Lines::add_bounds(token, degenerate, None, GREEN, 0.01);
+    ///     Lines::add_bounds(token, degenerate, Some(transform), GREEN, 0.01);
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/lines_bounds.jpeg" alt="screenshot" width="200">
+    pub fn add_bounds(
+        token: &MainThreadToken,
+        bounds: Bounds,
+        transform: Option<Matrix>,
+        color: Color32,
+        thickness: f32,
+    ) {
+        let to_world = |point: Vec3| match transform {
+            Some(transform) => transform.transform_point(point),
+            None => point,
+        };
+
+        if bounds.dimensions.length_sq() <= f32::EPSILON * f32::EPSILON {
+            let center = to_world(bounds.center);
+            let half = thickness.max(0.005) * 2.0;
+            for axis in [Vec3::X, Vec3::Y, Vec3::Z] {
+                Self::add(token, center - axis * half, center + axis * half, color, None, thickness);
+            }
+            return;
+        }
+
+        let corners = bounds.corners().map(to_world);
+        // The corners are indexed by their bits (1: X, 2: Z, 4: Y), an edge links two corners differing by one bit.
+        for (index, corner) in corners.iter().enumerate() {
+            for bit in [1, 2, 4] {
+                if index & bit == 0 {
+                    Self::add(token, *corner, corners[index | bit], color, None, thickness);
+                }
+            }
+        }
+    }
+}
+
+/// Non canonical structure accumulating lines to submit them all to StereoKit with a single call, instead of one call
+/// per [`Lines::add`]. The segments are joined by invisible links of zero thickness, so they stay disconnected. The
+/// points are kept after [`LineBatch::flush`]: a static batch can be flushed at each frame, while a dynamic one is
+/// emptied with [`LineBatch::clear`] which keeps its allocation for the next frame.
+///
+/// see also [`Lines`]
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{maths::Vec3, system::{LineBatch, LinePoint},
+///                      util::named_colors::{BLUE, GREEN, RED, YELLOW}};
+///
+/// let mut batch = LineBatch::with_capacity(1000);
+/// for i in 0..1000 {
+///     let angle = i as f32 * 0.36f32.to_radians();
+///     let (inner, outer) = (0.2, 0.25 + 0.05 * (angle * 8.0).sin());
+///     let direction = Vec3::new(angle.cos(), angle.sin(), 0.0);
+///     batch.add(direction * inner, direction * outer, RED, Some(YELLOW), 0.002);
+/// }
+/// assert_eq!(batch.get_segment_count(), 1000);
+///
+/// // A polyline, like Lines::add_list.
+/// batch.add_list(&[
+///     LinePoint { pt: Vec3::new(-0.1, -0.1, 0.0), thickness: 0.01, color: GREEN },
+///     LinePoint { pt: Vec3::new(0.0, 0.1, 0.0), thickness: 0.01, color: BLUE },
+///     LinePoint { pt: Vec3::new(0.1, -0.1, 0.0), thickness: 0.01, color: GREEN },
+/// ]);
+/// assert_eq!(batch.get_segment_count(), 1002);
+///
+/// filename_scr = "screenshots/line_batch.jpeg";
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     // A single call to StereoKit for the 1002 segments that Lines::add would submit one by one.
+///     assert_eq!(batch.flush(token), 1);
+/// );
+///
+/// batch.clear();
+/// assert!(batch.is_empty());
+/// assert_eq!(batch.get_segment_count(), 0);
+/// batch.add([0.0, 0.0, 0.0], [0.0, 1.0, 0.0], RED, None, 0.01);
+/// assert_eq!(batch.get_points().len(), 2);
+/// ```
+/// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/line_batch.jpeg" alt="screenshot" width="200">
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LineBatch {
+    points: Vec<LinePoint>,
+    segments: usize,
+}
+
+impl LineBatch {
+    /// An empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An empty batch with room for `segments` separate segments.
+    pub fn with_capacity(segments: usize) -> Self {
+        Self { points: Vec::with_capacity(segments * 4), segments: 0 }
+    }
+
+    /// Adds a line to the batch, like [`Lines::add`].
+    /// * color_end - If None, uses color_start.
+    pub fn add<V: Into<Vec3>>(
+        &mut self,
+        start: V,
+        end: V,
+        color_start: Color32,
+        color_end: Option<Color32>,
+        thickness: f32,
+    ) -> &mut Self {
+        let color_end = color_end.unwrap_or(color_start);
+        self.add_list(&[
+            LinePoint { pt: start.into(), thickness, color: color_start },
+            LinePoint { pt: end.into(), thickness, color: color_end },
+        ])
+    }
+
+    /// Adds a line from a list of line points to the batch, like [`Lines::add_list`]. This does not close the path.
+    pub fn add_list(&mut self, points: &[LinePoint]) -> &mut Self {
+        if points.len() < 2 {
+            return self;
+        }
+        // An invisible link from the end of the previous line to the start of this one.
+        if let Some(last) = self.points.last().copied() {
+            self.points.push(LinePoint { thickness: 0.0, ..last });
+            self.points.push(LinePoint { thickness: 0.0, ..points[0] });
+        }
+        self.points.extend_from_slice(points);
+        self.segments += points.len() - 1;
+        self
+    }
+
+    /// Submits all the lines of the batch to the environment for the current frame. The batch is kept as it is.
+    ///
+    /// Returns the number of calls made to StereoKit: 1, or 0 if the batch is empty.
+    ///
+    /// see also [crate::system::line_add_listv]
+    pub fn flush(&self, _token: &MainThreadToken) -> usize {
+        if self.points.is_empty() {
+            return 0;
+        }
+        unsafe { line_add_listv(self.points.as_ptr(), self.points.len() as i32) }
+        1
+    }
+
+    /// Removes all the lines of the batch, keeping its allocation.
+    pub fn clear(&mut self) -> &mut Self {
+        self.points.clear();
+        self.segments = 0;
+        self
+    }
+
+    /// Is there no line in this batch?
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The number of visible segments in the batch, the invisible links between the lines are not counted.
+    pub fn get_segment_count(&self) -> usize {
+        self.segments
+    }
+
+    /// The points submitted by [`LineBatch::flush`], with the invisible links between the lines.
+    pub fn get_points(&self) -> &[LinePoint] {
+        &self.points
+    }
 }
 
 /// The log tool will write to the console with annotations for console colors, which helps with readability, but isn’t
@@ -2326,7 +4501,30 @@ impl Log {
 /// enabled!
 /// <https://stereokit.net/Pages/StereoKit/Microphone.html>
 ///
-///## Examples
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::system::Microphone;
+///
+/// let mut samples: Vec<f32> = Vec::new();
+/// // Nothing to read while not recording.
+/// assert!(!Microphone::is_recording());
+/// assert_eq!(Microphone::read_samples(&mut samples), 0);
+/// assert_eq!(Microphone::sample_rate(), 48000);
+///
+/// let devices = Microphone::get_devices();
+/// if let Some(device) = devices.first() {
+///     if Microphone::start(device) {
+///         test_screenshot!( // !!!! Get a proper main loop !!!!
+///             let read = Microphone::read_samples(&mut samples);
+///             assert!(read <= samples.len());
+///         );
+///         Microphone::stop();
+///         assert!(!Microphone::is_recording());
+///     }
+/// }
+/// ```
 pub struct Microphone;
 
 extern "C" {
@@ -2399,6 +4597,34 @@ impl Microphone {
     pub fn stop() {
         unsafe { mic_stop() }
     }
+
+    /// Non canonical function reading the samples recorded since the last call, and appending them to `buffer`.
+    /// Samples are mono, between -1 and +1, at [`Microphone::sample_rate`].
+    /// * buffer - The vector receiving the new samples.
+    ///
+    /// Returns the number of samples appended, 0 if the Microphone is not recording.
+    /// see also [`Sound::read_samples`] [`Sound::get_unread_samples`]
+    pub fn read_samples(buffer: &mut Vec<f32>) -> usize {
+        if !Self::is_recording() {
+            return 0;
+        }
+        let Ok(sound) = Self::sound() else { return 0 };
+        let unread = sound.get_unread_samples() as usize;
+        if unread == 0 {
+            return 0;
+        }
+        let start = buffer.len();
+        buffer.resize(start + unread, 0.0);
+        let read = sound.read_samples(buffer[start..].as_mut_ptr(), unread as u64) as usize;
+        buffer.truncate(start + read);
+        read
+    }
+
+    /// Non canonical function giving the number of samples per second recorded by the Microphone. StereoKit uses
+    /// 48,000 samples per second for all audio.
+    pub fn sample_rate() -> u32 {
+        48000
+    }
 }
 
 /// When rendering to a rendertarget, this tells if and what of the rendertarget gets cleared before rendering. For
@@ -2449,7 +4675,7 @@ bitflags::bitflags! {
         /// Render layer 9.
         const Layer9 = 1 << 9;
         /// The default VFX layer, StereoKit draws some non-standard mesh content using this flag, such as lines.
-        const Layer_VFX = 10;
+        const Layer_VFX = 1 << 10;
         /// For items that should only be drawn from the first person perspective. By default, this is enabled for
         /// renders that are from a 1st person viewpoint.
         const Layer_first_person    = 1 << 11;
@@ -2500,6 +4726,38 @@ pub enum Projection {
 ///## Examples
 pub struct Renderer;
 
+/// The camera settings given to the Renderer, starting with the initial values of StereoKit. StereoKit can't be asked
+/// for them, so they are remembered to be restored after a camera override.
+#[derive(Copy, Clone)]
+struct RendererCamera {
+    fov: f32,
+    clip: (f32, f32),
+    ortho_clip: (f32, f32),
+    ortho_size: f32,
+    camera_override: Option<CameraOverride>,
+}
+
+/// A camera override and the camera root and projection mode it replaced.
+#[derive(Copy, Clone)]
+struct CameraOverride {
+    view: Matrix,
+    projection_matrix: Matrix,
+    camera_root: Matrix,
+    projection: Projection,
+}
+
+thread_local! {
+    static RENDERER_CAMERA: Cell<RendererCamera> = const {
+        Cell::new(RendererCamera {
+            fov: 90.0,
+            clip: (0.08, 50.0),
+            ortho_clip: (0.0, 50.0),
+            ortho_size: 1.0,
+            camera_override: None,
+        })
+    };
+}
+
 extern "C" {
     pub fn render_set_clip(near_plane: f32, far_plane: f32);
     pub fn render_set_fov(field_of_view_degrees: f32);
@@ -2617,15 +4875,84 @@ unsafe extern "C" fn sc_capture_trampoline<F: FnMut(&[Color32], usize, usize)>(
     closure(std::slice::from_raw_parts(color_buffer, (width * height) as usize), width as usize, height as usize)
 }
 
+/// Write RGBA pixels to a PNG file, keeping the alpha channel. The image data is stored without compression.
+fn write_png_rgba(path: &Path, width: u32, height: u32, pixels: &[Color32]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    fn crc32(bytes: &[u8]) -> u32 {
+        let mut crc = 0xffff_ffffu32;
+        for byte in bytes {
+            crc ^= *byte as u32;
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+            }
+        }
+        !crc
+    }
+
+    fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+        out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        let start = out.len();
+        out.extend_from_slice(kind);
+        out.extend_from_slice(data);
+        let crc = crc32(&out[start..]);
+        out.extend_from_slice(&crc.to_be_bytes());
+    }
+
+    // Each row starts with the filter type 0 (None).
+    let mut raw = Vec::with_capacity((width as usize * 4 + 1) * height as usize);
+    for row in 0..height as usize {
+        raw.push(0);
+        for pixel in pixels.iter().skip(row * width as usize).take(width as usize) {
+            raw.extend_from_slice(&[pixel.r, pixel.g, pixel.b, pixel.a]);
+        }
+    }
+
+    // zlib stream made of stored deflate blocks. The last block is always an empty final one, so an empty image is still
+    // a valid stream.
+    let mut zlib = vec![0x78, 0x01];
+    for (block, is_final) in raw.chunks(0xffff).map(|block| (block, false)).chain(std::iter::once((&[][..], true))) {
+        zlib.push(is_final as u8);
+        let len = block.len() as u16;
+        zlib.extend_from_slice(&len.to_le_bytes());
+        zlib.extend_from_slice(&(!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in &raw {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    zlib.extend_from_slice(&((b << 16) | a).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    header.extend_from_slice(&[8, 6, 0, 0, 0]); // 8 bits per channel, RGBA
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib);
+    write_chunk(&mut png, b"IEND", &[]);
+    std::fs::File::create(path)?.write_all(&png)
+}
+
 impl Renderer {
     /// Sets the root transform of the camera! This will be the identity matrix by default. The user’s head
     /// location will then be relative to this point. This is great to use if you’re trying to do teleportation,
     /// redirected walking, or just shifting the floor around.
     /// <https://stereokit.net/Pages/StereoKit/Renderer/CameraRoot.html>
     ///
+    /// During a [`Renderer::override_camera`], the transform is kept to be restored by
+    /// [`Renderer::clear_camera_override`].
+    ///
     /// see also [`crate::system::render_set_cam_root`]
     pub fn camera_root(transform: impl Into<Matrix>) {
-        unsafe { render_set_cam_root(&transform.into()) }
+        let transform = transform.into();
+        if Self::update_override(|camera_override| camera_override.camera_root = transform) {
+            return;
+        }
+        unsafe { render_set_cam_root(&transform) }
     }
 
     /// This is the gamma space color the renderer will clear the screen to when beginning to draw a new frame.
@@ -2659,10 +4986,14 @@ impl Renderer {
     /// some OpenXR runtimes may clamp this to lower values. Note that while this can greatly smooth out edges, it also
     /// greatly increases RAM usage and fill rate, so use it sparingly. Only works in XR mode. If known in advance, set
     /// this via SKSettings in initialization. This is a very costly change to make.
+    /// Other values snap to the nearest valid one.
     /// <https://stereokit.net/Pages/StereoKit/Renderer/Multisample.html>
     ///
     /// see also [`crate::system::render_set_multisample`]
     pub fn multisample(level: i32) {
+        let level = level.clamp(1, 16);
+        let lower = 1 << (31 - level.leading_zeros());
+        let level = if level - lower <= lower * 2 - level { lower } else { lower * 2 };
         unsafe { render_set_multisample(level) }
     }
 
@@ -2674,8 +5005,14 @@ impl Renderer {
     /// orthographic.
     /// <https://stereokit.net/Pages/StereoKit/Renderer/Projection.html>
     ///
+    /// During a [`Renderer::override_camera`], the projection is kept to be restored by
+    /// [`Renderer::clear_camera_override`].
+    ///
     /// see also [`crate::system::render_set_projection`]
     pub fn projection(projection: Projection) {
+        if Self::update_override(|camera_override| camera_override.projection = projection) {
+            return;
+        }
         unsafe { render_set_projection(projection) }
     }
 
@@ -2685,8 +5022,38 @@ impl Renderer {
     /// Consider if Viewport_scaling will work for you instead, and prefer that.
     /// <https://stereokit.net/Pages/StereoKit/Renderer/Scaling.html>
     ///
+    /// A scaling that is not strictly positive is ignored with a warning.
+    ///
     /// see also [`crate::system::render_set_scaling`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::system::Renderer;
+    ///
+    /// // Offscreen here, the XR runtimes may quantize the swapchain size.
+    /// Renderer::scaling(0.5);
+    /// assert_eq!(Renderer::get_scaling(), 0.5);
+    /// Renderer::scaling(0.0);
+    /// assert_eq!(Renderer::get_scaling(), 0.5);
+    ///
+    /// Renderer::multisample(3);
+    /// assert_eq!(Renderer::get_multisample(), 2);
+    /// Renderer::multisample(6);
+    /// assert_eq!(Renderer::get_multisample(), 4);
+    /// Renderer::multisample(64);
+    /// assert_eq!(Renderer::get_multisample(), 16);
+    /// Renderer::multisample(1);
+    /// assert_eq!(Renderer::get_multisample(), 1);
+    ///
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    /// );
+    /// ```
     pub fn scaling(scaling: f32) {
+        if scaling <= 0.0 || scaling.is_nan() {
+            Log::warn(format!("Renderer::scaling: {scaling} is not a valid scaling and is ignored"));
+            return;
+        }
         unsafe { render_set_scaling(scaling) }
     }
 
@@ -2715,9 +5082,16 @@ impl Renderer {
     /// see Renderer.SkyLight.
     /// <https://stereokit.net/Pages/StereoKit/Renderer/SkyTex.html>
     ///
+    /// A texture that failed to load is rejected with a warning, and the current skybox is kept.
+    ///
     /// see also [`crate::system::render_set_skytex`]
     pub fn skytex(tex: impl AsRef<Tex>) {
-        unsafe { render_set_skytex(tex.as_ref().0.as_ptr()) }
+        let tex = tex.as_ref();
+        if (tex.get_asset_state() as i32) < 0 {
+            Log::warn(format!("Renderer::skytex: the texture {} is not valid and is ignored", tex.get_id()));
+            return;
+        }
+        unsafe { render_set_skytex(tex.0.as_ptr()) }
     }
 
     /// This is the Material that StereoKit is currently using to draw the skybox! It needs a special shader that's
@@ -2835,6 +5209,61 @@ impl Renderer {
         }
     }
 
+    /// Non canonical function rendering the depth of a RenderList from the given viewpoint, like the viewpoint of a
+    /// light for shadow mapping. The render is done immediately and the depth buffer is returned as a Depth32 texture
+    /// that can be given to a Material or bound with [`Renderer::set_global_texture`]. The values go from 0.0 at the
+    /// near clip plane to 1.0 at the far clip plane.
+    /// * list - The visuals to render.
+    /// * camera - A TRS matrix representing the location and orientation of the viewpoint. This matrix gets inverted
+    ///   later on, so no need to do it yourself.
+    /// * projection - The projection matrix, like [`Matrix::orthographic`] for a directional light or
+    ///   [`Matrix::perspective`] for a spot light.
+    /// * width - The width of the depth texture in pixels.
+    /// * height - The height of the depth texture in pixels.
+    ///
+    /// Returns the depth texture, or an error if the render target can't be created.
+    /// see also [`RenderList::draw_now`] [`Tex::get_zbuffer`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, maths::{Matrix, Pose, Vec3}, mesh::Mesh, render_list::RenderList,
+    ///                      system::Renderer, tex::TexFormat, util::named_colors};
+    ///
+    /// let cube = Mesh::generate_cube(Vec3::ONE * 0.2, None);
+    /// let mut shadow_casters = RenderList::new();
+    /// shadow_casters.add_mesh(&cube, Material::unlit(), Matrix::IDENTITY, named_colors::WHITE, None);
+    ///
+    /// // A directional light right above the cube.
+    /// let light = Pose::look_at(Vec3::Y * 2.0, Vec3::ZERO).to_matrix(None);
+    /// let mut depth = None;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == 0 {
+    ///         let projection = Matrix::orthographic(1.0, 1.0, 0.1, 5.0);
+    ///         depth = Some(Renderer::render_depth(token, &mut shadow_casters, light, projection, 256, 128).unwrap());
+    ///     }
+    /// );
+    /// let depth = depth.unwrap();
+    /// assert_eq!(depth.get_width(), Some(256));
+    /// assert_eq!(depth.get_height(), Some(128));
+    /// assert_eq!(depth.get_format(), Some(TexFormat::Depth32));
+    /// ```
+    pub fn render_depth(
+        _token: &MainThreadToken,
+        list: &mut RenderList,
+        camera: impl Into<Matrix>,
+        projection: impl Into<Matrix>,
+        width: usize,
+        height: usize,
+    ) -> Result<Tex, StereoKitError> {
+        let target = Tex::render_target(width, height, Some(1), Some(TexFormat::RGBA32), Some(TexFormat::Depth32))?;
+        list.draw_now(&target, camera, projection, None, Some(RenderClear::All), Rect::new(0.0, 0.0, 1.0, 1.0), None);
+        target.get_zbuffer().ok_or(StereoKitError::TexRenderTarget(
+            format!("{width}x{height}"),
+            "the render target has no depth buffer".to_string(),
+        ))
+    }
+
     /// This attaches a texture resource globally across all shaders. StereoKit uses this to attach the sky cubemap for
     /// use in reflections across all materials (register 11). It can be used for things like shadowmaps, wind data, etc.
     ///  Prefer a higher registers (11+) to prevent conflicting with normal Material textures.
@@ -2953,6 +5382,144 @@ impl Renderer {
         }
     }
 
+    /// Non canonical function rendering right now what has been drawn so far in this frame, over the clear color of
+    /// the renderer. The sky is not drawn.
+    /// * viewpoint - is Pose::look_at(from_point, looking_at_point)
+    /// * field_of_view - If None will use default value of 90°
+    ///
+    /// Returns the RGBA pixels of the image, row by row.
+    /// see also [`Renderer::clear_color`] [`Renderer::capture_transparent`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Vec3}, system::Renderer, util::{named_colors, Color128, Color32}};
+    ///
+    /// Renderer::clear_color(named_colors::RED);
+    /// let clear_color = Renderer::get_clear_color();
+    /// assert_eq!((clear_color.r, clear_color.g, clear_color.b), (1.0, 0.0, 0.0));
+    ///
+    /// let mut corner = Color32::BLACK_TRANSPARENT;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == number_of_steps {
+    ///         let pixels = Renderer::capture(token, Pose::look_at(Vec3::Z, Vec3::ZERO), 16, 16, None)
+    ///             .expect("capture should work");
+    ///         corner = pixels[0];
+    ///     }
+    /// );
+    /// assert_eq!(corner, named_colors::RED);
+    /// ```
+    pub fn capture(
+        token: &MainThreadToken,
+        viewpoint: Pose,
+        width: i32,
+        height: i32,
+        field_of_view: Option<f32>,
+    ) -> Result<Vec<Color32>, StereoKitError> {
+        Self::capture_with_clear(token, viewpoint, width, height, field_of_view, Self::get_clear_color())
+    }
+
+    /// Non canonical function rendering right now, with a fully transparent background, what has been drawn so far
+    /// in this frame. The sky is not drawn and the alpha of each pixel is kept, so the image can be composited.
+    /// * viewpoint - is Pose::look_at(from_point, looking_at_point)
+    /// * field_of_view - If None will use default value of 90°
+    ///
+    /// Returns the RGBA pixels of the image, row by row.
+    /// see also [`Renderer::screenshot_transparent`] [`RenderList::draw_now`]
+    pub fn capture_transparent(
+        token: &MainThreadToken,
+        viewpoint: Pose,
+        width: i32,
+        height: i32,
+        field_of_view: Option<f32>,
+    ) -> Result<Vec<Color32>, StereoKitError> {
+        Self::capture_with_clear(token, viewpoint, width, height, field_of_view, Color128::new(0.0, 0.0, 0.0, 0.0))
+    }
+
+    fn capture_with_clear(
+        _token: &MainThreadToken,
+        viewpoint: Pose,
+        width: i32,
+        height: i32,
+        field_of_view: Option<f32>,
+        clear_color: Color128,
+    ) -> Result<Vec<Color32>, StereoKitError> {
+        let field_of_view = field_of_view.unwrap_or(90.0);
+        let render_tex = Tex::render_target(
+            width as usize,
+            height as usize,
+            None,
+            Some(TexFormat::RGBA32),
+            Some(TexFormat::Depth32),
+        )?;
+        let projection = Matrix::perspective(field_of_view, width as f32 / height as f32, 0.01, 100.0);
+        RenderList::primary().draw_now(
+            &render_tex,
+            viewpoint.to_matrix(None),
+            projection,
+            Some(clear_color),
+            Some(RenderClear::All),
+            Rect::new(0.0, 0.0, 1.0, 1.0),
+            None,
+        );
+        let pixels = vec![Color32::new(0, 0, 0, 0); (width * height) as usize];
+        if !render_tex.get_color_data::<Color32>(&pixels, 0) {
+            return Err(StereoKitError::TexRenderTarget(
+                render_tex.get_id().to_string(),
+                "unable to read the pixels".into(),
+            ));
+        }
+        Ok(pixels)
+    }
+
+    /// Non canonical function saving as a PNG file, with a fully transparent background, what has been drawn so far in
+    /// this frame. Unlike [`Renderer::screenshot`], the image is rendered immediately and the alpha of each pixel is
+    /// kept in the file.
+    /// * filename - The PNG file to create.
+    /// * viewpoint - is Pose::look_at(from_point, looking_at_point)
+    /// * field_of_view - If None will use default value of 90°
+    ///
+    /// Returns the RGBA pixels saved in the file.
+    /// see also [`Renderer::capture_transparent`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, maths::{Matrix, Pose, Quat, Vec2, Vec3}, mesh::Mesh, system::Renderer,
+    ///                      util::named_colors};
+    ///
+    /// let quad = Mesh::generate_plane_up(Vec2::new(0.5, 0.5), None, true);
+    /// let material = Material::unlit();
+    /// let mut pixels = vec![];
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     quad.draw(token, &material, Matrix::r(Quat::from_angles(90.0, 0.0, 0.0)), Some(named_colors::RED.into()), None);
+    ///     if iter == 0 {
+    ///         pixels = Renderer::screenshot_transparent(token, "screenshots/transparent.png",
+    ///                                                   Pose::look_at(Vec3::Z, Vec3::ZERO), 64, 64, None)
+    ///                      .expect("screenshot should be saved");
+    ///     }
+    /// );
+    /// assert_eq!(pixels.len(), 64 * 64);
+    /// // The quad is opaque, the corners are fully transparent.
+    /// assert_eq!(pixels[32 * 64 + 32].a, 255);
+    /// for corner in [0, 63, 63 * 64, 64 * 64 - 1] {
+    ///     assert_eq!(pixels[corner].a, 0);
+    /// }
+    /// ```
+    pub fn screenshot_transparent(
+        token: &MainThreadToken,
+        filename: impl AsRef<Path>,
+        viewpoint: Pose,
+        width: i32,
+        height: i32,
+        field_of_view: Option<f32>,
+    ) -> Result<Vec<Color32>, StereoKitError> {
+        let pixels = Self::capture_transparent(token, viewpoint, width, height, field_of_view)?;
+        write_png_rgba(filename.as_ref(), width as u32, height as u32, &pixels)
+            .map_err(|err| StereoKitError::WriteFileError(format!("{:?} : {}", filename.as_ref(), err)))?;
+        Ok(pixels)
+    }
+
     /// Set the near and far clipping planes of the camera! These are important to z-buffer quality, especially when
     /// using low bit depth z-buffers as recommended for devices like the HoloLens. The smaller the range between the
     /// near and far planes, the better your z-buffer will look! If you see flickering on objects that are overlapping,
@@ -2963,6 +5530,9 @@ impl Renderer {
     ///
     /// see also [`crate::system::render_set_clip`]
     pub fn set_clip(near_plane: f32, far_plane: f32) {
+        if Self::update_camera(|camera| camera.clip = (near_plane, far_plane)) {
+            return;
+        }
         unsafe { render_set_clip(near_plane, far_plane) }
     }
 
@@ -2973,6 +5543,9 @@ impl Renderer {
     ///
     /// see also [`crate::system::render_set_fov`]
     pub fn set_fov(field_of_view: f32) {
+        if Self::update_camera(|camera| camera.fov = field_of_view) {
+            return;
+        }
         unsafe { render_set_fov(field_of_view) }
     }
 
@@ -2986,6 +5559,9 @@ impl Renderer {
     ///
     /// see also [`crate::system::render_set_ortho_clip`]
     pub fn set_ortho_clip(near_plane: f32, far_plane: f32) {
+        if Self::update_camera(|camera| camera.ortho_clip = (near_plane, far_plane)) {
+            return;
+        }
         unsafe { render_set_ortho_clip(near_plane, far_plane) }
     }
 
@@ -2997,9 +5573,277 @@ impl Renderer {
     ///
     /// see also [`crate::system::render_set_ortho_size`]
     pub fn set_ortho_size(view_port_height_meters: f32) {
+        if Self::update_camera(|camera| camera.ortho_size = view_port_height_meters) {
+            return;
+        }
         unsafe { render_set_ortho_size(view_port_height_meters) }
     }
 
+    /// Non canonical function replacing the camera used to draw the main display, until
+    /// [`Renderer::clear_camera_override`] is called. The camera root is moved so the head is at `view`, and the
+    /// projection mode, field of view and clip planes are extracted from `projection`. As the head keeps moving
+    /// relative to the camera root, call this every frame you want the override to be exact.
+    ///
+    /// The camera root, projection mode, field of view and clip planes given to the Renderer while the override is
+    /// active are kept, and applied when the override is cleared.
+    ///
+    /// Only works for flatscreen! With XR displays the projection of each eye is controlled by the runtime, so the
+    /// override is ignored with a warning.
+    /// * view - A TRS matrix representing the location and orientation of the camera. This matrix gets inverted later
+    ///   on, so no need to do it yourself.
+    /// * projection - A matrix made with [`Matrix::perspective`] or [`Matrix::ortographic`]. Only the vertical field
+    ///   of view (or height) and the clip planes are used.
+    ///
+    /// see also [`Renderer::clear_camera_override`] [`Renderer::camera_root`] [`Renderer::set_fov`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{material::Material, maths::{Matrix, Pose, Vec3}, mesh::Mesh,
+    ///                      system::{Input, Renderer}, tex::{Tex, TexFormat}, util::{named_colors, Color32}};
+    ///
+    /// let sphere = Mesh::generate_sphere(0.3, None);
+    /// let material = Material::unlit();
+    /// let viewpoint = Pose::look_at([0.0, 0.0, 2.0], Vec3::ZERO);
+    /// let narrow = Matrix::perspective(30.0, 1.0, 0.05, 50.0);
+    /// let root_before = Renderer::get_camera_root();
+    /// let target = Tex::render_target(32, 32, Some(1), Some(TexFormat::RGBA32), Some(TexFormat::Depth32)).unwrap();
+    /// let wide_target = Tex::render_target(32, 32, Some(1), Some(TexFormat::RGBA32), Some(TexFormat::Depth32)).unwrap();
+    ///
+    /// filename_scr = "screenshots/camera_override.jpeg";
+    /// number_of_steps = 6;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     sphere.draw(token, &material, Matrix::IDENTITY, Some(named_colors::RED.into()), None);
+    ///     match iter {
+    ///         1 | 2 => Renderer::override_camera(token, viewpoint.to_matrix(None), narrow),
+    ///         3 => {
+    ///             // The frame is drawn from the viewpoint, with the narrow field of view.
+    ///             assert!((Input::get_head().position - viewpoint.position).length() < 0.01);
+    ///             let (view, projection) = Renderer::get_eye_views()[0];
+    ///             Renderer::render_to(token, &target, view.get_inverse(), projection, None, None, None);
+    ///             let wide = Matrix::perspective(90.0, 1.0, 0.05, 50.0);
+    ///             Renderer::render_to(token, &wide_target, view.get_inverse(), wide, None, None, None);
+    ///             // Kept to be applied after the override.
+    ///             Renderer::set_fov(60.0);
+    ///         }
+    ///         4 => {
+    ///             Renderer::clear_camera_override(token);
+    ///             assert!(Renderer::get_camera_override().is_none());
+    ///             assert_eq!(unsafe { Renderer::get_camera_root().m }, unsafe { root_before.m });
+    ///         }
+    ///         _ => {}
+    ///     }
+    /// );
+    /// // The narrow field of view zooms on the sphere.
+    /// let red = |tex: &Tex| {
+    ///     let pixels = vec![Color32::default(); 32 * 32];
+    ///     assert!(tex.get_color_data::<Color32>(&pixels, 0));
+    ///     pixels.iter().filter(|pixel| pixel.r > 128 && pixel.g < 64).count()
+    /// };
+    /// assert!(red(&target) > red(&wide_target) * 4);
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/camera_override.jpeg" alt="screenshot" width="200">
+    pub fn override_camera(_token: &MainThreadToken, view: impl Into<Matrix>, projection: impl Into<Matrix>) {
+        if Device::get_display_type() != DisplayType::Flatscreen {
+            Log::warn(
+                "Renderer::override_camera is ignored, the projection of XR displays is controlled by the runtime",
+            );
+            return;
+        }
+        let (view, projection_matrix) = (view.into(), projection.into());
+        let mut camera = RENDERER_CAMERA.with(|camera| camera.get());
+        let current_root = Self::get_camera_root();
+        let head_local = Input::get_head().to_matrix(None) * current_root.get_inverse();
+        let m = unsafe { projection_matrix.m };
+        let near = m[14] / m[10];
+        unsafe {
+            render_set_cam_root(&(head_local.get_inverse() * view));
+            if (m[15] - 1.0).abs() < 0.0001 {
+                render_set_projection(Projection::Orthographic);
+                render_set_ortho_size(2.0 / m[5].abs());
+                render_set_ortho_clip(near, near - 1.0 / m[10]);
+            } else {
+                render_set_projection(Projection::Perspective);
+                render_set_fov(2.0 * (1.0 / m[5].abs()).atan().to_degrees());
+                render_set_clip(near, m[14] / (m[10] + 1.0));
+            }
+        }
+        let (camera_root, projection) = match camera.camera_override {
+            Some(previous) => (previous.camera_root, previous.projection),
+            None => (current_root, Self::get_projection()),
+        };
+        camera.camera_override = Some(CameraOverride { view, projection_matrix, camera_root, projection });
+        RENDERER_CAMERA.with(|cell| cell.set(camera));
+    }
+
+    /// Non canonical function restoring the camera root and the projection mode in use before
+    /// [`Renderer::override_camera`], or given to the Renderer during the override. The field of view and the clip
+    /// planes are the last ones given to the Renderer, or the initial values of StereoKit if none were given.
+    ///
+    /// see also [`Renderer::override_camera`]
+    pub fn clear_camera_override(_token: &MainThreadToken) {
+        let mut camera = RENDERER_CAMERA.with(|camera| camera.get());
+        let Some(camera_override) = camera.camera_override.take() else {
+            return;
+        };
+        unsafe {
+            render_set_cam_root(&camera_override.camera_root);
+            render_set_projection(camera_override.projection);
+            render_set_fov(camera.fov);
+            render_set_clip(camera.clip.0, camera.clip.1);
+            render_set_ortho_size(camera.ortho_size);
+            render_set_ortho_clip(camera.ortho_clip.0, camera.ortho_clip.1);
+        }
+        RENDERER_CAMERA.with(|cell| cell.set(camera));
+    }
+
+    /// Non canonical function giving the view and projection matrices of the current [`Renderer::override_camera`].
+    ///
+    /// see also [`Renderer::override_camera`]
+    pub fn get_camera_override() -> Option<(Matrix, Matrix)> {
+        RENDERER_CAMERA
+            .with(|camera| camera.get().camera_override)
+            .map(|camera_override| (camera_override.view, camera_override.projection_matrix))
+    }
+
+    /// Non canonical function giving the view (world to eye) and projection matrices of each eye drawn on the main
+    /// display. Flatscreen displays have a single eye built from the head, the projection mode, the field of view and
+    /// the clip planes given to the Renderer, or from the current [`Renderer::override_camera`].
+    ///
+    /// XR displays have the views OpenXR locates (xrLocateViews) at the predicted display time of the current frame,
+    /// with their asymmetric field of view and the clip planes given to the Renderer. The list is empty when OpenXR
+    /// can't locate the views yet (session not started, tracking lost).
+    ///
+    /// see also [`Renderer::override_camera`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec3, system::{Input, Renderer}, util::{Device, DisplayType}};
+    ///
+    /// let eyes = Renderer::get_eye_views();
+    /// if Device::get_display_type() == DisplayType::Flatscreen {
+    ///     assert_eq!(eyes.len(), 1);
+    /// } else {
+    ///     // The views are located once the session runs.
+    ///     assert!(eyes.len() == 2 || eyes.is_empty());
+    /// }
+    /// // On a flatscreen display, the head is at the origin of the single eye.
+    /// if let [(view, projection)] = eyes.as_slice() {
+    ///     assert!(view.transform_point(Input::get_head().position).length() < 0.0001);
+    ///     assert!(unsafe { projection.m }.iter().all(|value| value.is_finite()));
+    /// }
+    /// ```
+    pub fn get_eye_views() -> Vec<(Matrix, Matrix)> {
+        let camera = RENDERER_CAMERA.with(|camera| camera.get());
+        let head = Input::get_head();
+        let (width, height) = unsafe { (device_display_get_width(), device_display_get_height()) };
+        let aspect = if width > 0 && height > 0 { width as f32 / height as f32 } else { 1.0 };
+        let perspective = |fov: f32, near: f32, far: f32| {
+            let top = near * (fov.to_radians() / 2.0).tan();
+            Matrix::perspective_off_center(-top * aspect, top * aspect, -top, top, near, far)
+        };
+
+        if Device::get_display_type() != DisplayType::Flatscreen {
+            return Self::locate_xr_views(camera.clip).unwrap_or_default();
+        }
+
+        if let Some(camera_override) = camera.camera_override {
+            return vec![(camera_override.view.get_inverse(), camera_override.projection_matrix)];
+        }
+        let projection = match Self::get_projection() {
+            Projection::Perspective => perspective(camera.fov, camera.clip.0, camera.clip.1),
+            Projection::Orthographic => Matrix::orthographic(
+                camera.ortho_size * aspect,
+                camera.ortho_size,
+                camera.ortho_clip.0,
+                camera.ortho_clip.1,
+            ),
+        };
+        vec![(head.to_matrix(None).get_inverse(), projection)]
+    }
+
+    /// The views of the XR display located by OpenXR, in world space.
+    fn locate_xr_views(clip: (f32, f32)) -> Option<Vec<(Matrix, Matrix)>> {
+        if Backend::xr_type() != BackendXRType::OpenXR || BackendOpenXR::session() == 0 {
+            return None;
+        }
+        let locate_views = BackendOpenXR::get_function::<LocateViews>("xrLocateViews")?;
+        let config = BackendOpenXR::view_configuration()?;
+        let info = ViewLocateInfo {
+            ty: ViewLocateInfo::TYPE,
+            next: null(),
+            view_configuration_type: config.view_configuration_type,
+            display_time: XrTime::from_nanos(BackendOpenXR::time()),
+            space: Space::from_raw(BackendOpenXR::space()),
+        };
+        let mut state = ViewState { ty: ViewState::TYPE, next: null_mut(), view_state_flags: ViewStateFlags::EMPTY };
+        let empty_view = View { ty: View::TYPE, next: null_mut(), pose: Posef::IDENTITY, fov: Fovf::default() };
+        let mut views = vec![empty_view; config.view_count as usize];
+        let mut count = 0u32;
+        let session = Session::from_raw(BackendOpenXR::session());
+        match unsafe { locate_views(session, &info, &mut state, views.len() as u32, &mut count, views.as_mut_ptr()) } {
+            XrResult::SUCCESS => (),
+            otherwise => {
+                Log::err(format!("xrLocateViews failed: {otherwise}"));
+                return None;
+            }
+        }
+        let valid = ViewStateFlags::ORIENTATION_VALID | ViewStateFlags::POSITION_VALID;
+        if !state.view_state_flags.contains(valid) {
+            return None;
+        }
+        views.truncate(count as usize);
+
+        // The views are located in the OpenXR space of StereoKit, under the camera root.
+        let root = Self::get_camera_root();
+        let (near, far) = clip;
+        let eyes = views
+            .iter()
+            .map(|view| {
+                let (pose, fov) = (view.pose, view.fov);
+                let position = Vec3::new(pose.position.x, pose.position.y, pose.position.z);
+                let orientation =
+                    Quat::new(pose.orientation.x, pose.orientation.y, pose.orientation.z, pose.orientation.w);
+                let eye = Matrix::tr(&position, &orientation) * root;
+                let projection = Matrix::perspective_off_center(
+                    near * fov.angle_left.tan(),
+                    near * fov.angle_right.tan(),
+                    near * fov.angle_down.tan(),
+                    near * fov.angle_up.tan(),
+                    near,
+                    far,
+                );
+                (eye.get_inverse(), projection)
+            })
+            .collect();
+        Some(eyes)
+    }
+
+    /// Remember a camera setting. Returns true if a camera override is active, the setting being then applied only by
+    /// [`Renderer::clear_camera_override`].
+    fn update_camera(update: impl FnOnce(&mut RendererCamera)) -> bool {
+        RENDERER_CAMERA.with(|cell| {
+            let mut camera = cell.get();
+            update(&mut camera);
+            cell.set(camera);
+            camera.camera_override.is_some()
+        })
+    }
+
+    /// Change what the active camera override will restore. Returns false if there is no camera override.
+    fn update_override(update: impl FnOnce(&mut CameraOverride)) -> bool {
+        RENDERER_CAMERA.with(|cell| {
+            let mut camera = cell.get();
+            let Some(camera_override) = camera.camera_override.as_mut() else {
+                return false;
+            };
+            update(camera_override);
+            cell.set(camera);
+            true
+        })
+    }
+
     /// Gets the root transform of the camera! This will be the identity matrix by default. The user’s head
     /// location will then be relative to this point. This is great to use if you’re trying to do teleportation,
     /// redirected walking, or just shifting the floor around.
@@ -3174,6 +6018,33 @@ extern "C" {
     pub fn text_style_get_baseline(style: TextStyle) -> f32;
 }
 
+/// The color and outline given to a [`TextStyle`] after its creation, with the color it was created with.
+#[derive(Debug, Copy, Clone)]
+struct TextStyleAdjust {
+    base_color: Color128,
+    color: Option<Color128>,
+    outline: Option<(Color128, f32)>,
+}
+
+thread_local! {
+    /// The [`TextStyleAdjust`] of the text styles, by style id.
+    static TEXT_STYLE_ADJUSTS: RefCell<HashMap<u32, TextStyleAdjust>> = RefCell::new(HashMap::new());
+}
+
+/// The distance the outline of a [`TextStyle`] is drawn behind its text, in meters.
+const TEXT_OUTLINE_DEPTH: f32 = 0.0002;
+/// The directions the text is shifted to in order to draw the outline of a [`TextStyle`].
+const TEXT_OUTLINE_SHIFTS: [(f32, f32); 8] = [
+    (1.0, 0.0),
+    (-1.0, 0.0),
+    (0.0, 1.0),
+    (0.0, -1.0),
+    (FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+    (-FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+    (FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+    (-FRAC_1_SQRT_2, -FRAC_1_SQRT_2),
+];
+
 impl Default for TextStyle {
     /// This is the default text style used by StereoKit.
     /// <https://stereokit.net/Pages/StereoKit/TextStyle/Default.html>
@@ -3196,7 +6067,9 @@ impl TextStyle {
     ///
     /// see also [`crate::system::text_make_style`]
     pub fn from_font(font: impl AsRef<Font>, layout_height_meters: f32, color_gamma: impl Into<Color128>) -> Self {
-        unsafe { text_make_style(font.as_ref().0.as_ptr(), layout_height_meters, color_gamma.into()) }
+        let color_gamma = color_gamma.into();
+        unsafe { text_make_style(font.as_ref().0.as_ptr(), layout_height_meters, color_gamma) }
+            .with_base_color(color_gamma)
     }
 
     /// Create a text style for use with other text functions! A text style is a font plus size/color/material
@@ -3218,14 +6091,16 @@ impl TextStyle {
         shader: impl AsRef<Shader>,
         color_gamma: impl Into<Color128>,
     ) -> Self {
+        let color_gamma = color_gamma.into();
         unsafe {
             text_make_style_shader(
                 font.as_ref().0.as_ptr(),
                 layout_height_meters,
                 shader.as_ref().0.as_ptr(),
-                color_gamma.into(),
+                color_gamma,
             )
         }
+        .with_base_color(color_gamma)
     }
 
     /// Create a text style for use with other text functions! A text style is a font plus size/color/material
@@ -3252,14 +6127,16 @@ impl TextStyle {
         material: impl AsRef<Material>,
         color_gamma: impl Into<Color128>,
     ) -> Self {
+        let color_gamma = color_gamma.into();
         unsafe {
             text_make_style_mat(
                 font.as_ref().0.as_ptr(),
                 layout_height_meters,
                 material.as_ref().0.as_ptr(),
-                color_gamma.into(),
+                color_gamma,
             )
         }
+        .with_base_color(color_gamma)
     }
 
     /// Height of a text glyph in meters. StereoKit currently bases this on the letter ‘T’.
@@ -3372,6 +6249,122 @@ impl TextStyle {
     pub fn get_descender(&self) -> f32 {
         unsafe { text_style_get_descender(*self) }
     }
+
+    /// Non canonical function changing the color of the text drawn with this style by [`Text::add_at`] and
+    /// [`Text::add_in`], without creating a new style. The color the style was created with is embedded in the text
+    /// mesh, so the change is applied as a tint of the text.
+    /// * color_gamma - The gamma space color of the text.
+    ///
+    /// see also [`TextStyle::get_color`] [`TextStyle::outline`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{font::Font, maths::{Matrix, Quat}, system::{Text, TextStyle},
+    ///                      util::{named_colors, Color128}};
+    ///
+    /// let mut style = Text::make_style(Font::default(), 0.08, named_colors::WHITE);
+    /// let color = style.get_color();
+    /// assert_eq!((color.r, color.g, color.b, color.a), (1.0, 1.0, 1.0, 1.0));
+    /// assert!(style.get_outline().is_none());
+    ///
+    /// style.color(Color128::new(1.0, 0.0, 0.0, 1.0));
+    /// let color = style.get_color();
+    /// assert_eq!((color.r, color.g, color.b, color.a), (1.0, 0.0, 0.0, 1.0));
+    /// style.outline(Color128::BLACK, 0.003);
+    /// let (outline_color, thickness) = style.get_outline().expect("an outline");
+    /// assert_eq!((outline_color.r, outline_color.a, thickness), (0.0, 1.0, 0.003));
+    /// style.layout_height(0.1);
+    /// assert!((style.get_layout_height() - 0.1).abs() < 0.0001);
+    ///
+    /// // The default style isn't affected.
+    /// assert_eq!(TextStyle::default().get_color().g, 1.0);
+    ///
+    /// let transform = Matrix::r(Quat::from_angles(0.0, 180.0, 0.0));
+    /// filename_scr = "screenshots/text_style_color.jpeg";
+    /// number_of_steps = 10;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     // From red to yellow over the steps.
+    ///     style.color(Color128::new(1.0, iter as f32 / 10.0, 0.0, 1.0));
+    ///     Text::add_at(token, "Alert!", transform, Some(style), None, None, None, None, None, None);
+    /// );
+    /// let color = style.get_color();
+    /// assert_eq!((color.r, color.g, color.b), (1.0, 1.0, 0.0));
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/text_style_color.jpeg" alt="screenshot" width="200">
+    pub fn color(&mut self, color_gamma: impl Into<Color128>) {
+        let color_gamma = color_gamma.into();
+        self.adjust(|adjust| adjust.color = Some(color_gamma));
+    }
+
+    /// Non canonical function giving the gamma space color of the text drawn with this style.
+    ///
+    /// see also [`TextStyle::color`]
+    pub fn get_color(&self) -> Color128 {
+        let adjust = TEXT_STYLE_ADJUSTS.with_borrow(|adjusts| adjusts.get(&self._id).copied());
+        adjust.map(|adjust| adjust.color.unwrap_or(adjust.base_color)).unwrap_or(Color128::WHITE)
+    }
+
+    /// Non canonical function adding an outline around the text drawn with this style by [`Text::add_at`] and
+    /// [`Text::add_in`]. The text shader has no outline, so the text is drawn 8 more times with the outline color,
+    /// shifted by `thickness` around the text and slightly behind it.
+    /// * color_gamma - The gamma space color of the outline.
+    /// * thickness - The thickness of the outline, in meters. 0 removes the outline.
+    ///
+    /// see also [`TextStyle::get_outline`] [`TextStyle::color`]
+    pub fn outline(&mut self, color_gamma: impl Into<Color128>, thickness: f32) {
+        let outline = if thickness > 0.0 { Some((color_gamma.into(), thickness)) } else { None };
+        self.adjust(|adjust| adjust.outline = outline);
+    }
+
+    /// Non canonical function giving the gamma space color and the thickness of the outline of this style, if any.
+    ///
+    /// see also [`TextStyle::outline`]
+    pub fn get_outline(&self) -> Option<(Color128, f32)> {
+        TEXT_STYLE_ADJUSTS.with_borrow(|adjusts| adjusts.get(&self._id).and_then(|adjust| adjust.outline))
+    }
+
+    /// Remember the color this style has been created with.
+    fn with_base_color(self, color_gamma: Color128) -> Self {
+        self.adjust(|adjust| adjust.base_color = color_gamma);
+        self
+    }
+
+    fn adjust(&self, change: impl FnOnce(&mut TextStyleAdjust)) {
+        TEXT_STYLE_ADJUSTS.with_borrow_mut(|adjusts| {
+            let adjust = adjusts.entry(self._id).or_insert(TextStyleAdjust {
+                base_color: Color128::WHITE,
+                color: None,
+                outline: None,
+            });
+            change(adjust);
+        });
+    }
+
+    /// The linear tints turning the color this style has been created with into its color, and into its outline color
+    /// with the outline thickness.
+    fn get_tints(&self, vertex_tint_linear: Color128) -> (Color128, Option<(Color128, f32)>) {
+        let Some(adjust) = TEXT_STYLE_ADJUSTS.with_borrow(|adjusts| adjusts.get(&self._id).copied()) else {
+            return (vertex_tint_linear, None);
+        };
+        let base = adjust.base_color.to_linear();
+        let ratio = |color: Color128| {
+            let color = color.to_linear();
+            let divide = |value: f32, by: f32| if by > 0.0 { value / by } else { value };
+            Color128::new(
+                divide(color.r, base.r),
+                divide(color.g, base.g),
+                divide(color.b, base.b),
+                divide(color.a, base.a),
+            )
+        };
+        let tint = match adjust.color {
+            Some(color) => vertex_tint_linear * ratio(color),
+            None => vertex_tint_linear,
+        };
+        let outline = adjust.outline.map(|(color, thickness)| (vertex_tint_linear * ratio(color), thickness));
+        (tint, outline)
+    }
 }
 
 /// An enum for describing alignment or positioning
@@ -3547,7 +6540,9 @@ impl Text {
         layout_height_meters: f32,
         color_gamma: impl Into<Color128>,
     ) -> TextStyle {
-        unsafe { text_make_style(font.as_ref().0.as_ptr(), layout_height_meters, color_gamma.into()) }
+        let color_gamma = color_gamma.into();
+        unsafe { text_make_style(font.as_ref().0.as_ptr(), layout_height_meters, color_gamma) }
+            .with_base_color(color_gamma)
     }
 
     /// Create a text style for use with other text functions! A text style is a font plus size/color/material
@@ -3571,14 +6566,16 @@ impl Text {
         shader: impl AsRef<Shader>,
         color_gamma: impl Into<Color128>,
     ) -> TextStyle {
+        let color_gamma = color_gamma.into();
         unsafe {
             text_make_style_shader(
                 font.as_ref().0.as_ptr(),
                 layout_height_meters,
                 shader.as_ref().0.as_ptr(),
-                color_gamma.into(),
+                color_gamma,
             )
         }
+        .with_base_color(color_gamma)
     }
 
     /// Create a text style for use with other text functions! A text style is a font plus size/color/material
@@ -3606,14 +6603,16 @@ impl Text {
         material: impl AsRef<Material>,
         color_gamma: impl Into<Color128>,
     ) -> TextStyle {
+        let color_gamma = color_gamma.into();
         unsafe {
             text_make_style_mat(
                 font.as_ref().0.as_ptr(),
                 layout_height_meters,
                 material.as_ref().0.as_ptr(),
-                color_gamma.into(),
+                color_gamma,
             )
         }
+        .with_base_color(color_gamma)
     }
 
     /// Renders text at the given location! Must be called every frame you want this text to be visible.
@@ -3646,21 +6645,124 @@ impl Text {
         let off_x = off_x.unwrap_or(0.0);
         let off_y = off_y.unwrap_or(0.0);
         let off_z = off_z.unwrap_or(0.0);
+        let transform = transform.into();
+        let (vertex_tint_linear, outline) = style.get_tints(vertex_tint_linear);
+        if let Some((outline_tint, thickness)) = outline {
+            for (dx, dy) in TEXT_OUTLINE_SHIFTS {
+                let (x, y, z) = (off_x + dx * thickness, off_y + dy * thickness, off_z + TEXT_OUTLINE_DEPTH);
+                unsafe { text_add_at(c_str.as_ptr(), &transform, style, position, align, x, y, z, outline_tint) }
+            }
+        }
         unsafe {
-            text_add_at(
-                c_str.as_ptr(),
-                &transform.into(),
-                style,
-                position,
-                align,
-                off_x,
-                off_y,
-                off_z,
-                vertex_tint_linear,
-            )
+            text_add_at(c_str.as_ptr(), &transform, style, position, align, off_x, off_y, off_z, vertex_tint_linear)
         }
     }
 
+    /// Non canonical function rendering text at the given location like [`Text::add_at`], but the text is broken into
+    /// lines fitting within `max_width`. Must be called every frame you want this text to be visible.
+    /// * max_width - The maximum width of a line in meters.
+    /// * text_style - if None will use the TextStyle::default()
+    /// * vertex_tint_linear - if None will use Color128::WHITE
+    /// * position - if None will use TextAlign::Center
+    /// * align - if None will use TextAlign::Center
+    /// * off_? - if None will use 0.0
+    ///
+    /// see also [`Text::wrap`] [`crate::system::text_add_at`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Matrix, Quat, Vec3}, system::{Text, TextAlign}};
+    ///
+    /// let paragraph = "StereoKit is an easy-to-use open source mixed reality library for building HoloLens and VR \
+    ///                  applications with C# and OpenXR!";
+    /// let wrapped = Text::wrap(paragraph, None, 0.4);
+    /// assert!(wrapped.lines().count() > 1);
+    ///
+    /// let transform = Matrix::tr(&Vec3::new(0.0, 0.0, 0.5), &Quat::from_angles(0.0, 180.0, 0.0));
+    /// filename_scr = "screenshots/text_wrapped.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Text::add_wrapped(token, paragraph, transform, 0.4, None, None, None, Some(TextAlign::CenterLeft),
+    ///                       None, None, None);
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/text_wrapped.jpeg" alt="screenshot" width="200">
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_wrapped(
+        token: &MainThreadToken,
+        text: impl AsRef<str>,
+        transform: impl Into<Matrix>,
+        max_width: f32,
+        text_style: Option<TextStyle>,
+        vertex_tint_linear: Option<Color128>,
+        position: Option<TextAlign>,
+        align: Option<TextAlign>,
+        off_x: Option<f32>,
+        off_y: Option<f32>,
+        off_z: Option<f32>,
+    ) {
+        let wrapped = Self::wrap(text, text_style, max_width);
+        Self::add_at(token, wrapped, transform, text_style, vertex_tint_linear, position, align, off_x, off_y, off_z)
+    }
+
+    /// Non canonical function breaking the text into lines fitting within `max_width` with the given style. Lines are
+    /// broken between words, the existing new lines are kept, and the words longer than `max_width` are broken
+    /// between characters.
+    /// * text_style - if None will use the TextStyle::default()
+    /// * max_width - The maximum width of a line in meters.
+    ///
+    /// Returns the text with the new lines added.
+    /// see also [`Text::add_wrapped`] [`Text::size_layout`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::system::Text;
+    ///
+    /// let max_width = Text::size_layout("Hello world", None, None).x * 1.01;
+    /// assert_eq!(Text::wrap("Hello world Hello world", None, max_width), "Hello world\nHello world");
+    /// assert_eq!(Text::wrap("Hello\nworld", None, max_width), "Hello\nworld");
+    ///
+    /// let narrow = Text::size_layout("Hello", None, None).x * 1.01;
+    /// let wrapped = Text::wrap("Hellohello", None, narrow);
+    /// assert_eq!(wrapped.lines().count(), 2);
+    /// for line in wrapped.lines() {
+    ///     assert!(Text::size_layout(line, None, None).x <= narrow);
+    /// }
+    /// ```
+    pub fn wrap(text: impl AsRef<str>, text_style: Option<TextStyle>, max_width: f32) -> String {
+        let fits = |line: &str| Self::size_layout(line, text_style, None).x <= max_width;
+        let mut lines: Vec<String> = vec![];
+        for paragraph in text.as_ref().split('\n') {
+            let mut current = String::new();
+            for word in paragraph.split_whitespace() {
+                let candidate = if current.is_empty() { word.to_string() } else { format!("{current} {word}") };
+                if fits(&candidate) {
+                    current = candidate;
+                    continue;
+                }
+                if !current.is_empty() {
+                    lines.push(std::mem::take(&mut current));
+                }
+                if fits(word) {
+                    current = word.to_string();
+                    continue;
+                }
+                // This word is too long, it has to be broken.
+                for character in word.chars() {
+                    current.push(character);
+                    if current.chars().count() > 1 && !fits(&current) {
+                        current.pop();
+                        lines.push(std::mem::take(&mut current));
+                        current.push(character);
+                    }
+                }
+            }
+            lines.push(current);
+        }
+        lines.join("\n")
+    }
+
     /// Renders text at the given location! Must be called every frame you want this text to be visible.
     /// <https://stereokit.net/Pages/StereoKit/Text/Add.html>
     /// * text_style - if None will use the TextStyle::default()
@@ -3695,11 +6797,21 @@ impl Text {
         let off_x = off_x.unwrap_or(0.0);
         let off_y = off_y.unwrap_or(0.0);
         let off_z = off_z.unwrap_or(0.0);
+        let (transform, size) = (transform.into(), size.into());
+        let (vertex_tint_linear, outline) = style.get_tints(vertex_tint_linear);
+        if let Some((outline_tint, thickness)) = outline {
+            for (dx, dy) in TEXT_OUTLINE_SHIFTS {
+                let (x, y, z) = (off_x + dx * thickness, off_y + dy * thickness, off_z + TEXT_OUTLINE_DEPTH);
+                unsafe {
+                    text_add_in(c_str.as_ptr(), &transform, size, fit, style, position, align, x, y, z, outline_tint)
+                };
+            }
+        }
         unsafe {
             text_add_in(
                 c_str.as_ptr(),
-                &transform.into(),
-                size.into(),
+                &transform,
+                size,
                 fit,
                 style,
                 position,
@@ -3769,6 +6881,49 @@ impl Text {
         let style = text_style.unwrap_or_default();
         unsafe { text_size_render(size_layout.into(), style, y_offset) }
     }
+
+    /// Non canonical function that word-wraps a text so it fits in the given box when rendered with the indicated
+    /// style. Line breaks are inserted between words, using [`Text::size_layout`] to measure each line. Lines that
+    /// would overflow the height of the box are dropped. A single word wider than the box is broken between characters.
+    /// * text - Text you want to wrap. Existing line breaks are kept.
+    /// * text_style - if None will use the TextStyle::default()
+    /// * max_size - The box in meters the text must fit in.
+    ///
+    /// Returns the wrapped text.
+    ///
+    /// see also [`crate::system::text_size_layout`]
+    /// # Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec2, system::Text};
+    ///
+    /// let short = Text::size_layout("Hello", None, None);
+    /// let long = Text::size_layout("Hello the wide world", None, None);
+    /// assert!(long.x > short.x);
+    ///
+    /// let wrapped = Text::fit_to("Hello the wide world", None, Vec2::new(short.x * 1.5, 1.0));
+    /// assert!(wrapped.contains('\n'));
+    /// assert_eq!(wrapped.split_whitespace().count(), 4);
+    /// ```
+    pub fn fit_to(text: impl AsRef<str>, text_style: Option<TextStyle>, max_size: impl Into<Vec2>) -> String {
+        let max_size = max_size.into();
+        let lines = Self::wrap(text, text_style, max_size.x);
+
+        let mut wrapped = String::new();
+        for (i, line) in lines.split('\n').enumerate() {
+            if i == 0 {
+                wrapped.push_str(line);
+                continue;
+            }
+            let candidate = format!("{}\n{}", wrapped, line);
+            if Self::size_layout(&candidate, text_style, None).y > max_size.y {
+                break;
+            }
+            wrapped = candidate;
+        }
+        wrapped
+    }
 }
 
 /// A settings flag that lets you describe the behavior of how StereoKit will refresh data about the world mesh, if
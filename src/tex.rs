@@ -1,5 +1,5 @@
 use crate::{
-    maths::{Bool32T, Vec3},
+    maths::{Bool32T, Recti, Vec2, Vec2i, Vec3},
     system::{
         render_enable_skytex, render_get_skylight, render_get_skytex, render_set_skylight, render_set_skytex,
         AssetState, IAsset, Log,
@@ -134,6 +134,28 @@ pub enum TexFormat {
     R8G8 = 19,
 }
 
+impl TexFormat {
+    /// Non canonical function giving the size in bytes of one pixel of this format, or None for TexFormat::None.
+    pub fn get_pixel_size(&self) -> Option<usize> {
+        match self {
+            TexFormat::None => None,
+            TexFormat::R8 => Some(1),
+            TexFormat::R16u | TexFormat::R16s | TexFormat::R16f | TexFormat::Depth16 | TexFormat::R8G8 => Some(2),
+            TexFormat::RGBA32
+            | TexFormat::RGBA32Linear
+            | TexFormat::BGRA32
+            | TexFormat::BGRA32Linear
+            | TexFormat::RG11B10
+            | TexFormat::RGB10A2
+            | TexFormat::R32
+            | TexFormat::DepthStencil
+            | TexFormat::Depth32 => Some(4),
+            TexFormat::RGBA64U | TexFormat::RGBA64S | TexFormat::RGBA64F => Some(8),
+            TexFormat::RGBA128 => Some(16),
+        }
+    }
+}
+
 /// How does the shader grab pixels from the texture? Or more
 /// specifically, how does the shader grab colors between the provided
 /// pixels? If you'd like an in-depth explanation of these topics, check
@@ -172,6 +194,9 @@ pub enum TexAddress {
     Mirror = 2,
 }
 
+/// The file extensions [`Tex::from_memory_with_format`] can load.
+pub const TEX_FORMATS: [&str; 10] = ["jpg", "jpeg", "png", "tga", "bmp", "psd", "gif", "hdr", "pic", "ktx2"];
+
 /// This is the texture asset class! This encapsulates 2D images, texture arrays, cubemaps, and rendertargets! It can
 /// load any image format that stb_image can, (jpg, png, tga, bmp, psd, gif, hdr, pic, ktx2) plus more later on, and you
 /// can also create textures procedurally.
@@ -265,6 +290,15 @@ extern "C" {
         out_sh_lighting_info: *mut SphericalHarmonics,
         multisample: i32,
     );
+    pub fn tex_set_color_arr_mips(
+        texture: TexT,
+        width: i32,
+        height: i32,
+        array_data: *mut *mut c_void,
+        array_count: i32,
+        mip_count: i32,
+        out_sh_lighting_info: *mut SphericalHarmonics,
+    );
     pub fn tex_set_mem(
         texture: TexT,
         data: *mut c_void,
@@ -350,6 +384,58 @@ impl Tex {
         .ok_or(StereoKitError::TexMemory)?))
     }
 
+    /// Non canonical function loading an image file stored in memory, with the format given explicitly. This is handy
+    /// with assets read from an archive. The supported formats are [`TEX_FORMATS`], and the data must match the
+    /// signature of the given format when it has one (all of them except tga).
+    /// * data - The content of the file.
+    /// * ext - The extension of the file, with or without its leading dot. The case is ignored.
+    /// * srgb_data - Is the image color data in sRGB space?
+    /// * priority - If None will be set to 10
+    ///
+    /// Returns StereoKitError::Unsupported if the format is unknown, or StereoKitError::TexMemory if the data doesn't
+    /// match the format or can't be loaded.
+    ///
+    /// see also [`Tex::from_memory`] [`crate::model::Model::from_memory_with_format`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{tex::Tex, StereoKitError};
+    ///
+    /// // These bytes could come from an archive.
+    /// let bytes = std::fs::read("assets/textures/exit.jpeg").expect("the asset should exist");
+    ///
+    /// assert!(Tex::from_memory_with_format(&bytes, "jpg", true, None).is_ok());
+    /// assert!(Tex::from_memory_with_format(&bytes, ".JPEG", true, None).is_ok());
+    /// assert!(matches!(Tex::from_memory_with_format(&bytes, "png", true, None), Err(StereoKitError::TexMemory)));
+    /// assert!(matches!(Tex::from_memory_with_format(&bytes, "webp", true, None),
+    ///                  Err(StereoKitError::Unsupported(_, _))));
+    /// ```
+    pub fn from_memory_with_format(
+        data: &[u8],
+        ext: impl AsRef<str>,
+        srgb_data: bool,
+        priority: Option<i32>,
+    ) -> Result<Tex, StereoKitError> {
+        let ext = ext.as_ref().trim_start_matches('.').to_lowercase();
+        let signatures: &[&[u8]] = match ext.as_str() {
+            "jpg" | "jpeg" => &[b"\xFF\xD8\xFF"],
+            "png" => &[b"\x89PNG"],
+            "tga" => &[b""],
+            "bmp" => &[b"BM"],
+            "psd" => &[b"8BPS"],
+            "gif" => &[b"GIF87a", b"GIF89a"],
+            "hdr" => &[b"#?RADIANCE", b"#?RGBE"],
+            "pic" => &[b"\x53\x80\xF6\x34"],
+            "ktx2" => &[b"\xABKTX 20\xBB"],
+            _ => return Err(StereoKitError::Unsupported("a tex from memory".to_owned(), ext)),
+        };
+        if !signatures.iter().any(|signature| data.starts_with(signature)) {
+            return Err(StereoKitError::TexMemory);
+        }
+        Self::from_memory(data, srgb_data, priority)
+    }
+
     /// Loads an image file directly into a texture! Supported formats are: jpg, png, tga, bmp, psd, gif, hdr, pic, ktx2.
     /// Asset Id will be the same as the filename.
     /// <https://stereokit.net/Pages/StereoKit/Tex/FromFile.html>
@@ -436,6 +522,82 @@ impl Tex {
         ))?))
     }
 
+    /// Non canonical function creating a texture from raw pixel data with all its mip levels and array layers, so
+    /// nothing is generated by the GPU.
+    /// * format - The format of the pixels of `data`.
+    /// * width - The width of the first mip level.
+    /// * height - The height of the first mip level.
+    /// * data - One slice per mip level, the biggest first, for each array layer in turn. The size of each mip level
+    ///   is half the size of the previous one (at least 1 pixel).
+    /// * array_count - The number of array layers, 1 for a simple texture.
+    /// * id - If None, a default unique asset Id is created.
+    ///
+    /// Returns an error if the length of `data` or the size of one of its slices doesn't match the declared sizes.
+    /// see also [`crate::tex::tex_set_color_arr_mips`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::tex::{Tex, TexFormat};
+    ///
+    /// let mip0 = [255u8; 4 * 4 * 4];
+    /// let mip1 = [128u8; 2 * 2 * 4];
+    /// let tex = Tex::from_raw(TexFormat::RGBA32, 4, 4, &[&mip0, &mip1], 1, Some("raw_mips")).unwrap();
+    /// assert_eq!(tex.get_mips(), Some(2));
+    /// assert_eq!(tex.get_data_infos(1), Some((2, 2, 4)));
+    ///
+    /// // The second mip is missing a row.
+    /// assert!(Tex::from_raw(TexFormat::RGBA32, 4, 4, &[&mip0, &mip1[..8]], 1, None).is_err());
+    /// ```
+    pub fn from_raw(
+        format: TexFormat,
+        width: i32,
+        height: i32,
+        data: &[&[u8]],
+        array_count: i32,
+        id: Option<&str>,
+    ) -> Result<Tex, StereoKitError> {
+        let details = format!("{}x{} format {:?} with {} arrays", width, height, format, array_count);
+        let error = |reason: String| Err(StereoKitError::TexColor(details.clone(), reason));
+        let Some(pixel_size) = format.get_pixel_size() else {
+            return error("the format has no pixel size".into());
+        };
+        if width <= 0 || height <= 0 || array_count <= 0 {
+            return error("width, height and array_count must be positive".into());
+        }
+        if data.is_empty() || !data.len().is_multiple_of(array_count as usize) {
+            return error(format!("{} data slices can't be shared by {} arrays", data.len(), array_count));
+        }
+        let mip_count = data.len() / array_count as usize;
+        for (index, mip_data) in data.iter().enumerate() {
+            let mip = (index % mip_count) as u32;
+            let expected = (width >> mip).max(1) as usize * (height >> mip).max(1) as usize * pixel_size;
+            if mip_data.len() != expected {
+                return error(format!("data slice {} is {} bytes instead of {}", index, mip_data.len(), expected));
+            }
+        }
+
+        let tex_type = if mip_count > 1 { TexType::Image } else { TexType::ImageNomips };
+        let mut tex = Tex(NonNull::new(unsafe { tex_create(tex_type, format) })
+            .ok_or(StereoKitError::TexColor(details.clone(), "tex_create failed".into()))?);
+        if let Some(id) = id {
+            tex.id(id);
+        }
+        let mut pointers: Vec<*mut c_void> = data.iter().map(|mip_data| mip_data.as_ptr() as *mut c_void).collect();
+        unsafe {
+            tex_set_color_arr_mips(
+                tex.0.as_ptr(),
+                width,
+                height,
+                pointers.as_mut_ptr(),
+                array_count,
+                mip_count as i32,
+                null_mut(),
+            )
+        };
+        Ok(tex)
+    }
+
     /// Creates a texture and sets the texture’s pixels using a color array! Color values are converted to 32 bit colors,
     /// so this means a memory allocation and conversion. Prefer the Color32 overload for performance, or create an empty
     /// Texture and use SetColors for more flexibility. This will be an image of type TexType.Image, and a format of
@@ -529,6 +691,132 @@ impl Tex {
         Tex(NonNull::new(unsafe { tex_gen_particle(width, height, roundness, gradient_linear.0.as_ptr()) }).unwrap())
     }
 
+    /// Non canonical function baking a linear gradient into a new texture, for skyboxes, UI backgrounds or materials
+    /// without image files. The gradient goes along `direction` from one edge of the texture to the opposite one.
+    /// * colors - The stops of the gradient as (position, linear color), with positions from 0 to 1.
+    /// * width - in pixels
+    /// * height - in pixels
+    /// * direction - The axis of the gradient in texture space, X+ toward the right and Y+ toward the bottom. If zero,
+    ///   Vec2::X is used.
+    ///
+    /// Returns an error if the size is zero or if the texture can't be created.
+    /// see also [`Tex::gen_particle`] [`crate::util::Gradient`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Matrix, Vec2, Vec3}, material::Material, mesh::Mesh, tex::Tex,
+    ///                      util::{Color128, Color32}};
+    ///
+    /// let tex = Tex::gen_gradient(&[(0.0, Color128::new(1.0, 0.0, 0.0, 1.0)), (1.0, Color128::new(0.0, 0.0, 1.0, 1.0))],
+    ///                             64, 64, Vec2::Y).unwrap();
+    /// let pixels = vec![Color32::default(); 64 * 64];
+    /// assert!(tex.get_color_data::<Color32>(&pixels, 0));
+    /// // Red at the top, blue at the bottom.
+    /// assert!(pixels[0].r > 250 && pixels[0].b < 5);
+    /// assert!(pixels[63 * 64].b > 250 && pixels[63 * 64].r < 5);
+    /// assert_eq!(pixels[0], pixels[63]);
+    ///
+    /// assert!(Tex::gen_gradient(&[(0.0, Color128::BLACK)], 0, 64, Vec2::X).is_err());
+    ///
+    /// let plane = Mesh::generate_plane(Vec2::new(1.0, 1.0), Vec3::Z, Vec3::Y, None, false);
+    /// let mut material = Material::unlit().copy();
+    /// material.diffuse_tex(&tex);
+    ///
+    /// filename_scr = "screenshots/tex_gen_gradient.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     plane.draw(token, &material, Matrix::IDENTITY, None, None);
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/tex_gen_gradient.jpeg" alt="screenshot" width="200">
+    pub fn gen_gradient(
+        colors: &[(f32, Color128)],
+        width: usize,
+        height: usize,
+        direction: impl Into<Vec2>,
+    ) -> Result<Tex, StereoKitError> {
+        if width == 0 || height == 0 {
+            return Err(StereoKitError::TexColor(format!("{}x{}", width, height), "size must not be zero".to_string()));
+        }
+        let keys: Vec<GradientKey> =
+            colors.iter().map(|(position, color)| GradientKey::new(*color, *position)).collect();
+        let gradient = Gradient::new(Some(&keys));
+
+        let mut direction: Vec2 = direction.into();
+        if direction.length_sq() <= f32::EPSILON {
+            direction = Vec2::X;
+        }
+        let direction = direction.get_normalized();
+        // Half of the projected size of the texture on the direction, so the corners reach 0 and 1.
+        let extent = (direction.x.abs() + direction.y.abs()) / 2.0;
+
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let uv = Vec2::new((x as f32 + 0.5) / width as f32 - 0.5, (y as f32 + 0.5) / height as f32 - 0.5);
+                let at = (Vec2::dot(uv, direction) + extent) / (2.0 * extent);
+                pixels.push(gradient.get(at.clamp(0.0, 1.0)));
+            }
+        }
+        Tex::from_color128(&pixels, width, height, false)
+    }
+
+    /// Non canonical function generating a grey Perlin noise texture, for clouds, dirt or any organic variation. The
+    /// same seed always gives the same pixels.
+    /// * width - in pixels
+    /// * height - in pixels
+    /// * seed - Selects the noise pattern.
+    /// * scale - The number of noise cells along the width of the texture, bigger values give finer details. The
+    ///   cells are square, so their number along the height depends on the aspect of the texture.
+    ///
+    /// Returns an error if the size is zero or if the texture can't be created.
+    /// see also [`Tex::gen_gradient`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Matrix, Vec2, Vec3}, material::Material, mesh::Mesh, tex::Tex, util::Color32};
+    ///
+    /// let noise = Tex::gen_noise(128, 128, 42, 8.0).unwrap();
+    ///
+    /// // The same seed gives the same pixels, another seed gives other ones.
+    /// let (pixels, same, other) = (vec![Color32::default(); 128 * 128], vec![Color32::default(); 128 * 128],
+    ///                              vec![Color32::default(); 128 * 128]);
+    /// assert!(noise.get_color_data::<Color32>(&pixels, 0));
+    /// assert!(Tex::gen_noise(128, 128, 42, 8.0).unwrap().get_color_data::<Color32>(&same, 0));
+    /// assert!(Tex::gen_noise(128, 128, 7, 8.0).unwrap().get_color_data::<Color32>(&other, 0));
+    /// assert!(pixels == same);
+    /// assert!(pixels != other);
+    ///
+    /// let plane = Mesh::generate_plane(Vec2::new(1.0, 1.0), Vec3::Z, Vec3::Y, None, false);
+    /// let mut material = Material::unlit().copy();
+    /// material.diffuse_tex(&noise);
+    ///
+    /// filename_scr = "screenshots/tex_gen_noise.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     plane.draw(token, &material, Matrix::IDENTITY, None, None);
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/tex_gen_noise.jpeg" alt="screenshot" width="200">
+    pub fn gen_noise(width: usize, height: usize, seed: u32, scale: f32) -> Result<Tex, StereoKitError> {
+        if width == 0 || height == 0 {
+            return Err(StereoKitError::TexColor(format!("{}x{}", width, height), "size must not be zero".to_string()));
+        }
+        let cell = scale.max(f32::EPSILON) / width as f32;
+
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let noise = perlin_noise((x as f32 + 0.5) * cell, (y as f32 + 0.5) * cell, seed);
+                // The 2D Perlin noise is within ±√½.
+                let value = ((noise * std::f32::consts::SQRT_2 + 1.0) * 0.5).clamp(0.0, 1.0);
+                let grey = (value * 255.0).round() as u8;
+                pixels.push(Color32::new(grey, grey, grey, 255));
+            }
+        }
+        Tex::from_color32(&pixels, width, height, false)
+    }
+
     /// This is the texture that all Tex objects will fall back to by default if they are still loading. Assigning a texture here that isn’t fully
     /// loaded will cause the app to block until it is loaded.
     /// <https://stereokit.net/Pages/StereoKit/Tex/SetLoadingFallback.html>
@@ -572,6 +860,91 @@ impl Tex {
             .ok_or(StereoKitError::TexCopy(self.get_id().into()))?))
     }
 
+    /// Non canonical function copying a rectangle of pixels of this texture into another texture at a given offset.
+    /// This is done on the CPU: the pixels of both textures are read back from the GPU, then the destination is
+    /// uploaded again, so this is a slow operation that shouldn't be used at each frame.
+    /// * dst - The texture receiving the pixels. It must have the same format as this texture.
+    /// * src_rect - The region of this texture to copy, in pixels with 0,0 at the top left corner.
+    /// * dst_offset - Where the top left corner of the region will be in `dst`, in pixels.
+    ///
+    /// Returns an error if the formats differ, have no known pixel size or if the region doesn't fit inside one of the
+    /// textures.
+    /// see also [`crate::tex::tex_get_data`] [`crate::tex::tex_set_colors`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Recti, Vec2i}, tex::Tex, util::{named_colors, Color32}};
+    ///
+    /// let red = Tex::from_color32(&[named_colors::RED; 4 * 4], 4, 4, false).unwrap();
+    /// let mut dst = Tex::from_color32(&[named_colors::BLACK; 8 * 8], 8, 8, false).unwrap();
+    ///
+    /// red.copy_region(&mut dst, Recti::new(1, 1, 2, 2), Vec2i::new(5, 6)).unwrap();
+    ///
+    /// let pixels = vec![Color32::default(); 8 * 8];
+    /// assert!(dst.get_color_data::<Color32>(&pixels, 0));
+    /// assert_eq!(pixels[6 * 8 + 5], named_colors::RED);
+    /// assert_eq!(pixels[7 * 8 + 6], named_colors::RED);
+    /// assert_eq!(pixels[6 * 8 + 4], named_colors::BLACK);
+    ///
+    /// // The region doesn't fit in the source or in the destination.
+    /// assert!(red.copy_region(&mut dst, Recti::new(2, 2, 4, 4), Vec2i::ZERO).is_err());
+    /// assert!(red.copy_region(&mut dst, Recti::new(0, 0, 4, 4), Vec2i::new(6, 0)).is_err());
+    ///
+    /// // The formats differ.
+    /// let mut srgb = Tex::from_color32(&[named_colors::BLACK; 8 * 8], 8, 8, true).unwrap();
+    /// assert!(red.copy_region(&mut srgb, Recti::new(0, 0, 2, 2), Vec2i::ZERO).is_err());
+    /// ```
+    pub fn copy_region(&self, dst: &mut Tex, src_rect: Recti, dst_offset: Vec2i) -> Result<(), StereoKitError> {
+        let details = |reason: &str| {
+            Err(StereoKitError::TexCopy(format!(
+                "{} into {} ({:?}): {}",
+                self.get_id(),
+                dst.get_id(),
+                src_rect,
+                reason
+            )))
+        };
+        let (Some(format), Some(dst_format)) = (self.get_format(), dst.get_format()) else {
+            return details("textures have no format yet");
+        };
+        if format != dst_format {
+            return details(&format!("formats {:?} and {:?} differ", format, dst_format));
+        }
+        let Some(pixel_size) = format.get_pixel_size() else {
+            return details(&format!("format {:?} has no pixel size", format));
+        };
+        let (Some(src_width), Some(src_height), Some(dst_width), Some(dst_height)) =
+            (self.get_width(), self.get_height(), dst.get_width(), dst.get_height())
+        else {
+            return details("textures have no size yet");
+        };
+        if !src_rect.is_inside(src_width as i32, src_height as i32) {
+            return details("the region is outside of the source");
+        }
+        let dst_rect = Recti::new(dst_offset.x, dst_offset.y, src_rect.width, src_rect.height);
+        if !dst_rect.is_inside(dst_width as i32, dst_height as i32) {
+            return details("the region is outside of the destination");
+        }
+
+        let mut src_data = vec![0u8; src_width * src_height * pixel_size];
+        let mut dst_data = vec![0u8; dst_width * dst_height * pixel_size];
+        unsafe {
+            tex_get_data(self.0.as_ptr(), src_data.as_mut_ptr() as *mut c_void, src_data.len(), 0);
+            tex_get_data(dst.0.as_ptr(), dst_data.as_mut_ptr() as *mut c_void, dst_data.len(), 0);
+        }
+        let row_len = src_rect.width as usize * pixel_size;
+        for row in 0..src_rect.height as usize {
+            let src_start = ((src_rect.y as usize + row) * src_width + src_rect.x as usize) * pixel_size;
+            let dst_start = ((dst_rect.y as usize + row) * dst_width + dst_rect.x as usize) * pixel_size;
+            dst_data[dst_start..dst_start + row_len].copy_from_slice(&src_data[src_start..src_start + row_len]);
+        }
+        unsafe {
+            tex_set_colors(dst.0.as_ptr(), dst_width as i32, dst_height as i32, dst_data.as_mut_ptr() as *mut c_void)
+        };
+        Ok(())
+    }
+
     /// Creates a clone of the same reference. Basically, the new variable is the same asset. This is what you get by
     /// calling find() method.
     /// <https://stereokit.net/Pages/StereoKit/Tex/Find.html>
@@ -1307,6 +1680,34 @@ impl Tex {
     // }
 }
 
+/// A unit gradient of the Perlin noise lattice, hashed from the corner and the seed.
+fn perlin_gradient(ix: i32, iy: i32, seed: u32) -> Vec2 {
+    let mut hash =
+        (ix as u32).wrapping_mul(0x8da6_b343) ^ (iy as u32).wrapping_mul(0xd816_3841) ^ seed.wrapping_mul(0xcb1a_b31f);
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(0x2c1b_3c6d);
+    hash ^= hash >> 12;
+    hash = hash.wrapping_mul(0x297a_2d39);
+    hash ^= hash >> 15;
+    let angle = hash as f32 / u32::MAX as f32 * std::f32::consts::TAU;
+    Vec2::new(angle.cos(), angle.sin())
+}
+
+/// 2D Perlin noise at a point given in noise cells, within ±√½.
+fn perlin_noise(x: f32, y: f32, seed: u32) -> f32 {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let (ix, iy) = (x0 as i32, y0 as i32);
+    let corner = |dx: i32, dy: i32| {
+        Vec2::dot(perlin_gradient(ix + dx, iy + dy, seed), Vec2::new(fx - dx as f32, fy - dy as f32))
+    };
+    let fade = |t: f32| t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+    let (u, v) = (fade(fx), fade(fy));
+    let top = corner(0, 0) + (corner(1, 0) - corner(0, 0)) * u;
+    let bottom = corner(0, 1) + (corner(1, 1) - corner(0, 1)) * u;
+    top + (bottom - top) * v
+}
+
 /// fluent syntax for Texture cubemap
 /// <https://stereokit.net/Pages/StereoKit/Tex.html>
 ///
@@ -1410,6 +1811,80 @@ impl SHCubemap {
         Ok(SHCubemap { sh: SphericalHarmonics::default(), tex })
     }
 
+    /// Non canonical function creating a cubemap from 6 image files, one per face, and extracting its lighting. This
+    /// blocks until the faces are loaded.
+    /// * faces - 6 image filenames, in order of +X, -X, +Y, -Y, +Z, -Z. They are loaded as sRGB color data.
+    /// * id - If Some, the Asset Id of the cubemap texture. If None, the Asset Id will be the first filename.
+    ///
+    /// Returns the cubemap with its lighting, or an error naming the first face that could not be loaded.
+    /// see also [`SHCubemap::from_cubemap_files`] [`SHCubemap::get_sample`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Vec3, tex::SHCubemap};
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// // Six solid color faces, written as tiny uncompressed TGA files.
+    /// let colors: [[u8; 3]; 6] = [[255, 0, 0], [0, 255, 0], [0, 0, 255], [0, 0, 0], [64, 64, 64], [64, 64, 64]];
+    /// let dir = std::env::temp_dir();
+    /// let files: Vec<PathBuf> = colors
+    ///     .iter()
+    ///     .enumerate()
+    ///     .map(|(i, [r, g, b])| {
+    ///         let mut tga = vec![0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 4, 0, 24, 0];
+    ///         for _ in 0..16 {
+    ///             tga.extend_from_slice(&[*b, *g, *r]);
+    ///         }
+    ///         let file = dir.join(format!("sh_cubemap_face_{i}.tga"));
+    ///         std::fs::write(&file, tga).unwrap();
+    ///         file
+    ///     })
+    ///     .collect();
+    /// let faces: [&Path; 6] = std::array::from_fn(|i| files[i].as_path());
+    ///
+    /// let cubemap = SHCubemap::from_faces(faces, Some("solid_faces")).unwrap();
+    /// assert_eq!(cubemap.tex.get_id(), "solid_faces");
+    /// let (right, left) = (cubemap.get_sample(Vec3::X), cubemap.get_sample(-Vec3::X));
+    /// assert!(right.r > left.r && right.r > right.g);
+    /// assert!(left.g > right.g && left.g > left.r);
+    /// let (up, down) = (cubemap.get_sample(Vec3::Y), cubemap.get_sample(-Vec3::Y));
+    /// assert!(up.b > down.b && up.b > up.r);
+    ///
+    /// let mut missing = faces;
+    /// missing[3] = Path::new("this/face/does_not_exist.png");
+    /// assert!(SHCubemap::from_faces(missing, None).is_err());
+    /// ```
+    pub fn from_faces(faces: [&Path; 6], id: Option<&str>) -> Result<SHCubemap, StereoKitError> {
+        const FACE_NAMES: [&str; 6] = ["+X", "-X", "+Y", "-Y", "+Z", "-Z"];
+        let mut cubemap = Self::from_cubemap_files(&faces, true, 0)?;
+        if let Some(id) = id {
+            cubemap.tex.id(id);
+        }
+        // This waits for the faces to be loaded.
+        cubemap.sh = unsafe { tex_get_cubemap_lighting(cubemap.tex.0.as_ptr()) };
+        match cubemap.tex.get_asset_state() {
+            AssetState::Loaded => Ok(cubemap),
+            state => {
+                let assets_dir = PathBuf::from(crate::tools::os_api::get_assets_dir());
+                let face = faces.iter().position(|face| !face.exists() && !assets_dir.join(face).exists());
+                let (name, path) = match face {
+                    Some(i) => (FACE_NAMES[i], faces[i].to_path_buf()),
+                    None => ("one of the faces", faces[0].to_path_buf()),
+                };
+                Err(StereoKitError::TexFiles(path, format!("cube face {name} could not be loaded: {state:?}")))
+            }
+        }
+    }
+
+    /// Non canonical function evaluating the lighting of this cubemap (its spherical harmonics) in a direction.
+    /// * direction - The direction to sample, from the center of the cubemap.
+    ///
+    /// see also [`SphericalHarmonics::get_sample`] [`SHCubemap::from_faces`]
+    pub fn get_sample(&self, direction: impl Into<Vec3>) -> Color128 {
+        self.sh.get_sample(direction)
+    }
+
     /// Generates a cubemap texture from a gradient and a direction! These are entirely suitable for skyboxes, which
     /// you can set via Renderer.SkyTex.
     /// <https://stereokit.net/Pages/StereoKit/Tex/GenCubemap.html>
@@ -133,6 +133,48 @@ pub fn compile_hlsl(
     Ok(true)
 }
 
+/// compile a single hlsl file to sks into the given target directory. This is the same call to skshaderc than
+/// compile_hlsl(), but the diagnostics of the compiler are returned instead of being printed.
+///
+/// Returns the path of the compiled .sks file or the diagnostics of skshaderc
+pub fn compile_hlsl_file(
+    hlsl_file: impl AsRef<Path>,
+    target_dir: impl AsRef<Path>,
+    options: &[&str],
+) -> Result<PathBuf, String> {
+    let hlsl_file = hlsl_file.as_ref();
+    let target_dir = target_dir.as_ref();
+
+    //we get the dir from StereoKit-rust (not from here)
+    let bin_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let skshaderc = get_skshaderc(bin_dir.clone(), false);
+    if !skshaderc.is_file() {
+        return Err(format!("skshaderc executable {:?} is not available on this target", skshaderc));
+    }
+
+    let mut shaders_include = bin_dir.clone();
+    shaders_include.push("StereoKit");
+    shaders_include.push("tools");
+    shaders_include.push("include");
+
+    let mut cmd = Command::new(OsStr::new(skshaderc.to_str().unwrap_or("NOPE")));
+    cmd.arg("-f").arg("-e").arg("-i").arg(&shaders_include).arg("-o").arg(target_dir);
+    for arg in options {
+        cmd.arg(arg);
+    }
+    let output = cmd.arg(hlsl_file).output().map_err(|err| format!("failed to run shader compiler: {}", err))?;
+    let out = String::from_utf8_lossy(&output.stdout).to_string();
+    let err = String::from_utf8_lossy(&output.stderr).to_string();
+
+    let file_name = hlsl_file.file_name().ok_or(format!("{:?} is not a file", hlsl_file))?;
+    let sks_file = target_dir.join(format!("{}.sks", file_name.to_string_lossy()));
+    if output.status.success() && sks_file.is_file() {
+        Ok(sks_file)
+    } else {
+        Err(format!("{}{}", out, err))
+    }
+}
+
 /// Recursive fn to copy all the content of a directory
 pub fn copy_tree(src: impl AsRef<Path>, dst: impl AsRef<Path>) -> std::io::Result<()> {
     if let Err(_err) = fs::create_dir(&dst) {}
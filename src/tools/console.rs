@@ -0,0 +1,166 @@
+use crate::{
+    maths::{units::CM, Pose, Vec2},
+    prelude::*,
+    system::{Input, Key},
+    ui::Ui,
+};
+use std::collections::{BTreeMap, VecDeque};
+
+/// The StepperAction to trigger with the value "true" or "false" to show or hide the console.
+pub const SHOW_CONSOLE: &str = "Tool_ShowConsole";
+
+/// The default maximum number of lines kept in the scrollback.
+pub const DEFAULT_MAX_SCROLLBACK: usize = 500;
+
+/// The handler of a console command. It receives the arguments following the command name and returns the text to
+/// print in the scrollback.
+pub type ConsoleCommand = Box<dyn FnMut(&[&str]) -> String + 'static>;
+
+/// A window with a text input where the user types commands registered by the app with [`Console::register`], and a
+/// scrollback showing the commands and their outputs. `help` lists the registered commands.
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::tools::console::{Console, SHOW_CONSOLE};
+///
+/// let mut console = Console::default();
+/// console.register("echo", |args| args.join(" "));
+///
+/// assert_eq!(console.execute("echo Hello   console"), "Hello console");
+/// assert!(console.get_scrollback().contains(&"Hello console".to_string()));
+///
+/// assert!(console.execute("help").contains("echo"));
+/// assert!(console.execute("unknown").starts_with("Unknown command"));
+///
+/// sk.push_action(StepperAction::add("Console", console));
+/// sk.push_action(StepperAction::event("main".into(), SHOW_CONSOLE, "true"));
+///
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+/// );
+/// ```
+#[derive(IStepper)]
+pub struct Console {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    pub enabled: bool,
+
+    pub pose: Pose,
+    pub x_len: f32,
+    /// The number of scrollback lines displayed.
+    pub y_len: usize,
+    /// The oldest lines of the scrollback are removed beyond this count.
+    pub max_scrollback: usize,
+    /// The text currently typed in the input field.
+    pub input: String,
+    commands: BTreeMap<String, ConsoleCommand>,
+    scrollback: VecDeque<String>,
+}
+
+unsafe impl Send for Console {}
+
+impl Default for Console {
+    fn default() -> Self {
+        Self {
+            id: "Console".to_string(),
+            sk_info: None,
+            enabled: true,
+
+            pose: Pose::new([0.0, 1.3, -0.5], None),
+            x_len: 60.0,
+            y_len: 12,
+            max_scrollback: DEFAULT_MAX_SCROLLBACK,
+            input: String::new(),
+            commands: BTreeMap::new(),
+            scrollback: VecDeque::new(),
+        }
+    }
+}
+
+impl Console {
+    /// Register a command. A command registered with the same name replaces the previous one.
+    /// * name - The first word to type to call the command. `help` is reserved.
+    /// * handler - Called with the following words, its result is printed in the scrollback.
+    pub fn register(&mut self, name: &str, handler: impl FnMut(&[&str]) -> String + 'static) {
+        self.commands.insert(name.to_string(), Box::new(handler));
+    }
+
+    /// Remove a registered command. Returns false if there was no command with this name.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.commands.remove(name).is_some()
+    }
+
+    /// Run a command line as if it had been typed in the input field. The line and the result are added to the
+    /// scrollback.
+    ///
+    /// Returns the result of the command.
+    pub fn execute(&mut self, line: &str) -> String {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((name, args)) = words.split_first() else {
+            return String::new();
+        };
+        let result = if *name == "help" {
+            let names: Vec<&str> = self.commands.keys().map(|name| name.as_str()).collect();
+            format!("Commands: help {}", names.join(" "))
+        } else if let Some(handler) = self.commands.get_mut(*name) {
+            handler(args)
+        } else {
+            format!("Unknown command '{name}', type help to list the commands")
+        };
+        self.push_line(format!("> {}", line.trim()));
+        for result_line in result.lines() {
+            self.push_line(result_line.to_string());
+        }
+        result
+    }
+
+    /// The lines of the scrollback, from the oldest to the newest.
+    pub fn get_scrollback(&self) -> Vec<String> {
+        self.scrollback.iter().cloned().collect()
+    }
+
+    /// Remove all the lines of the scrollback.
+    pub fn clear(&mut self) {
+        self.scrollback.clear();
+    }
+
+    fn push_line(&mut self, line: String) {
+        self.scrollback.push_back(line);
+        while self.scrollback.len() > self.max_scrollback {
+            self.scrollback.pop_front();
+        }
+    }
+
+    /// Called from IStepper::initialize here you can abort the initialization by returning false
+    fn start(&mut self) -> bool {
+        true
+    }
+
+    /// Called from IStepper::step, here you can check the event report
+    fn check_event(&mut self, _id: &StepperId, key: &str, value: &str) {
+        if let Some(ToolEvent::ShowConsole(shown)) = ToolEvent::from_key_value(key, value) {
+            self.enabled = shown
+        }
+    }
+
+    /// Called from IStepper::step, after check_event here you can draw your UI
+    fn draw(&mut self, _token: &MainThreadToken) {
+        Ui::window_begin("Console", &mut self.pose, Some(Vec2::new(self.x_len, 0.0) * CM), None, None);
+        let first = self.scrollback.len().saturating_sub(self.y_len);
+        for index in 0..self.y_len {
+            let line = self.scrollback.get(first + index).map_or("", |line| line.as_str());
+            Ui::label(line, None, false);
+        }
+        Ui::hseparator();
+        if let Some(input) = Ui::input("console_input", &self.input, Some(Vec2::new(self.x_len * CM * 0.8, 0.0)), None)
+        {
+            self.input = input;
+        }
+        Ui::same_line();
+        if (Ui::button("Run", None) || Input::key(Key::Return).is_just_active()) && !self.input.trim().is_empty() {
+            let line = std::mem::take(&mut self.input);
+            self.execute(&line);
+        }
+        Ui::window_end();
+    }
+}
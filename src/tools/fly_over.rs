@@ -8,14 +8,68 @@ use crate::{
 
 pub const ENABLE_FLY_OVER: &str = "Tool_EnableFlyOver";
 
+/// Stick equivalent of one pixel of mouse move.
+const MOUSE_SENSITIVITY: f32 = 0.05;
+
+/// The inputs driving a [`FlyOver`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlyOverInput {
+    /// Arrow keys (in debug builds only) to move, middle mouse button + mouse to rotate.
+    KeyboardMouse,
+    /// Left controller stick to move, right controller stick to rotate.
+    ControllerStick,
+    /// Both keyboard+mouse and controller sticks.
+    Both,
+}
+
+/// Move the camera root with the keyboard, the mouse or the controller sticks. The speeds can be changed at any time.
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{system::{Input, Key, Renderer}, tools::fly_over::{FlyOver, FlyOverInput}, util::Time};
+///
+/// let mut fly_over = FlyOver::default();
+/// fly_over.input_source = FlyOverInput::KeyboardMouse;
+/// fly_over.move_speed = 1.0;
+/// fly_over.initialize("FlyOver".into(), sk.get_sk_info_clone());
+///
+/// let (mut slow, mut fast) = (0.0, 0.0);
+/// number_of_steps = 20;
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     // The arrow keys move at the step they are pressed, in debug builds.
+///     if iter % 2 == 0 { Input::key_inject_press(Key::Up) } else { Input::key_inject_release(Key::Up) }
+///     if iter == 10 { fly_over.move_speed = 4.0 }
+///     let before = Renderer::get_camera_root().get_pose().position;
+///     fly_over.step(token);
+///     let after = Renderer::get_camera_root().get_pose().position;
+///     let step = Time::get_step_unscaledf();
+///     let moved = (after - before).length();
+///     if step > 0.0 && moved > 0.0 && iter > 2 {
+///         let speed = moved / step;
+///         if iter < 10 { slow = speed } else { fast = speed }
+///     }
+/// );
+/// Input::key_inject_release(Key::Up);
+/// assert!(fast > slow * 2.0, "fast {fast} / slow {slow}");
+/// ```
 #[derive(IStepper)]
 pub struct FlyOver {
     id: StepperId,
     sk_info: Option<Rc<RefCell<SkInfo>>>,
     pub enabled: bool,
 
+    /// Meters per second.
     pub move_speed: f32,
+    /// Degrees per second.
     pub rotate_speed: f32,
+    /// Inverts the vertical axis of the look: pushing the right stick or moving the mouse up looks down.
+    pub invert_y: bool,
+    pub input_source: FlyOverInput,
+    /// Multiplier of the speed while Shift is pressed or the left stick is clicked.
+    pub sprint_multiplier: f32,
+    /// Multiplier applied to both move and rotate speeds.
+    pub speed_multiplier: f32,
     reverse: f32,
 }
 
@@ -30,6 +84,10 @@ impl Default for FlyOver {
 
             move_speed: 2.0,
             rotate_speed: 90.0,
+            invert_y: false,
+            input_source: FlyOverInput::Both,
+            sprint_multiplier: 3.0,
+            speed_multiplier: 1.0,
             reverse: 1.0,
         }
     }
@@ -56,22 +114,26 @@ impl FlyOver {
 
     /// Called from IStepper::step, here you can check the event report
     fn check_event(&mut self, _id: &StepperId, key: &str, value: &str) {
-        if key.eq(ENABLE_FLY_OVER) {
-            self.enabled = value.parse().unwrap_or(false);
+        if let Some(ToolEvent::EnableFlyOver(enabled)) = ToolEvent::from_key_value(key, value) {
+            self.enabled = enabled;
             Log::diag(format!("Fly_Over: enabled is {}", self.enabled));
         }
     }
 
     /// Called from IStepper::step, after check_event here you can draw your UI
     fn draw(&mut self, _token: &MainThreadToken) {
+        let use_keyboard = self.input_source != FlyOverInput::ControllerStick;
+        let use_controller = self.input_source != FlyOverInput::KeyboardMouse;
+
         //----- move
         let mut camera_root = Renderer::get_camera_root();
         let head = Input::get_head();
 
         let move_ctrler = Input::controller(Handed::Left);
-        let mut move_v = -move_ctrler.stick.x0y();
+        let mut move_v = if use_controller { -move_ctrler.stick.x0y() } else { Vec3::ZERO };
+        let mut sprint = use_controller && move_ctrler.is_stick_clicked();
 
-        if cfg!(debug_assertions) {
+        if cfg!(debug_assertions) && use_keyboard {
             if Input::key(Key::Up).is_just_active() {
                 move_v.z = -1.0;
             }
@@ -84,16 +146,17 @@ impl FlyOver {
             if Input::key(Key::Left).is_just_active() {
                 move_v.x = -1.0;
             }
+            sprint |= Input::key(Key::Shift).is_active();
         }
-        let mut speed_accelerator = self.move_speed;
         if move_v != Vec3::ZERO {
+            let mut speed_accelerator = self.move_speed * self.speed_multiplier;
             let camera_pose = camera_root.get_pose();
             let head_forward = head.get_forward();
             move_v.y = head_forward.y * self.reverse;
             let mut shift = camera_pose.position;
 
-            if move_ctrler.is_stick_clicked() {
-                speed_accelerator *= 3.0;
+            if sprint {
+                speed_accelerator *= self.sprint_multiplier;
             }
 
             shift += head.orientation * move_v * Time::get_step_unscaledf() * speed_accelerator * self.reverse;
@@ -102,15 +165,32 @@ impl FlyOver {
         }
 
         //----- rotate
-
-        let rotate_stick = Input::controller(Handed::Right).stick;
-        let rotate_val = Vec2::dot(rotate_stick, Vec2::X);
+        let (mut rotate_val, mut pitch_val) = (0.0, 0.0);
+        if use_controller {
+            let rotate_stick = Input::controller(Handed::Right).stick;
+            rotate_val = Vec2::dot(rotate_stick, Vec2::X);
+            pitch_val = Vec2::dot(rotate_stick, Vec2::Y);
+        }
+        if use_keyboard && rotate_val == 0.0 && pitch_val == 0.0 && Input::key(Key::MouseCenter).is_active() {
+            let mouse_change = Input::get_mouse().pos_change;
+            rotate_val = (mouse_change.x * MOUSE_SENSITIVITY).clamp(-1.0, 1.0);
+            // The mouse y goes down the screen.
+            pitch_val = (-mouse_change.y * MOUSE_SENSITIVITY).clamp(-1.0, 1.0);
+        }
+        if self.invert_y {
+            pitch_val = -pitch_val;
+        }
 
         // Credit to Cazzola: https://discord.com/channels/805160376529715210/805160377130156124/1307293861680255067
-        if rotate_val != 0.0 {
-            let delta_rotate = Quat::from_angles(0.0, rotate_val * self.rotate_speed * Time::get_step_unscaledf(), 0.0);
+        if rotate_val != 0.0 || pitch_val != 0.0 {
+            let angle = self.rotate_speed * self.speed_multiplier * Time::get_step_unscaledf();
+            let delta_rotate = Quat::from_angles(0.0, rotate_val * angle, 0.0);
+            // The pitch turns around the right axis of the head.
+            let head_rotation = Matrix::r(head.orientation);
+            let delta_pitch =
+                head_rotation.get_inverse() * Matrix::r(Quat::from_angles(pitch_val * angle, 0.0, 0.0)) * head_rotation;
             let camera_in_head_space = camera_root * Matrix::t(head.position).get_inverse();
-            let rotated = camera_in_head_space * Matrix::r(delta_rotate);
+            let rotated = camera_in_head_space * delta_pitch * Matrix::r(delta_rotate);
             Renderer::camera_root(rotated * Matrix::t(head.position));
         }
     }
@@ -0,0 +1,166 @@
+use crate::{
+    maths::{Pose, Quat, Vec3},
+    prelude::*,
+    system::Input,
+};
+use std::cell::Cell;
+
+/// Keep a pose in front of the user, facing them, for UI panels or labels that must stay in sight. At each step the
+/// pose is placed `distance` meters in front of the head, turned with the heading (yaw) of the head so it stays
+/// level, then shifted by `offset`.
+/// * lazy_angle - If set, the pose only moves when the head heading differs from the pose by more than this angle in
+///   degrees, or when its distance to the head differs from `distance` by more than `lazy_distance`.
+/// * billboard_only - If true, the pose keeps its position and only rotates to face the head.
+///
+/// The pose is shared with [`FollowHead::get_shared_pose`], so other steppers can draw at it.
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{maths::{Pose, Quat, Vec3}, system::{Input, Lines}, tools::follow::FollowHead,
+///                      util::named_colors::RED};
+///
+/// let mut follow = FollowHead::new(0.5, Vec3::ZERO);
+/// let head = Pose::new(Vec3::ZERO, None);
+/// let pose = follow.follow(head);
+/// assert!((Vec3::distance(pose.position, head.position) - 0.5).abs() < 0.001);
+/// assert!(Vec3::distance(pose.position, Vec3::new(0.0, 0.0, -0.5)) < 0.001);
+///
+/// // The head moves, the pose stays at the configured distance.
+/// let head = Pose::new([1.0, 0.2, 0.0], Some(Quat::from_angles(0.0, 90.0, 0.0)));
+/// let pose = follow.follow(head);
+/// assert!((Vec3::distance(pose.position, head.position) - 0.5).abs() < 0.001);
+///
+/// // In lazy mode small head turns don't move the pose, large ones do.
+/// follow.lazy_angle = Some(30.0);
+/// let turned = Pose::new(head.position, Some(Quat::from_angles(0.0, 100.0, 0.0)));
+/// assert!(Vec3::distance(follow.follow(turned).position, pose.position) < 0.001);
+/// let turned = Pose::new(head.position, Some(Quat::from_angles(0.0, 140.0, 0.0)));
+/// assert!(Vec3::distance(follow.follow(turned).position, pose.position) > 0.1);
+///
+/// // The stepper follows the simulated head, and shares its pose.
+/// let mut follow_head = FollowHead::new(0.6, Vec3::new(0.0, -0.1, 0.0));
+/// let shared_pose = follow_head.get_shared_pose();
+/// sk.push_action(StepperAction::add("FollowHead", follow_head));
+///
+/// filename_scr = "screenshots/follow_head.jpeg";
+/// number_of_steps = 4;
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     Lines::add_axis(token, shared_pose.get(), Some(0.1), Some(0.01));
+/// );
+/// let head = Input::get_head();
+/// assert!((Vec3::distance(shared_pose.get().position, head.position) - (0.6f32 * 0.6 + 0.1 * 0.1).sqrt()).abs() < 0.01);
+/// ```
+/// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/follow_head.jpeg" alt="screenshot" width="200">
+#[derive(IStepper)]
+pub struct FollowHead {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    pub enabled: bool,
+
+    /// Distance from the head, in meters.
+    pub distance: f32,
+    /// Shift of the pose in the space of the head heading, in meters. X+ is on the right, Y+ is up.
+    pub offset: Vec3,
+    /// If set, the angle in degrees the head heading must turn away from the pose before it moves.
+    pub lazy_angle: Option<f32>,
+    /// In lazy mode, the difference of distance to the head in meters before the pose moves.
+    pub lazy_distance: f32,
+    /// If true, the pose keeps its position and only rotates to face the head.
+    pub billboard_only: bool,
+    pose: Rc<Cell<Pose>>,
+    placed: bool,
+}
+
+unsafe impl Send for FollowHead {}
+
+impl Default for FollowHead {
+    fn default() -> Self {
+        Self {
+            id: "FollowHead".to_string(),
+            sk_info: None,
+            enabled: true,
+
+            distance: 0.5,
+            offset: Vec3::ZERO,
+            lazy_angle: None,
+            lazy_distance: 0.1,
+            billboard_only: false,
+            pose: Rc::new(Cell::new(Pose::IDENTITY)),
+            placed: false,
+        }
+    }
+}
+
+impl FollowHead {
+    /// Create a follower always moving with the head.
+    /// * distance - Distance from the head, in meters.
+    /// * offset - Shift of the pose in the space of the head heading, in meters.
+    pub fn new(distance: f32, offset: impl Into<Vec3>) -> Self {
+        Self { distance, offset: offset.into(), ..Default::default() }
+    }
+
+    /// The pose maintained by the follower.
+    pub fn get_pose(&self) -> Pose {
+        self.pose.get()
+    }
+
+    /// The pose maintained by the follower, shared with the ones keeping this clone. It's updated at each step, even
+    /// once the follower has been moved into the steppers.
+    pub fn get_shared_pose(&self) -> Rc<Cell<Pose>> {
+        self.pose.clone()
+    }
+
+    /// Place the pose relatively to a head pose. This is called at each step with [`Input::get_head`], you may call it
+    /// with your own head pose.
+    /// * head - The pose of the head.
+    ///
+    /// Returns the new pose.
+    pub fn follow(&mut self, head: Pose) -> Pose {
+        let forward = head.get_forward().x0z();
+        let heading = if forward.length_sq() > f32::EPSILON {
+            Quat::look_dir(forward)
+        } else {
+            self.pose.get().orientation
+        };
+        let target = head.position + heading.mul_vec3(Vec3::FORWARD * self.distance + self.offset);
+
+        let mut position = self.pose.get().position;
+        if !self.placed {
+            position = target;
+            self.placed = true;
+        } else if !self.billboard_only {
+            let must_move = match self.lazy_angle {
+                None => true,
+                Some(lazy_angle) => {
+                    let to_pose = (position - head.position).x0z();
+                    let to_target = (target - head.position).x0z();
+                    let expected = Vec3::distance(target, head.position);
+                    Vec3::angle_between(to_pose, to_target) > lazy_angle
+                        || (Vec3::distance(position, head.position) - expected).abs() > self.lazy_distance
+                }
+            };
+            if must_move {
+                position = target;
+            }
+        }
+
+        let orientation = Quat::look_at(position, head.position, None);
+        let pose = Pose::new(position, Some(orientation));
+        self.pose.set(pose);
+        pose
+    }
+
+    /// Called from IStepper::initialize here you can abort the initialization by returning false
+    fn start(&mut self) -> bool {
+        true
+    }
+
+    /// Called from IStepper::step, here you can check the event report
+    fn check_event(&mut self, _id: &StepperId, _key: &str, _value: &str) {}
+
+    /// Called from IStepper::step, after check_event here you can draw your UI and scene
+    fn draw(&mut self, _token: &MainThreadToken) {
+        self.follow(Input::get_head());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{
+    maths::{Pose, Quat, Vec3},
+    prelude::*,
+    system::{Input, Lines},
+    ui::UiHandleSnap,
+    util::Color32,
+};
+
+/// A global editor grid, drawn around `origin` on the XZ plane, with optional levels above it for a 3D grid. The grid
+/// gives the positions and rotations other tools (handles, gizmos, ...) may snap to with [`EditorGrid::snap`] and
+/// [`EditorGrid::snap_rotation`].
+///
+/// Only the lines closer to the head than `cull_distance` are drawn, and only their part inside that distance.
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{maths::{Quat, Vec3}, tools::grid::EditorGrid};
+///
+/// let grid = EditorGrid::new(1.0, 3.0);
+/// assert_eq!(grid.snap([0.4, 1.6, -2.51]), Vec3::new(0.0, 2.0, -3.0));
+/// assert_eq!(grid.snap([-0.6, 0.0, 0.49]), Vec3::new(-1.0, 0.0, 0.0));
+///
+/// let mut fine = EditorGrid::new(0.25, 1.0);
+/// fine.origin = Vec3::new(0.1, 0.0, 0.0);
+/// assert!(Vec3::distance(fine.snap([0.3, 0.1, 0.2]), Vec3::new(0.35, 0.0, 0.25)) < 0.0001);
+///
+/// let snapped = EditorGrid::snap_rotation(Quat::from_angles(0.0, 50.0, 0.0), 45.0);
+/// let expected = Quat::from_angles(0.0, 45.0, 0.0).rotate_point(Vec3::FORWARD);
+/// assert!(Vec3::distance(snapped.rotate_point(Vec3::FORWARD), expected) < 0.0001);
+///
+/// // 7 lines on each axis, all of them inside the cull distance.
+/// assert_eq!(grid.visible_lines(Vec3::ZERO).len(), 14);
+/// // Far away, nothing is drawn.
+/// assert!(grid.visible_lines([100.0, 0.0, 0.0]).is_empty());
+///
+/// let mut editor_grid = EditorGrid::new(1.0, 3.0);
+/// editor_grid.origin = Vec3::new(0.0, -1.5, 0.0);
+/// sk.push_action(StepperAction::add("EditorGrid", editor_grid));
+///
+/// filename_scr = "screenshots/editor_grid.jpeg";
+/// from_scr = Vec3::new(0.0, 1.0, 2.0);
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+/// );
+/// ```
+/// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/editor_grid.jpeg" alt="screenshot" width="200">
+#[derive(IStepper)]
+pub struct EditorGrid {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    pub enabled: bool,
+
+    /// The center of the grid, it's also an intersection of the grid.
+    pub origin: Vec3,
+    /// Distance between two grid lines, in meters.
+    pub spacing: f32,
+    /// Half size of the grid on the X and Z axis, in meters.
+    pub extent: f32,
+    /// Number of grid planes drawn above the ground one, every `spacing` meters. 0 draws only the ground.
+    pub levels: u32,
+    pub color: Color32,
+    /// Thickness of the lines in meters.
+    pub thickness: f32,
+    /// The lines, or part of lines, farther than this distance from the head are not drawn, in meters.
+    pub cull_distance: f32,
+}
+
+unsafe impl Send for EditorGrid {}
+
+impl Default for EditorGrid {
+    fn default() -> Self {
+        Self {
+            id: "EditorGrid".to_string(),
+            sk_info: None,
+            enabled: true,
+
+            origin: Vec3::ZERO,
+            spacing: 0.1,
+            extent: 1.0,
+            levels: 0,
+            color: Color32::new(128, 128, 128, 160),
+            thickness: 0.002,
+            cull_distance: 5.0,
+        }
+    }
+}
+
+impl EditorGrid {
+    /// Create a ground grid centered on the origin.
+    /// * spacing - Distance between two grid lines, in meters.
+    /// * extent - Half size of the grid on the X and Z axis, in meters.
+    pub fn new(spacing: f32, extent: f32) -> Self {
+        Self { spacing, extent, ..Default::default() }
+    }
+
+    /// Get the grid intersection nearest to a position. The grid goes on in all directions, beyond its extent and its
+    /// levels. A null or negative spacing leaves the position unchanged.
+    /// * position - The position to snap, in world space.
+    pub fn snap(&self, position: impl Into<Vec3>) -> Vec3 {
+        let position = position.into();
+        if self.spacing <= 0.0 {
+            return position;
+        }
+        let snap = |value: f32, origin: f32| ((value - origin) / self.spacing).round() * self.spacing + origin;
+        Vec3::new(snap(position.x, self.origin.x), snap(position.y, self.origin.y), snap(position.z, self.origin.z))
+    }
+
+    /// Get a rotation with its yaw, pitch and roll angles snapped by steps of `degrees`, like [`UiHandleSnap`] does.
+    /// * rotation - The rotation to snap.
+    /// * degrees - The angle step. A null or negative step leaves the rotation unchanged.
+    pub fn snap_rotation(rotation: Quat, degrees: f32) -> Quat {
+        let mut snap = UiHandleSnap::default();
+        snap.rotation(degrees);
+        snap.apply(Pose::new(Vec3::ZERO, Some(rotation))).orientation
+    }
+
+    /// The lines of the grid to draw for a viewer, each one clipped to the sphere of `cull_distance` around the viewer.
+    /// * viewer - The position of the viewer, usually the head.
+    pub fn visible_lines(&self, viewer: impl Into<Vec3>) -> Vec<(Vec3, Vec3)> {
+        let viewer = viewer.into() - self.origin;
+        let mut lines = vec![];
+        if self.spacing <= 0.0 || self.extent < 0.0 {
+            return lines;
+        }
+        let count = (self.extent / self.spacing + 0.0001).floor() as i32;
+        for level in 0..=self.levels {
+            let y = level as f32 * self.spacing;
+            for i in -count..=count {
+                let across = i as f32 * self.spacing;
+                // The lines along X at z = across, then the ones along Z at x = across.
+                for (along_x, viewer_along, viewer_across) in [(true, viewer.x, viewer.z), (false, viewer.z, viewer.x)]
+                {
+                    let away_sq = (viewer_across - across).powi(2) + (viewer.y - y).powi(2);
+                    let chord_sq = self.cull_distance * self.cull_distance - away_sq;
+                    if chord_sq <= 0.0 {
+                        continue;
+                    }
+                    let chord = chord_sq.sqrt();
+                    let (start, end) =
+                        ((viewer_along - chord).max(-self.extent), (viewer_along + chord).min(self.extent));
+                    if start >= end {
+                        continue;
+                    }
+                    let (start, end) = if along_x {
+                        (Vec3::new(start, y, across), Vec3::new(end, y, across))
+                    } else {
+                        (Vec3::new(across, y, start), Vec3::new(across, y, end))
+                    };
+                    lines.push((self.origin + start, self.origin + end));
+                }
+            }
+        }
+        lines
+    }
+
+    /// Called from IStepper::initialize here you can abort the initialization by returning false
+    fn start(&mut self) -> bool {
+        true
+    }
+
+    /// Called from IStepper::step, here you can check the event report
+    fn check_event(&mut self, _id: &StepperId, _key: &str, _value: &str) {}
+
+    /// Called from IStepper::step, after check_event here you can draw your UI and scene
+    fn draw(&mut self, token: &MainThreadToken) {
+        for (start, end) in self.visible_lines(Input::get_head().position) {
+            Lines::add(token, start, end, self.color, None, self.thickness);
+        }
+    }
+}
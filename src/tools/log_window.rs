@@ -4,6 +4,7 @@ use crate::{
     maths::{units::CM, Matrix, Pose, Vec2, Vec3},
     prelude::*,
     system::{LogLevel, Text, TextAlign, TextFit, TextStyle},
+    tools::os_api::set_clipboard_text,
     ui::{Ui, UiCut},
     util::Color128,
 };
@@ -18,6 +19,42 @@ pub struct LogItem {
 
 pub const SHOW_LOG_WINDOW: &str = "Tool_ShowLogWindow";
 
+/// The default maximum number of lines kept in the log backlog.
+pub const DEFAULT_MAX_BACKLOG: usize = 5000;
+
+/// A window showing the log lines pushed in `log_log`. The lines can be filtered by text and by severity, without
+/// changing the backlog, and the visible lines can be copied to the clipboard.
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{system::LogLevel, tools::log_window::{LogItem, LogWindow}};
+/// use std::sync::Mutex;
+///
+/// static LOG_LOG: Mutex<Vec<LogItem>> = Mutex::new(vec![]);
+/// for (level, text) in [
+///     (LogLevel::Diagnostic, "diag 1"),
+///     (LogLevel::Inform, "info 1"),
+///     (LogLevel::Warning, "warn 1"),
+///     (LogLevel::Error, "error 1"),
+///     (LogLevel::Diagnostic, "diag 2"),
+///     (LogLevel::Inform, "info 2"),
+/// ] {
+///     LOG_LOG.lock().unwrap().push(LogItem { level, text: text.into(), count: 1 });
+/// }
+///
+/// let mut log_window = LogWindow::new(&LOG_LOG);
+/// assert_eq!(log_window.get_visible_items().len(), 6);
+///
+/// log_window.show_diagnostic = false;
+/// assert_eq!(log_window.get_visible_items().len(), 4);
+///
+/// log_window.filter = "1".into();
+/// assert_eq!(log_window.get_visible_items().len(), 3);
+///
+/// // The backlog is not changed by the filter.
+/// assert_eq!(LOG_LOG.lock().unwrap().len(), 6);
+/// ```
 #[derive(IStepper)]
 pub struct LogWindow<'a> {
     id: StepperId,
@@ -32,6 +69,14 @@ pub struct LogWindow<'a> {
     style_warn: TextStyle,
     style_err: TextStyle,
     pub log_log: &'a Mutex<Vec<LogItem>>,
+    /// Only the lines containing this text (case insensitive) are shown.
+    pub filter: String,
+    pub show_diagnostic: bool,
+    pub show_inform: bool,
+    pub show_warning: bool,
+    pub show_error: bool,
+    /// The oldest lines of the backlog are removed beyond this count.
+    pub max_backlog: usize,
     log_index: f32,
     items_size: usize,
 }
@@ -65,11 +110,34 @@ impl<'a> LogWindow<'a> {
             style_warn,
             style_err,
             log_log,
+            filter: String::new(),
+            show_diagnostic: true,
+            show_inform: true,
+            show_warning: true,
+            show_error: true,
+            max_backlog: DEFAULT_MAX_BACKLOG,
             log_index: 0.0,
             items_size: 0,
         }
     }
 
+    /// Is this log line shown with the current text filter and severity toggles.
+    pub fn is_visible(&self, item: &LogItem) -> bool {
+        let level_shown = match item.level {
+            LogLevel::Diagnostic => self.show_diagnostic,
+            LogLevel::Inform => self.show_inform,
+            LogLevel::Warning => self.show_warning,
+            LogLevel::Error => self.show_error,
+            _ => true,
+        };
+        level_shown && (self.filter.is_empty() || item.text.to_lowercase().contains(&self.filter.to_lowercase()))
+    }
+
+    /// The log lines shown with the current text filter and severity toggles.
+    pub fn get_visible_items(&self) -> Vec<LogItem> {
+        self.log_log.lock().unwrap().iter().filter(|item| self.is_visible(item)).cloned().collect()
+    }
+
     /// Called from IStepper::initialize here you can abort the initialization by returning false
     fn start(&mut self) -> bool {
         true
@@ -77,26 +145,57 @@ impl<'a> LogWindow<'a> {
 
     /// Called from IStepper::step, here you can check the event report
     fn check_event(&mut self, _id: &StepperId, key: &str, value: &str) {
-        if key.eq(SHOW_LOG_WINDOW) {
-            self.enabled = value.parse().unwrap_or(false)
+        if let Some(ToolEvent::ShowLogWindow(shown)) = ToolEvent::from_key_value(key, value) {
+            self.enabled = shown
         }
     }
     /// Called from IStepper::step, after check_event here you can draw your UI
     fn draw(&mut self, token: &MainThreadToken) {
         Ui::window_begin("Log", &mut self.pose, Some(Vec2::new(self.x_len, 0.0) * CM), None, None);
+        self.draw_filters();
         self.draw_logs(token);
         Ui::hseparator();
         Ui::window_end();
     }
 
+    fn draw_filters(&mut self) {
+        Ui::label("Filter", None, true);
+        Ui::same_line();
+        if let Some(filter) = Ui::input("log_filter", &self.filter, Some(Vec2::new(0.3, 0.0)), None) {
+            self.filter = filter;
+        }
+        for (label, shown) in [
+            ("Diag", &mut self.show_diagnostic),
+            ("Info", &mut self.show_inform),
+            ("Warn", &mut self.show_warning),
+            ("Error", &mut self.show_error),
+        ] {
+            Ui::same_line();
+            if let Some(value) = Ui::toggle(label, *shown, None) {
+                *shown = value;
+            }
+        }
+        Ui::same_line();
+        if Ui::button("Copy visible", None) {
+            let text: Vec<String> = self.get_visible_items().into_iter().map(|item| item.text).collect();
+            set_clipboard_text(text.join("\n"));
+        }
+    }
+
     fn draw_logs(&mut self, token: &MainThreadToken) {
         let text_size = Vec2::new(Ui::get_layout_remaining().x, 0.024);
-        let items = self.log_log.lock().unwrap();
+        let log_log = self.log_log;
+        let mut backlog = log_log.lock().unwrap();
+        if backlog.len() > self.max_backlog {
+            let excess = backlog.len() - self.max_backlog;
+            backlog.drain(..excess);
+        }
+        let items: Vec<&LogItem> = backlog.iter().filter(|item| self.is_visible(item)).collect();
 
         Ui::layout_push_cut(UiCut::Top, text_size.y * self.y_len, false);
         Ui::layout_push_cut(UiCut::Right, Ui::get_line_height() * 0.6, false);
 
-        if self.items_size < items.len() {
+        if self.items_size != items.len() {
             self.items_size = items.len();
             self.log_index = items.len() as f32;
 
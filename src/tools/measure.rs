@@ -0,0 +1,192 @@
+use crate::{
+    font::Font,
+    maths::{Matrix, Quat, Vec3},
+    prelude::*,
+    system::{BtnState, Handed, Input, Lines, Text, TextAlign, TextStyle},
+    util::{
+        named_colors::{WHITE, YELLOW},
+        Color32,
+    },
+};
+
+pub const MEASURE_CLEAR_ALL: &str = "Tool_MeasureClearAll";
+
+/// Height of the distance label above the middle of its line, in meters.
+const LABEL_OFFSET: f32 = 0.02;
+
+/// Measure the distance between two points placed with a pinch. Each pinch places a point, the second point of a pair
+/// finalizes the measurement and a new one can begin. All the measurements are drawn with a label facing the user,
+/// until they are cleared with [`MeasureTool::clear_all`] or a [`ToolEvent::MeasureClearAll`] event.
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{maths::Vec3, system::BtnState, tools::measure::MeasureTool};
+///
+/// let mut measure = MeasureTool::default();
+/// measure.initialize("MeasureTool".into(), sk.get_sk_info_clone());
+///
+/// let (a, b) = (Vec3::new(-0.1, 0.0, -0.5), Vec3::new(0.2, 0.4, -0.5));
+/// let mut reported = None;
+/// filename_scr = "screenshots/measure_tool.jpeg";
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     // The pinches of a hand, as the tool reads them from Input::hand at each step.
+///     match iter {
+///         0 => assert_eq!(measure.pinch_input(BtnState::JustActive, a), None),
+///         1 => reported = measure.pinch_input(BtnState::JustActive, b),
+///         _ => assert_eq!(measure.pinch_input(BtnState::Active, b), None),
+///     }
+///     measure.step(token);
+/// );
+/// assert!((reported.expect("the second pinch should finalize") - 0.5).abs() < 0.0001);
+/// assert_eq!(measure.get_measurements(), &[(a, b)]);
+///
+/// measure.clear_all();
+/// assert!(measure.get_measurements().is_empty());
+/// ```
+/// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/measure_tool.jpeg" alt="screenshot" width="200">
+#[derive(IStepper)]
+pub struct MeasureTool {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    pub enabled: bool,
+
+    /// The hand placing the points, None for both hands.
+    pub handed: Option<Handed>,
+    pub line_color: Color32,
+    /// Thickness of the lines in meters.
+    pub thickness: f32,
+    pub text_style: TextStyle,
+    measurements: Vec<(Vec3, Vec3)>,
+    pending: Option<Vec3>,
+}
+
+unsafe impl Send for MeasureTool {}
+
+impl Default for MeasureTool {
+    fn default() -> Self {
+        Self {
+            id: "MeasureTool".to_string(),
+            sk_info: None,
+            enabled: true,
+
+            handed: None,
+            line_color: YELLOW,
+            thickness: 0.003,
+            text_style: Text::make_style(Font::default(), 0.02, WHITE),
+            measurements: vec![],
+            pending: None,
+        }
+    }
+}
+
+impl MeasureTool {
+    /// The finalized measurements, as pairs of points.
+    pub fn get_measurements(&self) -> &[(Vec3, Vec3)] {
+        &self.measurements
+    }
+
+    /// The first point of the measurement in progress, if any.
+    pub fn get_pending(&self) -> Option<Vec3> {
+        self.pending
+    }
+
+    /// Remove all the measurements, and the one in progress.
+    pub fn clear_all(&mut self) {
+        self.measurements.clear();
+        self.pending = None;
+    }
+
+    /// Give a pinch state to the tool. A pinch just activated places a point at `at`. This is called at each step with
+    /// the pinch of the hands, you may call it with your own input.
+    /// * pinch - The state of the pinch.
+    /// * at - Where the pinch occurs, in world space.
+    ///
+    /// Returns the distance in meters if this pinch finalized a measurement.
+    pub fn pinch_input(&mut self, pinch: BtnState, at: impl Into<Vec3>) -> Option<f32> {
+        if !pinch.is_just_active() {
+            return None;
+        }
+        let at = at.into();
+        match self.pending.take() {
+            None => {
+                self.pending = Some(at);
+                None
+            }
+            Some(start) => {
+                self.measurements.push((start, at));
+                Some(Vec3::distance(start, at))
+            }
+        }
+    }
+
+    /// Format a distance with a unit suited to its size: millimeters under one centimeter, centimeters under one meter
+    /// and meters above.
+    /// * meters - The distance to format.
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::tools::measure::MeasureTool;
+    ///
+    /// assert_eq!(MeasureTool::format_distance(0.0042), "4.2 mm");
+    /// assert_eq!(MeasureTool::format_distance(0.256), "25.6 cm");
+    /// assert_eq!(MeasureTool::format_distance(1.5), "1.50 m");
+    /// ```
+    pub fn format_distance(meters: f32) -> String {
+        if meters < 0.01 {
+            format!("{:.1} mm", meters * 1000.0)
+        } else if meters < 1.0 {
+            format!("{:.1} cm", meters * 100.0)
+        } else {
+            format!("{meters:.2} m")
+        }
+    }
+
+    /// Called from IStepper::initialize here you can abort the initialization by returning false
+    fn start(&mut self) -> bool {
+        true
+    }
+
+    /// Called from IStepper::step, here you can check the event report
+    fn check_event(&mut self, _id: &StepperId, key: &str, value: &str) {
+        if let Some(ToolEvent::MeasureClearAll) = ToolEvent::from_key_value(key, value) {
+            self.clear_all();
+        }
+    }
+
+    /// Called from IStepper::step, after check_event here you can draw your UI and scene
+    fn draw(&mut self, token: &MainThreadToken) {
+        for handed in [Handed::Left, Handed::Right] {
+            if self.handed.is_some_and(|only| only != handed) {
+                continue;
+            }
+            let hand = Input::hand(handed);
+            if !hand.tracked.is_active() {
+                continue;
+            }
+            if let Some(distance) = self.pinch_input(hand.pinch, hand.pinch_pt) {
+                Log::diag(format!("MeasureTool: {}", Self::format_distance(distance)));
+            }
+            if let Some(pending) = self.pending {
+                Lines::add(token, pending, hand.pinch_pt, self.line_color, None, self.thickness);
+            }
+        }
+
+        let head = Input::get_head().position;
+        for (start, end) in self.measurements.iter() {
+            Lines::add(token, *start, *end, self.line_color, None, self.thickness);
+            let label_at = (*start + *end) / 2.0 + Vec3::UP * LABEL_OFFSET;
+            Text::add_at(
+                token,
+                Self::format_distance(Vec3::distance(*start, *end)),
+                Matrix::tr(&label_at, &Quat::look_at(label_at, head, None)),
+                Some(self.text_style),
+                None,
+                Some(TextAlign::BottomCenter),
+                None,
+                None,
+                None,
+                None,
+            );
+        }
+    }
+}
@@ -1,5 +1,9 @@
 pub mod build_tools;
 pub mod os_api;
+pub mod xr_comp_layers;
+
+#[cfg(feature = "event-loop")]
+pub mod console;
 
 #[cfg(feature = "event-loop")]
 pub mod file_browser;
@@ -7,9 +11,18 @@ pub mod file_browser;
 #[cfg(feature = "event-loop")]
 pub mod fly_over;
 
+#[cfg(feature = "event-loop")]
+pub mod follow;
+
+#[cfg(feature = "event-loop")]
+pub mod grid;
+
 #[cfg(feature = "event-loop")]
 pub mod log_window;
 
+#[cfg(feature = "event-loop")]
+pub mod measure;
+
 #[cfg(feature = "event-loop")]
 pub mod notif;
 
@@ -19,5 +32,11 @@ pub mod passthrough_fb_ext;
 #[cfg(feature = "event-loop")]
 pub mod screenshot;
 
+#[cfg(feature = "event-loop")]
+pub mod shader_watch;
+
+#[cfg(feature = "event-loop")]
+pub mod title;
+
 #[cfg(feature = "event-loop")]
 pub mod virtual_kbd_meta;
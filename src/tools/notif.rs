@@ -1,23 +1,76 @@
+use std::collections::VecDeque;
+
 use crate::{
     font::Font,
     maths::{Matrix, Quat, Vec3},
     prelude::*,
     shader::Shader,
-    system::{Text, TextStyle},
+    sprite::Sprite,
+    system::{Text, TextAlign, TextStyle},
     util::{named_colors::BLACK, Time},
 };
 
-/// A simple notification to display a text for a given duration in second.
+/// The StepperAction to trigger with a text as value to push a new notification with the default duration.
+pub const HUD_NOTIFICATION_PUSH: &str = "HudNotificationPush";
+
+/// The speed the notifications slide to their place.
+const SLIDE_SPEED: f32 = 10.0;
+
+/// One of the notifications in the queue of a [`HudNotification`].
+pub struct Notification {
+    pub text: String,
+    /// Remaining seconds before this notification expires.
+    pub lifetime: f32,
+    pub icon: Option<Sprite>,
+    offset_y: f32,
+}
+
+/// A simple notification to display a text for a given duration in second. New notifications pushed with
+/// [`HudNotification::push`] or with the event [`HUD_NOTIFICATION_PUSH`] stack beneath the older ones, which slide up.
+/// The stepper removes itself once all the notifications have expired.
+/// ### Examples
+/// ```
+/// // 60 steps per second, whatever the real frame rate.
+/// stereokit_rust::test_init_sk!(1.0 / 60.0); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::tools::notif::HudNotification;
+///
+/// let mut notif = HudNotification::default();
+/// notif.text = String::new();
+/// notif.max_visible = 2;
+/// notif.push("First", 0.01, None);
+/// notif.push("Second", 0.01, None);
+/// notif.push("Third", 0.02, None);
+/// // The oldest one is dropped right away.
+/// assert_eq!(notif.get_count(), 2);
+/// assert_eq!(notif.get_texts(), vec!["Second", "Third"]);
+///
+/// notif.initialize("HudNotification".into(), sk.get_sk_info_clone());
+/// let mut max_drawn = 0;
+/// // 10 steps of 1/60s are longer than the 0.02s of the last notification.
+/// number_of_steps = 10;
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     notif.step(token);
+///     max_drawn = max_drawn.max(notif.get_count());
+/// );
+/// assert_eq!(max_drawn, 2);
+/// assert_eq!(notif.get_count(), 0);
+/// ```
 #[derive(IStepper)]
 pub struct HudNotification {
     id: StepperId,
     sk_info: Option<Rc<RefCell<SkInfo>>>,
 
+    /// If not empty at start, this text is the first notification of the queue.
     pub text: String,
+    /// The default lifetime of a notification.
     pub duration: f32,
     pub position: Vec3,
+    /// The maximum number of notifications displayed together. Beyond, the oldest are dropped.
+    pub max_visible: usize,
     transform_text: Matrix,
     pub text_style: TextStyle,
+    queue: VecDeque<Notification>,
 }
 
 unsafe impl Send for HudNotification {}
@@ -37,28 +90,93 @@ impl Default for HudNotification {
             text,
             duration: 5.0,
             position,
+            max_visible: 3,
             transform_text,
             text_style,
+            queue: VecDeque::new(),
         }
     }
 }
 
 impl HudNotification {
+    /// Add a notification at the bottom of the queue. If there are more than `max_visible` notifications, the oldest
+    /// are dropped.
+    /// * text - The text to display.
+    /// * lifetime - The seconds this notification stays.
+    /// * icon - An optional sprite displayed before the text.
+    pub fn push(&mut self, text: impl AsRef<str>, lifetime: f32, icon: Option<Sprite>) {
+        let offset_y = self.queue.back().map_or(0.0, |last| last.offset_y - self.get_line_height());
+        self.queue.push_back(Notification { text: text.as_ref().to_string(), lifetime, icon, offset_y });
+        while self.queue.len() > self.max_visible.max(1) {
+            self.queue.pop_front();
+        }
+    }
+
+    /// The number of notifications currently displayed.
+    pub fn get_count(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// The texts of the notifications currently displayed, from the oldest to the newest.
+    pub fn get_texts(&self) -> Vec<&str> {
+        self.queue.iter().map(|notification| notification.text.as_str()).collect()
+    }
+
+    fn get_line_height(&self) -> f32 {
+        self.text_style.get_layout_height() * 1.5
+    }
+
     /// Called from IStepper::initialize here you can abort the initialization by returning false
     fn start(&mut self) -> bool {
         self.transform_text = Matrix::tr(&self.position, &Quat::from_angles(0.0, 180.0, 0.0));
+        if !self.text.is_empty() {
+            let text = std::mem::take(&mut self.text);
+            self.queue.push_front(Notification { text, lifetime: self.duration, icon: None, offset_y: 0.0 });
+        }
         true
     }
 
     /// Called from IStepper::step, here you can check the event report
-    fn check_event(&mut self, _id: &StepperId, _key: &str, _value: &str) {}
+    fn check_event(&mut self, _id: &StepperId, key: &str, value: &str) {
+        if let Some(ToolEvent::HudNotificationPush(text)) = ToolEvent::from_key_value(key, value) {
+            self.push(text, self.duration, None);
+        }
+    }
 
     /// Called from IStepper::step after check_event, here you can draw your UI and scene
     fn draw(&mut self, token: &MainThreadToken) {
-        Text::add_at(token, &self.text, self.transform_text, Some(self.text_style), None, None, None, None, None, None);
+        let step = Time::get_stepf();
+        let line_height = self.get_line_height();
+        let slide = (step * SLIDE_SPEED).min(1.0);
+        let count = self.queue.len();
+        for (index, notification) in self.queue.iter_mut().enumerate() {
+            let target_y = (count - 1 - index) as f32 * line_height;
+            notification.offset_y += (target_y - notification.offset_y) * slide;
+            let transform = Matrix::t(Vec3::new(0.0, notification.offset_y, 0.0)) * self.transform_text;
+            Text::add_at(
+                token,
+                &notification.text,
+                transform,
+                Some(self.text_style),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+            if let Some(icon) = &notification.icon {
+                let text_width = Text::size_layout(&notification.text, Some(self.text_style), None).x;
+                let icon_size = self.text_style.get_layout_height() * 1.2;
+                let icon_transform =
+                    Matrix::ts(Vec3::new(text_width / 2.0 + icon_size, 0.0, 0.0), Vec3::ONE * icon_size) * transform;
+                icon.draw(token, icon_transform, TextAlign::Center, None);
+            }
+            notification.lifetime -= step;
+        }
 
-        self.duration -= Time::get_stepf();
-        if self.duration < 0.0 {
+        self.queue.retain(|notification| notification.lifetime >= 0.0);
+        if self.queue.is_empty() {
             SkInfo::send_message(&self.sk_info, StepperAction::Remove(self.id.clone()));
         }
     }
@@ -369,12 +369,95 @@ pub fn show_soft_input(_show: bool) -> bool {
     false
 }
 
+/// Copy a text to the Android clipboard.
+#[cfg(target_os = "android")]
+pub fn set_clipboard_text(text: impl AsRef<str>) -> bool {
+    use jni::objects::JValue;
+
+    let ctx = ndk_context::android_context();
+    let vm = match unsafe { jni::JavaVM::from_raw(ctx.vm() as _) } {
+        Ok(value) => value,
+        Err(e) => {
+            Log::err(format!("clipboard : no vm !! : {:?}", e));
+            return false;
+        }
+    };
+    let activity = unsafe { jni::objects::JObject::from_raw(ctx.context() as _) };
+    let mut env = match vm.attach_current_thread() {
+        Ok(value) => value,
+        Err(e) => {
+            Log::err(format!("clipboard : no env !! : {:?}", e));
+            return false;
+        }
+    };
+
+    let result = (|| -> jni::errors::Result<()> {
+        let service_name = env.new_string("clipboard")?;
+        let clipboard = env
+            .call_method(
+                &activity,
+                "getSystemService",
+                "(Ljava/lang/String;)Ljava/lang/Object;",
+                &[JValue::Object(&service_name)],
+            )?
+            .l()?;
+        let label = env.new_string("StereoKit")?;
+        let content = env.new_string(text.as_ref())?;
+        let clip = env
+            .call_static_method(
+                "android/content/ClipData",
+                "newPlainText",
+                "(Ljava/lang/CharSequence;Ljava/lang/CharSequence;)Landroid/content/ClipData;",
+                &[JValue::Object(&label), JValue::Object(&content)],
+            )?
+            .l()?;
+        env.call_method(clipboard, "setPrimaryClip", "(Landroid/content/ClipData;)V", &[JValue::Object(&clip)])?;
+        Ok(())
+    })();
+    match result {
+        Ok(()) => true,
+        Err(e) => {
+            Log::err(format!("clipboard : jni error !! : {:?}", e));
+            false
+        }
+    }
+}
+
+/// Copy a text to the clipboard using the command line tool of the platform (clip, pbcopy, wl-copy or xclip).
+#[cfg(not(target_os = "android"))]
+pub fn set_clipboard_text(text: impl AsRef<str>) -> bool {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    let candidates: &[(&str, &[&str])] = if cfg!(target_os = "windows") {
+        &[("clip", &[])]
+    } else if cfg!(target_os = "macos") {
+        &[("pbcopy", &[])]
+    } else {
+        &[("wl-copy", &[]), ("xclip", &["-selection", "clipboard"]), ("xsel", &["--clipboard", "--input"])]
+    };
+    for (program, args) in candidates {
+        let Ok(mut child) = Command::new(program).args(*args).stdin(Stdio::piped()).spawn() else {
+            continue;
+        };
+        let written = match child.stdin.take() {
+            Some(mut stdin) => stdin.write_all(text.as_ref().as_bytes()).is_ok(),
+            None => false,
+        };
+        if child.wait().map(|status| status.success()).unwrap_or(false) && written {
+            return true;
+        }
+    }
+    Log::warn("clipboard : no clipboard tool found");
+    false
+}
+
 pub const USUAL_FPS_SUSPECTS: [i32; 12] = [30, 60, 72, 80, 90, 100, 110, 120, 144, 165, 240, 360];
 
 /// Return and maybe Log all the display refresh rates available.
 pub fn get_all_display_refresh_rates(with_log: bool) -> Vec<f32> {
     let mut array = [0.0; 40];
-    let mut count = 5u32;
+    let mut count = 0u32;
     if BackendOpenXR::ext_enabled("XR_FB_display_refresh_rate") {
         if let Some(rate_display) =
             BackendOpenXR::get_function::<EnumerateDisplayRefreshRatesFB>("xrEnumerateDisplayRefreshRatesFB")
@@ -401,11 +484,13 @@ pub fn get_all_display_refresh_rates(with_log: bool) -> Vec<f32> {
                             }
                         }
                         otherwise => {
+                            count = 0;
                             Log::err(format!("xrEnumerateDisplayRefreshRatesFB failed: {otherwise}"));
                         }
                     }
                 }
                 otherwise => {
+                    count = 0;
                     Log::err(format!("xrEnumerateDisplayRefreshRatesFB failed: {otherwise}"));
                 }
             }
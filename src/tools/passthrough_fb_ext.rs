@@ -1,24 +1,37 @@
 use openxr_sys::{
     pfn::{
-        CreatePassthroughFB, CreatePassthroughLayerFB, DestroyPassthroughFB, DestroyPassthroughLayerFB,
-        PassthroughLayerPauseFB, PassthroughLayerResumeFB, PassthroughLayerSetStyleFB, PassthroughPauseFB,
-        PassthroughStartFB,
+        CreatePassthroughColorLutMETA, CreatePassthroughFB, CreatePassthroughLayerFB, DestroyPassthroughColorLutMETA,
+        DestroyPassthroughFB, DestroyPassthroughLayerFB, PassthroughLayerPauseFB, PassthroughLayerResumeFB,
+        PassthroughLayerSetStyleFB, PassthroughPauseFB, PassthroughStartFB,
     },
-    CompositionLayerFlags, CompositionLayerPassthroughFB, PassthroughCreateInfoFB, PassthroughFB, PassthroughFlagsFB,
-    PassthroughLayerCreateInfoFB, PassthroughLayerFB, PassthroughLayerPurposeFB, Result, Session, Space, StructureType,
+    Color4f, CompositionLayerFlags, CompositionLayerPassthroughFB, PassthroughBrightnessContrastSaturationFB,
+    PassthroughColorLutChannelsMETA, PassthroughColorLutCreateInfoMETA, PassthroughColorLutDataMETA,
+    PassthroughColorLutMETA, PassthroughColorMapLutMETA, PassthroughCreateInfoFB, PassthroughFB, PassthroughFlagsFB,
+    PassthroughLayerCreateInfoFB, PassthroughLayerFB, PassthroughLayerPurposeFB, PassthroughStyleFB, Result, Session,
+    Space, StructureType,
 };
 use stereokit_macros::IStepper;
 
 use crate::{
     prelude::*,
     system::{Backend, BackendOpenXR, BackendXRType, Renderer},
-    util::Color128,
+    tex::Tex,
+    util::{Color128, Color32},
+};
+use std::{
+    ffi::c_void,
+    ptr::{null, null_mut},
 };
-use std::ptr::null_mut;
 
 /// The StepperAction to trigger with the value "0"/"1" to Deactivate/Activate the passthrough.
 pub const PASSTHROUGH_FLIP: &str = "PassthroughFlip";
 
+/// The range of the brightness given to [`PassthroughFbExt::set_style`]. 0.0 doesn't change the brightness.
+pub const PASSTHROUGH_BRIGHTNESS_RANGE: (f32, f32) = (-100.0, 100.0);
+
+/// The maximum contrast or saturation given to [`PassthroughFbExt::set_style`]. 1.0 doesn't change them.
+pub const PASSTHROUGH_MAX_CONTRAST_SATURATION: f32 = 10.0;
+
 ///
 ///
 ///  This is a rust copycat of https://github.com/StereoKit/StereoKit/blob/master/Examples/StereoKitTest/Tools/PassthroughFBExt.cs
@@ -92,6 +105,9 @@ pub struct PassthroughFbExt {
     xr_passthrough_layer_pause_fb: Option<PassthroughLayerPauseFB>,
     xr_passthrough_layer_resume_fb: Option<PassthroughLayerResumeFB>,
     xr_passthrough_layer_set_style_fb: Option<PassthroughLayerSetStyleFB>,
+    active_color_lut: PassthroughColorLutMETA,
+    xr_create_passthrough_color_lut_meta: Option<CreatePassthroughColorLutMETA>,
+    xr_destroy_passthrough_color_lut_meta: Option<DestroyPassthroughColorLutMETA>,
 }
 
 unsafe impl Send for PassthroughFbExt {}
@@ -129,6 +145,13 @@ impl Default for PassthroughFbExt {
             xr_passthrough_layer_set_style_fb: BackendOpenXR::get_function::<PassthroughLayerSetStyleFB>(
                 "xrPassthroughLayerSetStyleFB",
             ),
+            active_color_lut: PassthroughColorLutMETA::from_raw(0),
+            xr_create_passthrough_color_lut_meta: BackendOpenXR::get_function::<CreatePassthroughColorLutMETA>(
+                "xrCreatePassthroughColorLutMETA",
+            ),
+            xr_destroy_passthrough_color_lut_meta: BackendOpenXR::get_function::<DestroyPassthroughColorLutMETA>(
+                "xrDestroyPassthroughColorLutMETA",
+            ),
         }
     }
 }
@@ -152,12 +175,8 @@ impl PassthroughFbExt {
     /// Called from IStepper::step, here you can check the event report
     fn check_event(&mut self, _id: &StepperId, key: &str, value: &str) {
         // Here we enable/disable the passthrough
-        if key.eq(PASSTHROUGH_FLIP) {
-            if value == "0" {
-                self.enable(false)
-            } else {
-                self.enable(true)
-            }
+        if let Some(ToolEvent::PassthroughFlip(active)) = ToolEvent::from_key_value(key, value) {
+            self.enable(active)
         }
     }
     /// Called from IStepper::step after check_event, here you can draw your UI and scene
@@ -185,6 +204,138 @@ impl PassthroughFbExt {
         }
     }
 
+    /// Change the brightness, contrast and saturation of the passthrough. Out of range values are clamped. This does
+    /// nothing if the passthrough is not enabled.
+    /// * brightness - From -100.0 to 100.0, 0.0 keeps the brightness of the cameras.
+    /// * contrast - From 0.0 to 10.0, 1.0 keeps the contrast of the cameras.
+    /// * saturation - From 0.0 to 10.0, 1.0 keeps the colors of the cameras, 0.0 gives a grayscale image.
+    ///
+    /// Returns true if the style has been applied.
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{tex::Tex, tools::passthrough_fb_ext::{PassthroughFbExt, PASSTHROUGH_FLIP},
+    ///                      util::Color32};
+    ///
+    /// let mut passthrough = PassthroughFbExt::new(true);
+    /// // Offscreen mode has no passthrough, so these calls do nothing.
+    /// assert!(!passthrough.set_style(20.0, 1.5, 200.0));
+    /// let lut = Tex::from_color32(&[Color32::WHITE; 2 * 2 * 2], 2 * 2, 2, false).unwrap();
+    /// assert!(!passthrough.set_color_lut(&lut));
+    ///
+    /// sk.push_action(StepperAction::add("PassthroughFbExt", passthrough));
+    /// sk.push_action(StepperAction::event("main".into(), PASSTHROUGH_FLIP, "1"));
+    ///
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    /// );
+    /// ```
+    pub fn set_style(&mut self, brightness: f32, contrast: f32, saturation: f32) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let (min_brightness, max_brightness) = PASSTHROUGH_BRIGHTNESS_RANGE;
+        let color_adjustment = PassthroughBrightnessContrastSaturationFB {
+            ty: PassthroughBrightnessContrastSaturationFB::TYPE,
+            next: null(),
+            brightness: brightness.clamp(min_brightness, max_brightness),
+            contrast: contrast.clamp(0.0, PASSTHROUGH_MAX_CONTRAST_SATURATION),
+            saturation: saturation.clamp(0.0, PASSTHROUGH_MAX_CONTRAST_SATURATION),
+        };
+        self.apply_style(&color_adjustment as *const _ as *const c_void, "brightness/contrast/saturation")
+    }
+
+    /// Map the colors of the passthrough through a 3D color look up table. This needs the OpenXR extension
+    /// XR_META_passthrough_color_lut to be requested with [`BackendOpenXR::request_ext`] before initialization. This
+    /// does nothing if the passthrough is not enabled.
+    /// * tex - A RGBA32 texture of height `n` and width `n * n` (with `n` a power of two), made of `n` squares side by
+    ///   side. The blue input selects the square, the red input is the horizontal position and the green input the
+    ///   vertical position inside the square.
+    ///
+    /// Returns true if the look up table has been applied.
+    /// see also [`PassthroughFbExt::set_style`]
+    pub fn set_color_lut(&mut self, tex: &Tex) -> bool {
+        if !self.enabled || !BackendOpenXR::ext_enabled("XR_META_passthrough_color_lut") {
+            return false;
+        }
+        let (Some(create_lut), Some(destroy_lut)) =
+            (self.xr_create_passthrough_color_lut_meta, self.xr_destroy_passthrough_color_lut_meta)
+        else {
+            return false;
+        };
+        let (Some(width), Some(height)) = (tex.get_width(), tex.get_height()) else {
+            return false;
+        };
+        let resolution = height;
+        if !resolution.is_power_of_two() || width != resolution * resolution {
+            Log::warn(format!(
+                "PassthroughFbExt::set_color_lut: the size of {} must be n*n x n with n a power of two, not {}x{}",
+                tex.get_id(),
+                width,
+                height
+            ));
+            return false;
+        }
+        let pixels = vec![Color32::default(); width * height];
+        if !tex.get_color_data::<Color32>(&pixels, 0) {
+            return false;
+        }
+        // From n squares side by side to the red, then green, then blue order of the look up table.
+        let mut data = Vec::with_capacity(pixels.len() * 4);
+        for blue in 0..resolution {
+            for green in 0..resolution {
+                for red in 0..resolution {
+                    let pixel = pixels[green * width + blue * resolution + red];
+                    data.extend_from_slice(&[pixel.r, pixel.g, pixel.b, pixel.a]);
+                }
+            }
+        }
+
+        let mut color_lut = PassthroughColorLutMETA::from_raw(0);
+        let create_info = PassthroughColorLutCreateInfoMETA {
+            ty: PassthroughColorLutCreateInfoMETA::TYPE,
+            next: null(),
+            channels: PassthroughColorLutChannelsMETA::RGBA,
+            resolution: resolution as u32,
+            data: PassthroughColorLutDataMETA { buffer_size: data.len() as u32, buffer: data.as_ptr() },
+        };
+        match unsafe { create_lut(self.active_passtrough, &create_info, &mut color_lut) } {
+            Result::SUCCESS => {}
+            otherwise => {
+                Log::err(format!("xrCreatePassthroughColorLutMETA failed: {otherwise}"));
+                return false;
+            }
+        }
+        let color_map =
+            PassthroughColorMapLutMETA { ty: PassthroughColorMapLutMETA::TYPE, next: null(), color_lut, weight: 1.0 };
+        let applied = self.apply_style(&color_map as *const _ as *const c_void, "color look up table");
+        // The previous table is no longer used by the layer.
+        let previous = std::mem::replace(&mut self.active_color_lut, color_lut);
+        if previous != PassthroughColorLutMETA::from_raw(0) {
+            unsafe { destroy_lut(previous) };
+        }
+        applied
+    }
+
+    fn apply_style(&mut self, color_map: *const c_void, what: &str) -> bool {
+        let Some(set_style) = self.xr_passthrough_layer_set_style_fb else {
+            return false;
+        };
+        let style = PassthroughStyleFB {
+            ty: PassthroughStyleFB::TYPE,
+            next: color_map,
+            texture_opacity_factor: 1.0,
+            edge_color: Color4f { r: 0.0, g: 0.0, b: 0.0, a: 0.0 },
+        };
+        match unsafe { set_style(self.active_layer, &style) } {
+            Result::SUCCESS => true,
+            otherwise => {
+                Log::err(format!("xrPassthroughLayerSetStyleFB failed to set the {what}: {otherwise}"));
+                false
+            }
+        }
+    }
+
     fn init_passthrough(&mut self) -> bool {
         let flags = if self.enable_on_init {
             PassthroughFlagsFB::IS_RUNNING_AT_CREATION
@@ -300,6 +451,12 @@ impl PassthroughFbExt {
             if self.enabled {
                 self.enable(false);
                 if self.ext_available {
+                    if let (Some(destroy_lut), false) = (
+                        self.xr_destroy_passthrough_color_lut_meta,
+                        self.active_color_lut == PassthroughColorLutMETA::from_raw(0),
+                    ) {
+                        unsafe { destroy_lut(self.active_color_lut) };
+                    }
                     unsafe { self.xr_destroy_passthrough_layer_fb.unwrap()(self.active_layer) };
                     unsafe { self.xr_destroy_passthrough_fb.unwrap()(self.active_passtrough) };
                 }
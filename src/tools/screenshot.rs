@@ -43,6 +43,8 @@ pub struct ScreenshotViewer {
     pub field_of_view: f32,
     pub pose: Pose,
     pub window_size: Vec2,
+    /// Capture with a transparent background instead of the sky and clear color.
+    pub transparent: bool,
     tex: Tex,
     screen: Option<Sprite>,
 }
@@ -64,6 +66,7 @@ impl Default for ScreenshotViewer {
             field_of_view: 90.0,
             pose: Pose::new(Vec3::new(-0.7, 1.0, -0.3), Some(Quat::look_dir(Vec3::new(1.0, 0.0, 1.0)))),
             window_size: Vec2::new(42.0, 37.0) * CM,
+            transparent: false,
             tex,
             screen: None,
         }
@@ -94,8 +97,8 @@ impl ScreenshotViewer {
 
     /// Called from IStepper::step, here you can check the event report
     fn check_event(&mut self, id: &StepperId, key: &str, value: &str) {
-        if key.eq(SHOW_SCREENSHOT_WINDOW) {
-            self.enabled = value.parse().unwrap_or(false);
+        if let Some(ToolEvent::ShowScreenshotWindow(shown)) = ToolEvent::from_key_value(key, value) {
+            self.enabled = shown;
             if !self.enabled {
                 self.close_file_browser()
             }
@@ -206,26 +209,42 @@ impl ScreenshotViewer {
             let width_i = self.picture_size.x as i32;
             let height_i = self.picture_size.y as i32;
 
-            Renderer::screenshot_capture(
-                token,
-                move |dots, width, height| {
-                    Log::info(format!("data length {} -> size {}/{}", dots.len(), width, height));
-                    let tex = Tex::find(CAPTURE_TEXTURE_ID).ok();
-                    match tex {
-                        Some(mut tex) => tex.set_colors32(width, height, dots),
-                        None => todo!(),
-                    };
-                },
-                camera_at,
-                width_i,
-                height_i,
-                Some(self.field_of_view),
-                Some(TexFormat::RGBA32),
-            );
+            if self.transparent {
+                match Renderer::capture_transparent(token, camera_at, width_i, height_i, Some(self.field_of_view)) {
+                    Ok(dots) => match Tex::find(CAPTURE_TEXTURE_ID) {
+                        Ok(mut tex) => {
+                            tex.set_colors32(width_i as usize, height_i as usize, &dots);
+                        }
+                        Err(err) => Log::warn(format!("Screenshoot Error unable to get texture : {:?}", err)),
+                    },
+                    Err(err) => Log::warn(format!("Screenshoot Error when capturing : {:?}", err)),
+                }
+            } else {
+                Renderer::screenshot_capture(
+                    token,
+                    move |dots, width, height| {
+                        Log::info(format!("data length {} -> size {}/{}", dots.len(), width, height));
+                        let tex = Tex::find(CAPTURE_TEXTURE_ID).ok();
+                        match tex {
+                            Some(mut tex) => tex.set_colors32(width, height, dots),
+                            None => todo!(),
+                        };
+                    },
+                    camera_at,
+                    width_i,
+                    height_i,
+                    Some(self.field_of_view),
+                    Some(TexFormat::RGBA32),
+                );
+            }
 
             self.screen = Sprite::from_tex(&self.tex, None, None).ok();
         }
         Ui::same_line();
+        if let Some(value) = Ui::toggle("Transparent", self.transparent, None) {
+            self.transparent = value;
+        }
+        Ui::same_line();
         if Ui::button("Save", None) && !Platform::get_file_picker_visible() {
             if cfg!(target_os = "android") {
                 if let Some(img_dir) = get_external_path(&self.sk_info) {
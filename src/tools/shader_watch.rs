@@ -0,0 +1,139 @@
+use std::{fs, path::PathBuf, time::SystemTime};
+
+use crate::{material::Material, prelude::*, shader::Shader, util::Time, StereoKitError};
+
+pub const SHADER_WATCH_RELOADED: &str = "Tool_ShaderWatchReloaded";
+pub const SHADER_WATCH_FAILED: &str = "Tool_ShaderWatchFailed";
+
+/// Watch a .hlsl or .sks file and swap the shader of a material each time the file is modified. Changes are detected
+/// from the modification time of the file, polled every `poll_interval` seconds.
+///
+/// After each reload, an event is sent with the key [`SHADER_WATCH_RELOADED`] and the path of the file as value, or
+/// with the key [`SHADER_WATCH_FAILED`] and the compiler diagnostics as value. A failed reload keeps the last good
+/// shader on the material.
+///
+/// .hlsl files are compiled with [`Shader::from_hlsl`] so skshaderc must be available.
+///
+/// # Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{material::Material, tools::shader_watch::*};
+///
+/// let path = std::env::temp_dir().join("sk_shader_watch_test.hlsl");
+/// std::fs::write(&path, "// not yet a shader").unwrap();
+///
+/// let material = Material::unlit().copy();
+/// let mut watcher = ShaderWatcher::new(&path, material.clone_ref());
+/// watcher.poll_interval = 0.0;
+/// sk.push_action(StepperAction::add("ShaderWatcher", watcher));
+///
+/// let mut reloaded = false;
+/// number_of_steps = 10;
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     if iter == 3 {
+///         std::fs::write(&path, "// still not a shader").unwrap();
+///     }
+///     for event in token.get_event_report() {
+///         if let StepperAction::Event(_, key, _) = event {
+///             if key == SHADER_WATCH_FAILED {
+///                 reloaded = true;
+///             }
+///         }
+///     }
+/// );
+/// assert!(reloaded);
+/// // The failed compilation kept the last good shader.
+/// assert_eq!(material.get_shader().get_id(), Material::unlit().get_shader().get_id());
+/// ```
+#[derive(IStepper)]
+pub struct ShaderWatcher {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+    pub enabled: bool,
+
+    pub path: PathBuf,
+    pub material: Material,
+    pub poll_interval: f32,
+    last_modified: Option<SystemTime>,
+    since_last_poll: f32,
+}
+
+unsafe impl Send for ShaderWatcher {}
+
+impl ShaderWatcher {
+    /// Watch the file at `path` and set the compiled shader to `material` each time the file changes.
+    pub fn new(path: impl Into<PathBuf>, material: Material) -> Self {
+        Self {
+            id: "ShaderWatcher".to_string(),
+            sk_info: None,
+            enabled: true,
+
+            path: path.into(),
+            material,
+            poll_interval: 1.0,
+            last_modified: None,
+            since_last_poll: 0.0,
+        }
+    }
+
+    /// Called from IStepper::initialize here you can abort the initialization by returning false
+    fn start(&mut self) -> bool {
+        self.last_modified = self.get_modified();
+        true
+    }
+
+    /// Called from IStepper::step, here you can check the event report
+    fn check_event(&mut self, _id: &StepperId, _key: &str, _value: &str) {}
+
+    /// Called from IStepper::step after check_event, here you can draw your UI and scene
+    fn draw(&mut self, _token: &MainThreadToken) {
+        self.since_last_poll += Time::get_step_unscaledf();
+        if self.since_last_poll < self.poll_interval {
+            return;
+        }
+        self.since_last_poll = 0.0;
+
+        let modified = self.get_modified();
+        if modified.is_none() || modified == self.last_modified {
+            return;
+        }
+        self.last_modified = modified;
+
+        match self.reload() {
+            Ok(shader) => {
+                self.material.shader(&shader);
+                Log::info(format!("ShaderWatcher: {:?} reloaded", self.path));
+                let value = self.path.to_string_lossy().to_string();
+                SkInfo::send_message(
+                    &self.sk_info,
+                    StepperAction::event(self.id.clone(), SHADER_WATCH_RELOADED, value.as_str()),
+                );
+            }
+            Err(err) => {
+                Log::warn(format!("ShaderWatcher: {:?} failed to reload: {}", self.path, err));
+                let value = err.to_string();
+                SkInfo::send_message(
+                    &self.sk_info,
+                    StepperAction::event(self.id.clone(), SHADER_WATCH_FAILED, value.as_str()),
+                );
+            }
+        }
+    }
+
+    fn get_modified(&self) -> Option<SystemTime> {
+        fs::metadata(&self.path).and_then(|metadata| metadata.modified()).ok()
+    }
+
+    fn reload(&self) -> Result<Shader, StereoKitError> {
+        let is_sks = self.path.extension().is_some_and(|ext| ext == "sks");
+        if is_sks {
+            let data = fs::read(&self.path).map_err(|err| StereoKitError::ReadFileError(err.to_string()))?;
+            Shader::from_memory(&data)
+        } else {
+            let source =
+                fs::read_to_string(&self.path).map_err(|err| StereoKitError::ReadFileError(err.to_string()))?;
+            Shader::from_hlsl(source, None)
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    font::Font,
+    maths::{Matrix, Quat, Vec3},
+    prelude::*,
+    system::{Text, TextAlign, TextStyle},
+    util::{named_colors::WHITE, Color128, Time},
+};
+
+/// The size of the subtitle compared to the size of the title.
+pub const SUBTITLE_SCALE: f32 = 0.5;
+
+/// A title with an optional subtitle beneath it. When `duration` is not 0.0, the title stays `duration` seconds then
+/// fades out during `fade_time` seconds before removing itself.
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{maths::Vec3, tools::title::Title};
+///
+/// let mut title = Title::new("StereoKit", "Rust edition");
+/// title.position = Vec3::new(0.0, 0.0, -0.5);
+/// sk.push_action(StepperAction::add("Title", title));
+///
+/// filename_scr = "screenshots/title.jpeg";
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+/// );
+/// ```
+/// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/title.jpeg" alt="screenshot" width="200">
+#[derive(IStepper)]
+pub struct Title {
+    id: StepperId,
+    sk_info: Option<Rc<RefCell<SkInfo>>>,
+
+    pub text: String,
+    pub subtitle: String,
+    pub position: Vec3,
+    pub text_style: TextStyle,
+    /// Seconds before the fade out. 0.0 means the title stays until removed.
+    pub duration: f32,
+    /// Seconds of the fade out.
+    pub fade_time: f32,
+    elapsed: f32,
+    transform_text: Matrix,
+    subtitle_style: TextStyle,
+}
+
+unsafe impl Send for Title {}
+
+impl Default for Title {
+    fn default() -> Self {
+        let text_style = Text::make_style(Font::default(), 0.05, WHITE);
+
+        Self {
+            id: "Title".to_string(),
+            sk_info: None,
+
+            text: "Title".into(),
+            subtitle: String::new(),
+            position: Vec3::new(0.0, 0.1, -0.8),
+            text_style,
+            duration: 0.0,
+            fade_time: 1.0,
+            elapsed: 0.0,
+            transform_text: Matrix::IDENTITY,
+            subtitle_style: text_style,
+        }
+    }
+}
+
+impl Title {
+    /// Create a title with a subtitle, staying until removed.
+    /// * text - The text of the title.
+    /// * subtitle - The text shown smaller beneath the title. May be empty.
+    pub fn new(text: impl AsRef<str>, subtitle: impl AsRef<str>) -> Self {
+        Self { text: text.as_ref().to_string(), subtitle: subtitle.as_ref().to_string(), ..Default::default() }
+    }
+
+    /// The alpha of the title after `elapsed` seconds: 1.0 until `duration`, then decreasing to 0.0 during
+    /// `fade_time`. Always 1.0 if `duration` is 0.0.
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::tools::title::Title;
+    ///
+    /// let mut title = Title::new("Fading", "away");
+    /// title.duration = 2.0;
+    /// title.fade_time = 1.0;
+    /// assert_eq!(title.get_alpha_at(1.0), 1.0);
+    /// assert_eq!(title.get_alpha_at(2.0), 1.0);
+    /// assert!(title.get_alpha_at(2.5) < 1.0);
+    /// assert!(title.get_alpha_at(2.8) < title.get_alpha_at(2.5));
+    /// assert_eq!(title.get_alpha_at(3.5), 0.0);
+    ///
+    /// title.duration = 0.0;
+    /// assert_eq!(title.get_alpha_at(100.0), 1.0);
+    /// ```
+    pub fn get_alpha_at(&self, elapsed: f32) -> f32 {
+        if self.duration <= 0.0 || elapsed <= self.duration {
+            1.0
+        } else if self.fade_time <= 0.0 {
+            0.0
+        } else {
+            (1.0 - (elapsed - self.duration) / self.fade_time).clamp(0.0, 1.0)
+        }
+    }
+
+    /// The current alpha of the title.
+    pub fn get_alpha(&self) -> f32 {
+        self.get_alpha_at(self.elapsed)
+    }
+
+    /// Called from IStepper::initialize here you can abort the initialization by returning false
+    fn start(&mut self) -> bool {
+        self.transform_text = Matrix::tr(&self.position, &Quat::from_angles(0.0, 180.0, 0.0));
+        self.subtitle_style = Text::make_style_with_material(
+            Font::default(),
+            self.text_style.get_layout_height() * SUBTITLE_SCALE,
+            self.text_style.get_material(),
+            WHITE,
+        );
+        self.elapsed = 0.0;
+        true
+    }
+
+    /// Called from IStepper::step, here you can check the event report
+    fn check_event(&mut self, _id: &StepperId, _key: &str, _value: &str) {}
+
+    /// Called from IStepper::step after check_event, here you can draw your UI and scene
+    fn draw(&mut self, token: &MainThreadToken) {
+        let alpha = self.get_alpha();
+        let tint = Color128::new(1.0, 1.0, 1.0, alpha);
+        let align = if self.subtitle.is_empty() { TextAlign::Center } else { TextAlign::BottomCenter };
+        Text::add_at(
+            token,
+            &self.text,
+            self.transform_text,
+            Some(self.text_style),
+            Some(tint),
+            Some(align),
+            None,
+            None,
+            None,
+            None,
+        );
+        if !self.subtitle.is_empty() {
+            let gap = self.subtitle_style.get_layout_height() * 0.5;
+            Text::add_at(
+                token,
+                &self.subtitle,
+                self.transform_text,
+                Some(self.subtitle_style),
+                Some(tint),
+                Some(TextAlign::TopCenter),
+                None,
+                None,
+                Some(-gap),
+                None,
+            );
+        }
+
+        self.elapsed += Time::get_stepf();
+        if self.duration > 0.0 && self.elapsed > self.duration + self.fade_time {
+            SkInfo::send_message(&self.sk_info, StepperAction::Remove(self.id.clone()));
+        }
+    }
+}
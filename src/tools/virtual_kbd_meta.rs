@@ -111,14 +111,8 @@ impl IStepper for VirtualKbdMETA {
     fn step(&mut self, token: &MainThreadToken) {
         // Here with enable/disable the passthrough
         for e in token.get_event_report().iter() {
-            if let StepperAction::Event(_, key, value) = e {
-                if key.eq(KEYBOARD_SHOW) {
-                    if value == "0" {
-                        self.enable(false)
-                    } else {
-                        self.enable(true)
-                    }
-                }
+            if let Some(ToolEvent::KeyboardShow(shown)) = ToolEvent::from_action(e) {
+                self.enable(shown)
             }
         }
         if self.enabled() {
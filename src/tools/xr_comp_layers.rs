@@ -0,0 +1,404 @@
+use openxr_sys::{
+    pfn::{
+        AcquireSwapchainImage, CreateSwapchain, DestroySwapchain, EnumerateSwapchainFormats, EnumerateSwapchainImages,
+        ReleaseSwapchainImage, WaitSwapchainImage,
+    },
+    CompositionLayerCylinderKHR, CompositionLayerEquirect2KHR, CompositionLayerFlags, Duration, Extent2Di,
+    EyeVisibility, Offset2Di, Posef, Quaternionf, Rect2Di, Result, Session, Space, StructureType, Swapchain,
+    SwapchainCreateFlags, SwapchainCreateInfo, SwapchainImageAcquireInfo, SwapchainImageBaseHeader,
+    SwapchainImageReleaseInfo, SwapchainImageWaitInfo, SwapchainSubImage, SwapchainUsageFlags, Vector3f,
+};
+
+use crate::{
+    material::Material,
+    maths::Pose,
+    prelude::*,
+    shader::Shader,
+    system::{Backend, BackendGraphics, BackendOpenXR, BackendXRType, Renderer},
+    tex::{Tex, TexFormat, TexType},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::c_void,
+    ptr::null_mut,
+};
+
+/// The OpenXR extension needed by [`XrCompLayers::add_equirect_layer`].
+pub const XR_EQUIRECT2_EXT: &str = "XR_KHR_composition_layer_equirect2";
+
+/// The OpenXR extension needed by [`XrCompLayers::add_cylinder_layer`].
+pub const XR_CYLINDER_EXT: &str = "XR_KHR_composition_layer_cylinder";
+
+/// The native swapchain formats we'd like to copy an sRGB texture to, by order of preference.
+const GL_FORMATS: [i64; 2] = [0x8C43 /* GL_SRGB8_ALPHA8 */, 0x8058 /* GL_RGBA8 */];
+const D3D11_FORMATS: [i64; 3] = [
+    29, // DXGI_FORMAT_R8G8B8A8_UNORM_SRGB
+    91, // DXGI_FORMAT_B8G8R8A8_UNORM_SRGB
+    28, // DXGI_FORMAT_R8G8B8A8_UNORM
+];
+
+/// The image of a swapchain for all the graphics backends (XrSwapchainImageOpenGLKHR, XrSwapchainImageOpenGLESKHR and
+/// XrSwapchainImageD3D11KHR). The image is a GL texture name or an ID3D11Texture2D pointer.
+#[repr(C)]
+struct SwapchainImage {
+    ty: StructureType,
+    next: *mut c_void,
+    image: u64,
+}
+
+/// A static swapchain the texture of a layer has been copied to.
+struct LayerSwapchain {
+    swapchain: Swapchain,
+    width: i32,
+    height: i32,
+}
+
+/// Composition layers submitted to the OpenXR compositor instead of being drawn by StereoKit. The compositor samples
+/// the texture of the layer at the display resolution, this is the sharpest way to show a high resolution 360 photo
+/// or video frame. The extensions must be requested with [`BackendOpenXR::request_ext`] before initialization.
+///
+/// The texture of a layer is copied once, at the first call where it is loaded, to a static OpenXR swapchain. The
+/// layers are submitted for the frame they are added in, so call the add functions at each step. On runtimes without
+/// the extension, or outside of OpenXR, the add functions log a warning once and return false.
+/// ### Examples
+/// ```
+/// // The folowing lines must be added before initializing sk:
+/// stereokit_rust::system::BackendOpenXR::request_ext("XR_KHR_composition_layer_equirect2");
+/// stereokit_rust::system::BackendOpenXR::request_ext("XR_KHR_composition_layer_cylinder");
+/// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::{maths::{Pose, Vec3}, tex::{Tex, TexFormat, TexType}, tools::xr_comp_layers::XrCompLayers,
+///                      util::named_colors};
+///
+/// let photo = Tex::gen_color(named_colors::LIGHT_SKY_BLUE, 64, 32, TexType::Image, TexFormat::RGBA32);
+/// let mut layers = XrCompLayers::default();
+///
+/// number_of_steps = 3;
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     // Offscreen mode has no compositor, so the layers are skipped.
+///     assert!(!layers.add_equirect_layer(token, &photo, Pose::IDENTITY, 0.0, -2));
+///     let panel = Pose::new(Vec3::new(0.0, 1.5, 0.0), None);
+///     assert!(!layers.add_cylinder_layer(token, &photo, panel, 1.0, 1.5, 2.0, 1));
+/// );
+/// ```
+pub struct XrCompLayers {
+    xr_enumerate_swapchain_formats: Option<EnumerateSwapchainFormats>,
+    xr_create_swapchain: Option<CreateSwapchain>,
+    xr_destroy_swapchain: Option<DestroySwapchain>,
+    xr_enumerate_swapchain_images: Option<EnumerateSwapchainImages>,
+    xr_acquire_swapchain_image: Option<AcquireSwapchainImage>,
+    xr_wait_swapchain_image: Option<WaitSwapchainImage>,
+    xr_release_swapchain_image: Option<ReleaseSwapchainImage>,
+    swapchains: HashMap<String, LayerSwapchain>,
+    warned: HashSet<String>,
+}
+
+impl Default for XrCompLayers {
+    fn default() -> Self {
+        Self {
+            xr_enumerate_swapchain_formats: BackendOpenXR::get_function::<EnumerateSwapchainFormats>(
+                "xrEnumerateSwapchainFormats",
+            ),
+            xr_create_swapchain: BackendOpenXR::get_function::<CreateSwapchain>("xrCreateSwapchain"),
+            xr_destroy_swapchain: BackendOpenXR::get_function::<DestroySwapchain>("xrDestroySwapchain"),
+            xr_enumerate_swapchain_images: BackendOpenXR::get_function::<EnumerateSwapchainImages>(
+                "xrEnumerateSwapchainImages",
+            ),
+            xr_acquire_swapchain_image: BackendOpenXR::get_function::<AcquireSwapchainImage>("xrAcquireSwapchainImage"),
+            xr_wait_swapchain_image: BackendOpenXR::get_function::<WaitSwapchainImage>("xrWaitSwapchainImage"),
+            xr_release_swapchain_image: BackendOpenXR::get_function::<ReleaseSwapchainImage>("xrReleaseSwapchainImage"),
+            swapchains: HashMap::new(),
+            warned: HashSet::new(),
+        }
+    }
+}
+
+/// All the code here run in the main thread
+impl XrCompLayers {
+    /// Submit a texture mapped on the inside of a sphere for this frame, with the extension
+    /// XR_KHR_composition_layer_equirect2. Use a negative sort order to get a background behind the StereoKit scene.
+    /// * token - The main thread token.
+    /// * tex - An equirectangular image, 360° wide and 180° high.
+    /// * pose - The center and the orientation of the sphere, in the reference space of StereoKit.
+    /// * radius - The radius of the sphere in meters. 0.0 is an infinite sphere, like a skybox.
+    /// * sort_order - The order of the layer relative to StereoKit's own layer, at 0.
+    ///
+    /// Returns true if the layer has been submitted.
+    ///
+    /// see also [`XrCompLayers::add_cylinder_layer`]
+    pub fn add_equirect_layer(
+        &mut self,
+        token: &MainThreadToken,
+        tex: &Tex,
+        pose: Pose,
+        radius: f32,
+        sort_order: i32,
+    ) -> bool {
+        let Some(sub_image) = self.sub_image(token, tex, XR_EQUIRECT2_EXT) else {
+            return false;
+        };
+        let mut layer = CompositionLayerEquirect2KHR {
+            ty: StructureType::COMPOSITION_LAYER_EQUIRECT2_KHR,
+            next: null_mut(),
+            layer_flags: CompositionLayerFlags::BLEND_TEXTURE_SOURCE_ALPHA,
+            space: Space::from_raw(BackendOpenXR::space()),
+            eye_visibility: EyeVisibility::BOTH,
+            sub_image,
+            pose: to_posef(pose),
+            radius: radius.max(0.0),
+            central_horizontal_angle: std::f32::consts::TAU,
+            upper_vertical_angle: std::f32::consts::FRAC_PI_2,
+            lower_vertical_angle: -std::f32::consts::FRAC_PI_2,
+        };
+        BackendOpenXR::add_composition_layer(&mut layer, sort_order);
+        true
+    }
+
+    /// Submit a texture mapped on the inside of a portion of cylinder for this frame, with the extension
+    /// XR_KHR_composition_layer_cylinder. The cylinder is vertical and centered on the viewer side of the texture.
+    /// * token - The main thread token.
+    /// * tex - The image to show.
+    /// * pose - The center and the orientation of the cylinder, in the reference space of StereoKit.
+    /// * radius - The radius of the cylinder in meters.
+    /// * central_angle - The angle of the visible portion of the cylinder, in radians, from 0 to 2π.
+    /// * aspect_ratio - The width of the visible portion divided by its height.
+    /// * sort_order - The order of the layer relative to StereoKit's own layer, at 0.
+    ///
+    /// Returns true if the layer has been submitted.
+    ///
+    /// see also [`XrCompLayers::add_equirect_layer`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_cylinder_layer(
+        &mut self,
+        token: &MainThreadToken,
+        tex: &Tex,
+        pose: Pose,
+        radius: f32,
+        central_angle: f32,
+        aspect_ratio: f32,
+        sort_order: i32,
+    ) -> bool {
+        let Some(sub_image) = self.sub_image(token, tex, XR_CYLINDER_EXT) else {
+            return false;
+        };
+        let mut layer = CompositionLayerCylinderKHR {
+            ty: StructureType::COMPOSITION_LAYER_CYLINDER_KHR,
+            next: null_mut(),
+            layer_flags: CompositionLayerFlags::BLEND_TEXTURE_SOURCE_ALPHA,
+            space: Space::from_raw(BackendOpenXR::space()),
+            eye_visibility: EyeVisibility::BOTH,
+            sub_image,
+            pose: to_posef(pose),
+            radius: radius.max(0.0),
+            central_angle: central_angle.clamp(0.0, std::f32::consts::TAU),
+            aspect_ratio: aspect_ratio.max(f32::EPSILON),
+        };
+        BackendOpenXR::add_composition_layer(&mut layer, sort_order);
+        true
+    }
+
+    /// Forget the swapchain a texture has been copied to, the next layer with this texture will copy it again. Call
+    /// this when the content of the texture has changed.
+    /// * tex - The texture of the layer.
+    pub fn release(&mut self, tex: &Tex) {
+        if let Some(layer_swapchain) = self.swapchains.remove(tex.get_id()) {
+            self.destroy_swapchain(layer_swapchain.swapchain);
+        }
+    }
+
+    /// The swapchain image of the texture, once the extension is known to be available. None if the layer must be
+    /// skipped.
+    fn sub_image(&mut self, token: &MainThreadToken, tex: &Tex, extension: &str) -> Option<SwapchainSubImage> {
+        if Backend::xr_type() != BackendXRType::OpenXR
+            || !BackendOpenXR::ext_enabled(extension)
+            || BackendOpenXR::session() == 0
+            || !self.load_binding()
+        {
+            self.warn_once(extension, format!("XrCompLayers: {extension} is not available, the layer is skipped"));
+            return None;
+        }
+        let id = tex.get_id().to_string();
+        if !self.swapchains.contains_key(&id) {
+            if self.warned.contains(&id) {
+                return None;
+            }
+            let (Some(width), Some(height)) = (tex.get_width(), tex.get_height()) else {
+                // The texture is not loaded yet.
+                return None;
+            };
+            match self.create_swapchain(token, tex, width as i32, height as i32) {
+                Some(layer_swapchain) => {
+                    self.swapchains.insert(id.clone(), layer_swapchain);
+                }
+                None => {
+                    self.warn_once(&id, format!("XrCompLayers: {id} can't be copied to a swapchain, it is skipped"));
+                    return None;
+                }
+            }
+        }
+        let layer_swapchain = self.swapchains.get(&id)?;
+        Some(SwapchainSubImage {
+            swapchain: layer_swapchain.swapchain,
+            image_rect: Rect2Di {
+                offset: Offset2Di { x: 0, y: 0 },
+                extent: Extent2Di { width: layer_swapchain.width, height: layer_swapchain.height },
+            },
+            image_array_index: 0,
+        })
+    }
+
+    /// Create a static swapchain and copy the texture to its single image.
+    fn create_swapchain(&self, token: &MainThreadToken, tex: &Tex, width: i32, height: i32) -> Option<LayerSwapchain> {
+        let (image_type, preferred_formats): (StructureType, &[i64]) = match Backend::graphics() {
+            BackendGraphics::D3D11 => (StructureType::SWAPCHAIN_IMAGE_D3D11_KHR, &D3D11_FORMATS),
+            BackendGraphics::OpenGLGLX | BackendGraphics::OpenGLWGL => {
+                (StructureType::SWAPCHAIN_IMAGE_OPENGL_KHR, &GL_FORMATS)
+            }
+            BackendGraphics::OpenGLESEGL => (StructureType::SWAPCHAIN_IMAGE_OPENGL_ES_KHR, &GL_FORMATS),
+            _ => return None,
+        };
+        let session = Session::from_raw(BackendOpenXR::session());
+
+        let enumerate_formats = self.xr_enumerate_swapchain_formats?;
+        let mut count = 0;
+        if unsafe { enumerate_formats(session, 0, &mut count, null_mut()) } != Result::SUCCESS {
+            return None;
+        }
+        let mut formats = vec![0i64; count as usize];
+        if unsafe { enumerate_formats(session, count, &mut count, formats.as_mut_ptr()) } != Result::SUCCESS {
+            return None;
+        }
+        let format = preferred_formats.iter().find(|format| formats.contains(format)).or(formats.first()).copied()?;
+
+        let create_info = SwapchainCreateInfo {
+            ty: StructureType::SWAPCHAIN_CREATE_INFO,
+            next: null_mut(),
+            create_flags: SwapchainCreateFlags::STATIC_IMAGE,
+            usage_flags: SwapchainUsageFlags::COLOR_ATTACHMENT | SwapchainUsageFlags::SAMPLED,
+            format,
+            sample_count: 1,
+            width: width as u32,
+            height: height as u32,
+            face_count: 1,
+            array_size: 1,
+            mip_count: 1,
+        };
+        let mut swapchain = Swapchain::from_raw(0);
+        match unsafe { self.xr_create_swapchain?(session, &create_info, &mut swapchain) } {
+            Result::SUCCESS => {}
+            otherwise => {
+                Log::err(format!("xrCreateSwapchain failed: {otherwise}"));
+                return None;
+            }
+        }
+        if self.copy_to_swapchain(token, tex, swapchain, image_type, format, width, height) {
+            Some(LayerSwapchain { swapchain, width, height })
+        } else {
+            self.destroy_swapchain(swapchain);
+            None
+        }
+    }
+
+    /// Blit the texture to the image of a static swapchain.
+    #[allow(clippy::too_many_arguments)]
+    fn copy_to_swapchain(
+        &self,
+        token: &MainThreadToken,
+        tex: &Tex,
+        swapchain: Swapchain,
+        image_type: StructureType,
+        format: i64,
+        width: i32,
+        height: i32,
+    ) -> bool {
+        let (Some(enumerate_images), Some(acquire), Some(wait), Some(release)) = (
+            self.xr_enumerate_swapchain_images,
+            self.xr_acquire_swapchain_image,
+            self.xr_wait_swapchain_image,
+            self.xr_release_swapchain_image,
+        ) else {
+            return false;
+        };
+        let mut count = 0;
+        if unsafe { enumerate_images(swapchain, 0, &mut count, null_mut()) } != Result::SUCCESS || count == 0 {
+            return false;
+        }
+        let mut images: Vec<SwapchainImage> =
+            (0..count).map(|_| SwapchainImage { ty: image_type, next: null_mut(), image: 0 }).collect();
+        if unsafe {
+            enumerate_images(swapchain, count, &mut count, images.as_mut_ptr() as *mut SwapchainImageBaseHeader)
+        } != Result::SUCCESS
+        {
+            return false;
+        }
+
+        let mut index = 0;
+        let acquire_info =
+            SwapchainImageAcquireInfo { ty: StructureType::SWAPCHAIN_IMAGE_ACQUIRE_INFO, next: null_mut() };
+        match unsafe { acquire(swapchain, &acquire_info, &mut index) } {
+            Result::SUCCESS => {}
+            otherwise => {
+                Log::err(format!("xrAcquireSwapchainImage failed: {otherwise}"));
+                return false;
+            }
+        }
+        let wait_info = SwapchainImageWaitInfo {
+            ty: StructureType::SWAPCHAIN_IMAGE_WAIT_INFO,
+            next: null_mut(),
+            timeout: Duration::INFINITE,
+        };
+        let waited = unsafe { wait(swapchain, &wait_info) } == Result::SUCCESS;
+        if waited {
+            let mut target =
+                Tex::new(TexType::Rendertarget, TexFormat::RGBA32, format!("{}/xr_comp_layer", tex.get_id()));
+            let image = images[index as usize].image as usize as *mut c_void;
+            target.set_native_surface(image, TexType::Rendertarget, format, width, height, 1, false);
+            let material = Material::new(Shader::blit(), None);
+            material.get_all_param_info().set_texture("source", tex);
+            Renderer::blit(token, &target, &material);
+        }
+        let release_info =
+            SwapchainImageReleaseInfo { ty: StructureType::SWAPCHAIN_IMAGE_RELEASE_INFO, next: null_mut() };
+        unsafe { release(swapchain, &release_info) };
+        waited
+    }
+
+    fn destroy_swapchain(&self, swapchain: Swapchain) {
+        if let Some(destroy_swapchain) = self.xr_destroy_swapchain {
+            unsafe { destroy_swapchain(swapchain) };
+        }
+    }
+
+    fn warn_once(&mut self, key: &str, message: String) {
+        if self.warned.insert(key.to_string()) {
+            Log::warn(message);
+        }
+    }
+
+    /// Check if all the binded functions are ready.
+    fn load_binding(&self) -> bool {
+        self.xr_enumerate_swapchain_formats.is_some()
+            && self.xr_create_swapchain.is_some()
+            && self.xr_destroy_swapchain.is_some()
+            && self.xr_enumerate_swapchain_images.is_some()
+            && self.xr_acquire_swapchain_image.is_some()
+            && self.xr_wait_swapchain_image.is_some()
+            && self.xr_release_swapchain_image.is_some()
+    }
+}
+
+impl Drop for XrCompLayers {
+    fn drop(&mut self) {
+        for (_, layer_swapchain) in std::mem::take(&mut self.swapchains) {
+            self.destroy_swapchain(layer_swapchain.swapchain);
+        }
+    }
+}
+
+fn to_posef(pose: Pose) -> Posef {
+    let (orientation, position) = (pose.orientation, pose.position);
+    Posef {
+        orientation: Quaternionf { x: orientation.x, y: orientation.y, z: orientation.z, w: orientation.w },
+        position: Vector3f { x: position.x, y: position.y, z: position.z },
+    }
+}
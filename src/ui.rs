@@ -1,16 +1,24 @@
 use crate::{
-    material::{Material, MaterialT},
-    maths::{Bool32T, Bounds, Pose, Vec2, Vec3},
-    mesh::{Mesh, MeshT, Vertex},
+    material::{Material, MaterialT, Transparency},
+    maths::{Bool32T, Bounds, Matrix, Pose, Quat, Vec2, Vec3, Vec4},
+    mesh::{mesh_draw, Mesh, MeshT, Vertex},
     model::{Model, ModelT},
     sound::{Sound, SoundT},
     sprite::{Sprite, SpriteT},
-    system::{BtnState, Handed, HierarchyParent, Log, TextAlign, TextContext, TextFit, TextStyle},
-    util::{Color128, Color32},
+    system::{
+        hierarchy_to_local_point, hierarchy_to_local_ray, BtnState, Handed, HierarchyParent, Input, Key, Log,
+        RenderLayer, Text, TextAlign, TextContext, TextFit, TextStyle,
+    },
+    tex::Tex,
+    util::{platform_file_picker_sz, Color128, Color32, FileFilter, PickerMode, Time},
     StereoKitError,
 };
 use std::{
-    ffi::{c_char, c_ushort, CStr, CString},
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    ffi::{c_char, c_ushort, c_void, CStr, CString},
+    path::PathBuf,
     ptr::{null_mut, NonNull},
 };
 
@@ -383,6 +391,9 @@ pub struct UiSliderData {
     pub interactor: i32,
 }
 
+/// The size in meters of the grip of [`Ui::window_begin_resizable`].
+const RESIZE_GRIP_SIZE: f32 = 0.02;
+
 /// This class is a collection of user interface and interaction methods! StereoKit uses an Immediate Mode GUI system,
 /// which can be very easy to work with and modify during runtime.
 ///
@@ -933,6 +944,168 @@ extern "C" {
     pub fn ui_panel_end();
 }
 
+/// Non canonical structure describing how a handle snaps while it is moved. See [`Ui::handle_snapped`].
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct UiHandleSnap {
+    /// The size of the grid the position snaps to, in meters. None doesn't snap the position.
+    pub position: Option<f32>,
+    /// The step the pitch, yaw and roll angles snap to, in degrees. None doesn't snap the rotation.
+    pub rotation: Option<f32>,
+    /// If true, the snapping is only applied while [`Ui::is_snap_modifier_active`] is true.
+    pub with_modifier: bool,
+}
+
+impl UiHandleSnap {
+    /// Snap the position on a grid of `grid_size` meters, always.
+    pub fn grid(grid_size: f32) -> Self {
+        Self { position: Some(grid_size), ..Default::default() }
+    }
+
+    /// Snap the angles by steps of `degrees`.
+    pub fn rotation(&mut self, degrees: f32) -> &mut Self {
+        self.rotation = Some(degrees);
+        self
+    }
+
+    /// Only snap while the modifier (grip or Shift key) is held.
+    pub fn with_modifier(&mut self, with_modifier: bool) -> &mut Self {
+        self.with_modifier = with_modifier;
+        self
+    }
+
+    /// Get the snapped version of a pose. Null or negative steps are ignored.
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::{maths::{Pose, Quat, Vec3}, ui::UiHandleSnap};
+    ///
+    /// let mut snap = UiHandleSnap::grid(0.1);
+    /// snap.rotation(45.0);
+    /// let pose = Pose::new(Vec3::new(0.234, -0.118, -0.51), Some(Quat::from_angles(0.0, 50.0, 0.0)));
+    /// let snapped = snap.apply(pose);
+    /// assert!(Vec3::distance(snapped.position, Vec3::new(0.2, -0.1, -0.5)) < 0.0001);
+    /// let forward = snapped.orientation.rotate_point(Vec3::FORWARD);
+    /// let expected = Quat::from_angles(0.0, 45.0, 0.0).rotate_point(Vec3::FORWARD);
+    /// assert!(Vec3::distance(forward, expected) < 0.0001);
+    ///
+    /// assert_eq!(UiHandleSnap::default().apply(pose), pose);
+    /// ```
+    pub fn apply(&self, pose: Pose) -> Pose {
+        let mut snapped = pose;
+        if let Some(grid) = self.position.filter(|grid| *grid > 0.0) {
+            let snap = |value: f32| (value / grid).round() * grid;
+            snapped.position = Vec3::new(snap(pose.position.x), snap(pose.position.y), snap(pose.position.z));
+        }
+        if let Some(step) = self.rotation.filter(|step| *step > 0.0) {
+            let step = step.to_radians();
+            let snap = |value: f32| (value / step).round() * step;
+            let orientation: glam::Quat = pose.orientation.into();
+            let (yaw, pitch, roll) = orientation.to_euler(glam::EulerRot::YXZ);
+            let orientation = glam::Quat::from_euler(glam::EulerRot::YXZ, snap(yaw), snap(pitch), snap(roll));
+            snapped.orientation = Quat::from(orientation);
+        }
+        snapped
+    }
+}
+
+/// The number of bytes a [`Ui::number_input`] field can hold, with the final nul.
+const NUMBER_INPUT_CAPACITY: usize = 64;
+
+/// The text edited in a [`Ui::number_input`], and the value it shows.
+struct NumberInputText {
+    buffer: Vec<u8>,
+    shown: f64,
+}
+
+impl NumberInputText {
+    fn new(value: f64) -> Self {
+        let mut input = Self { buffer: vec![0; NUMBER_INPUT_CAPACITY], shown: value };
+        input.show(value);
+        input
+    }
+
+    fn show(&mut self, value: f64) {
+        let text = format!("{value}");
+        let len = text.len().min(NUMBER_INPUT_CAPACITY - 1);
+        self.buffer.fill(0);
+        self.buffer[..len].copy_from_slice(&text.as_bytes()[..len]);
+        self.shown = value;
+    }
+
+    fn text(&self) -> &str {
+        CStr::from_bytes_until_nul(&self.buffer)
+            .ok()
+            .and_then(|text| text.to_str().ok())
+            .unwrap_or_default()
+    }
+}
+
+/// The materials drawn by [`Ui::image_uv`] with the last frame they were drawn.
+#[derive(Default)]
+struct ImageUvMaterials {
+    frame: u64,
+    materials: HashMap<(String, [u32; 4]), (Material, u64)>,
+}
+
+thread_local! {
+    static TREE_NODE_DEPTH: Cell<usize> = const { Cell::new(0) };
+    static CONTEXT_MENU: Cell<Option<IdHashT>> = const { Cell::new(None) };
+    static UI_DRAG: RefCell<Option<UiDrag>> = const { RefCell::new(None) };
+    /// The quad drawn by [`Ui::image_uv`], and its materials by texture id and UV rectangle.
+    static UI_IMAGE_UV_QUAD: RefCell<Option<Mesh>> = const { RefCell::new(None) };
+    static UI_IMAGE_UV_MATERIALS: RefCell<ImageUvMaterials> = RefCell::new(ImageUvMaterials::default());
+    /// The arcs of [`Ui::progress_ring`] by id, with the angle of their indeterminate spin.
+    static UI_PROGRESS_RINGS: RefCell<HashMap<String, (Mesh, f32)>> = RefCell::new(HashMap::new());
+    /// The texts of the [`Ui::number_input`] fields by id hash.
+    static UI_NUMBER_INPUTS: RefCell<HashMap<IdHashT, NumberInputText>> = RefCell::new(HashMap::new());
+    /// The id of the [`Ui::file_picker_button`] whose picker is open.
+    static UI_FILE_PICKER_PENDING: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// The files chosen for the [`Ui::file_picker_button`] by id, not yet returned.
+    static UI_FILE_PICKS: RefCell<HashMap<String, PathBuf>> = RefCell::new(HashMap::new());
+}
+
+/// The callback of the picker opened by [`Ui::file_picker_button`]. The picker may outlive the frame it was opened
+/// in, so the id of the button is kept in a thread local instead of the callback data.
+unsafe extern "C" fn ui_file_picker_callback(
+    _callback_data: *mut c_void,
+    confirmed: Bool32T,
+    filename: *const c_char,
+    filename_length: i32,
+) {
+    let Some(id) = UI_FILE_PICKER_PENDING.with_borrow_mut(|pending| pending.take()) else {
+        return;
+    };
+    if confirmed != 0 && !filename.is_null() && filename_length > 0 {
+        let bytes = std::slice::from_raw_parts(filename as *const u8, filename_length as usize);
+        Ui::file_picker_select(id, String::from_utf8_lossy(bytes).trim_end_matches('\0'));
+    }
+}
+
+/// The spin speed of an indeterminate [`Ui::progress_ring`], in degrees per second.
+const PROGRESS_RING_SPIN_SPEED: f32 = 360.0;
+/// The arc length of an indeterminate [`Ui::progress_ring`], in degrees.
+const PROGRESS_RING_SPIN_ARC: f32 = 90.0;
+/// The number of segments of a full [`Ui::progress_ring`].
+const PROGRESS_RING_SEGMENTS: f32 = 64.0;
+
+/// The drag in progress between a [`Ui::drag_source`] and a [`Ui::drop_target`].
+struct UiDrag {
+    source: String,
+    handed: Handed,
+    payload: Box<dyn Any>,
+    /// The last frame the source was drawn.
+    frame: u64,
+}
+
+/// Work on the drag in progress, forgetting it if its source was not drawn during the last frame.
+fn with_ui_drag<R>(work: impl FnOnce(&mut Option<UiDrag>) -> R) -> R {
+    UI_DRAG.with_borrow_mut(|drag| {
+        if drag.as_ref().is_some_and(|drag| drag.frame + 1 < Time::get_frame()) {
+            *drag = None;
+        }
+        work(drag)
+    })
+}
+
 impl Ui {
     /// StereoKit will generate a color palette from this gamma space color, and use it to skin the UI! To explicitly
     /// adjust individual theme colors, see Ui::set_theme_color.
@@ -943,6 +1116,147 @@ impl Ui {
         unsafe { ui_set_color(color.into()) };
     }
 
+    /// Non canonical function starting a context menu attached to the element drawn just before. The menu opens when
+    /// this element is focused and receives a secondary action (see [`Ui::get_secondary_action`]), and closes when a
+    /// pinch or a click is released outside of it. Pressing an item doesn't close the menu, call
+    /// [`Ui::context_menu_close`] for this. When it returns true, draw the items of the menu then call
+    /// [`Ui::context_menu_end`].
+    /// * id - An id for tracking the menu. MUST be unique within current hierarchy.
+    ///
+    /// Returns true if the menu is open and its items must be drawn.
+    /// see also [`Ui::context_menu_end`] [`Ui::context_menu_open`] [`Ui::context_menu_close`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Quat, Vec3}, system::{HandJoint, Handed, Hierarchy, Input, Key}, ui::Ui};
+    ///
+    /// let mut window_pose = Pose::new([0.0, 0.05, 0.9], Some(Quat::from_angles(0.0, 180.0, 0.0)));
+    /// // A hand with its index finger at `point`, the thumb being far from it unless the hand pinches.
+    /// let hand_at = |point: Vec3, pinch: bool| {
+    ///     let mut joints = [HandJoint { position: point, orientation: Quat::IDENTITY, radius: 0.005 }; 25];
+    ///     if !pinch {
+    ///         joints[0..5].iter_mut().for_each(|joint| joint.position = point + Vec3::UP * 0.06);
+    ///     }
+    ///     joints
+    /// };
+    /// let (mut cube, mut delete, mut toward_user) = (Vec3::ZERO, Vec3::ZERO, Vec3::FORWARD);
+    /// let mut deleted = false;
+    /// let mut menu_drawn = vec![];
+    ///
+    /// filename_scr = "screenshots/context_menu.jpeg";
+    /// number_of_steps = 16;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Ui::window_begin("Scene", &mut window_pose, None, None, None);
+    ///     toward_user = Hierarchy::to_world_direction(token, Vec3::FORWARD);
+    ///     let item = || Ui::get_layout_at() + Vec3::new(-0.02, -Ui::get_line_height() / 2.0, 0.0);
+    ///     cube = Hierarchy::to_world_point(token, item());
+    ///     Ui::button("Cube", None);
+    ///     let mut drawn = false;
+    ///     if Ui::context_menu_begin("cube_menu") {
+    ///         drawn = true;
+    ///         delete = Hierarchy::to_world_point(token, item());
+    ///         if Ui::button("Delete", None) {
+    ///             deleted = true;
+    ///         }
+    ///         Ui::context_menu_end();
+    ///     }
+    ///     Ui::window_end();
+    ///     menu_drawn.push(drawn);
+    ///
+    ///     match iter {
+    ///         // The finger hovers the cube button while the secondary action is pressed.
+    ///         1 | 3 => Input::hand_override(Handed::Right, &hand_at(cube + toward_user * 0.005, false)),
+    ///         2 => Input::key_inject_press(Key::MouseRight),
+    ///         4 => Input::key_inject_release(Key::MouseRight),
+    ///         // The finger pokes the Delete item.
+    ///         5 | 8 => Input::hand_override(Handed::Right, &hand_at(delete + toward_user * 0.03, false)),
+    ///         6 => Input::hand_override(Handed::Right, &hand_at(delete + toward_user * 0.005, false)),
+    ///         7 => Input::hand_override(Handed::Right, &hand_at(delete - toward_user * 0.005, false)),
+    ///         // A pinch released in front of the menu keeps it open.
+    ///         9 => Input::hand_override(Handed::Right, &hand_at(delete + toward_user * 0.03, true)),
+    ///         10 => Input::hand_override(Handed::Right, &hand_at(delete + toward_user * 0.03, false)),
+    ///         // A pinch released away from the menu closes it.
+    ///         11 => Input::hand_override(Handed::Right, &hand_at(delete + Vec3::UP * 0.3, true)),
+    ///         12 => Input::hand_override(Handed::Right, &hand_at(delete + Vec3::UP * 0.3, false)),
+    ///         13 => Input::hand_clear_override(Handed::Right),
+    ///         _ => (),
+    ///     }
+    /// );
+    /// // Opened by the secondary action, the menu stayed open while its item was pressed.
+    /// let opened_at = menu_drawn.iter().position(|drawn| *drawn).expect("the menu should open");
+    /// assert!(opened_at <= 5);
+    /// assert!(deleted);
+    /// assert!(menu_drawn[opened_at..=11].iter().all(|drawn| *drawn));
+    /// assert_eq!(menu_drawn.last(), Some(&false));
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/context_menu.jpeg" alt="screenshot" width="200">
+    pub fn context_menu_begin(id: impl AsRef<str>) -> bool {
+        let hash = Ui::stack_hash(id.as_ref());
+        if Ui::get_last_element_focused().is_active() && Ui::get_secondary_action().is_just_active() {
+            CONTEXT_MENU.with(|menu| menu.set(Some(hash)));
+        }
+        if CONTEXT_MENU.with(|menu| menu.get()) != Some(hash) {
+            return false;
+        }
+        Ui::push_id(id);
+        Ui::panel_begin(None);
+        true
+    }
+
+    /// Non canonical function closing the items of a context menu whose [`Ui::context_menu_begin`] returned true. The
+    /// menu is closed if a pinch or a click is released outside of it.
+    ///
+    /// see also [`Ui::context_menu_begin`]
+    pub fn context_menu_end() {
+        Ui::panel_end();
+        Ui::pop_id();
+        // The panel is the last element of the layout.
+        let bounds = Ui::get_layout_last();
+        let inside = |local: Vec3| {
+            (local.x - bounds.center.x).abs() <= bounds.dimensions.x / 2.0
+                && (local.y - bounds.center.y).abs() <= bounds.dimensions.y / 2.0
+        };
+        let mut hand_released = false;
+        let mut released_outside = false;
+        for handed in [Handed::Left, Handed::Right] {
+            let hand = Input::hand(handed);
+            if hand.pinch.is_just_inactive() {
+                hand_released = true;
+                released_outside |= !inside(unsafe { hierarchy_to_local_point(&hand.pinch_pt) });
+            }
+        }
+        // The simulated hand already follows the mouse, so the mouse ray is only used when no hand released.
+        let mouse = Input::get_mouse();
+        if !hand_released && mouse.available != 0 && Input::key(Key::MouseLeft).is_just_inactive() {
+            let ray = unsafe { hierarchy_to_local_ray(mouse.get_ray()) };
+            let on_menu = ray.direction.z != 0.0 && {
+                let distance = (bounds.center.z - ray.position.z) / ray.direction.z;
+                distance >= 0.0 && inside(ray.position + ray.direction * distance)
+            };
+            released_outside |= !on_menu;
+        }
+        if released_outside {
+            Ui::context_menu_close();
+        }
+    }
+
+    /// Non canonical function opening a context menu from code, closing the one that was open.
+    /// * id - The id given to [`Ui::context_menu_begin`], in the same hierarchy.
+    ///
+    /// see also [`Ui::context_menu_begin`]
+    pub fn context_menu_open(id: impl AsRef<str>) {
+        let hash = Ui::stack_hash(id);
+        CONTEXT_MENU.with(|menu| menu.set(Some(hash)));
+    }
+
+    /// Non canonical function closing the open context menu, if any.
+    ///
+    /// see also [`Ui::context_menu_begin`]
+    pub fn context_menu_close() {
+        CONTEXT_MENU.with(|menu| menu.set(None));
+    }
+
     /// Enables or disables the far ray grab interaction for Handle elements like the Windows. It can be enabled and
     /// disabled for individual UI elements, and if this remains disabled at the start of the next frame, then the
     /// hand ray indicators will not be visible. This is enabled by default.
@@ -1255,6 +1569,82 @@ impl Ui {
         unsafe { ui_handle_begin(cstr.as_ptr(), pose, handle, draw_handle as Bool32T, move_type, allower_gesture) != 0 }
     }
 
+    /// Non canonical function doing the same as [`Ui::handle`], with the pose snapped while the user is moving the
+    /// handle. When the handle is not grabbed or the snap doesn't apply, the pose is left as Ui::handle gives it.
+    /// * snap - The grid and angle steps, and if they need the modifier.
+    /// * move_type - If None, has default value of UiMove::Exact
+    /// * allower_gesture - If None, has default value of UiGesture::Pinch
+    ///
+    /// see also [`UiHandleSnap`] [`Ui::handle_begin_snapped`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Bounds, Pose, Vec3}, ui::{Ui, UiHandleSnap}};
+    ///
+    /// let mut pose = Pose::new(Vec3::new(0.04, 0.0, -0.3), None);
+    /// let snap = UiHandleSnap::grid(0.1);
+    ///
+    /// filename_scr = "screenshots/ui_handle_snapped.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     // Nobody grabs the handle here, so the pose is not snapped.
+    ///     assert!(!Ui::handle_snapped("snapped", &mut pose, Bounds::new(Vec3::ZERO, Vec3::ONE * 0.1),
+    ///                                 true, &snap, None, None));
+    ///     assert_eq!(pose.position, Vec3::new(0.04, 0.0, -0.3));
+    /// );
+    /// // This is what is written back while the handle is dragged.
+    /// assert!(Vec3::distance(snap.apply(pose).position, Vec3::new(0.0, 0.0, -0.3)) < 0.0001);
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/ui_handle_snapped.jpeg" alt="screenshot" width="200">
+    #[allow(clippy::too_many_arguments)]
+    pub fn handle_snapped(
+        id: impl AsRef<str>,
+        pose: &mut Pose,
+        handle: Bounds,
+        draw_handle: bool,
+        snap: &UiHandleSnap,
+        move_type: Option<UiMove>,
+        allower_gesture: Option<UiGesture>,
+    ) -> bool {
+        let result = Self::handle_begin_snapped(id, pose, handle, draw_handle, snap, move_type, allower_gesture);
+        Self::handle_end();
+        result
+    }
+
+    /// Non canonical function doing the same as [`Ui::handle_begin`], with the pose snapped while the user is moving
+    /// the handle. The elements drawn up to [`Ui::handle_end`] follow the snapped pose from the next frame.
+    /// * snap - The grid and angle steps, and if they need the modifier.
+    /// * move_type - If None, has default value of UiMove::Exact
+    /// * allower_gesture - If None, has default value of UiGesture::Pinch
+    ///
+    /// see also [`UiHandleSnap`] [`Ui::handle_snapped`]
+    #[allow(clippy::too_many_arguments)]
+    pub fn handle_begin_snapped(
+        id: impl AsRef<str>,
+        pose: &mut Pose,
+        handle: Bounds,
+        draw_handle: bool,
+        snap: &UiHandleSnap,
+        move_type: Option<UiMove>,
+        allower_gesture: Option<UiGesture>,
+    ) -> bool {
+        let result = Self::handle_begin(id, pose, handle, draw_handle, move_type, allower_gesture);
+        if result && (!snap.with_modifier || Self::is_snap_modifier_active()) {
+            *pose = snap.apply(*pose);
+        }
+        result
+    }
+
+    /// Non canonical function telling if the modifier of [`UiHandleSnap::with_modifier`] is held: the grip of a hand
+    /// (or controller) or the Shift key.
+    ///
+    /// see also [`Ui::handle_snapped`]
+    pub fn is_snap_modifier_active() -> bool {
+        Input::key(Key::Shift).is_active()
+            || Input::hand(Handed::Left).grip.is_active()
+            || Input::hand(Handed::Right).grip.is_active()
+    }
+
     /// Finishes a handle! Must be called after UI::handle_begin() and all elements have been drawn. Pops the pose
     /// transform pushed by Ui::handle_begin() from the hierarchy stack.
     /// <https://stereokit.net/Pages/StereoKit/UI/HandleEnd.html>
@@ -1304,6 +1694,115 @@ impl Ui {
         }
     }
 
+    /// Non canonical function drawing a horizontal slider locked on notches, with a tick mark under each notch and
+    /// optionally a label under the ticks. Great for settings like Low/Medium/High.
+    /// * value - The value of the slider, snapped to the nearest notch.
+    /// * step - The distance between two notches, starting at min. 0 or less gives a single notch per bound.
+    /// * notch_labels - The texts shown under the first notches, if any.
+    /// * width - Physical width of the slider on the window. None will fill the remaining amount of window space.
+    ///
+    /// Returns true only when the snapped value has changed.
+    /// see also [`Ui::hslider`] [`Ui::slider_snap`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Vec2, Vec3}, ui::Ui};
+    ///
+    /// let mut window_pose = Pose::new(Vec3::new(0.0, 0.05, 0.9), None);
+    /// let mut quality = 0.4;
+    ///
+    /// filename_scr = "screenshots/ui_hslider_stepped.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Ui::window_begin("Settings", &mut window_pose, Some(Vec2::new(0.24, 0.0)), None, None);
+    ///     let changed = Ui::hslider_stepped("quality", &mut quality, 0.0, 2.0, 1.0,
+    ///                                       Some(&["Low", "Medium", "High"]), None);
+    ///     // The first frame snaps 0.4 to the Low notch.
+    ///     assert_eq!(changed, iter == 0);
+    ///     Ui::window_end();
+    /// );
+    /// assert_eq!(quality, 0.0);
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/ui_hslider_stepped.jpeg" alt="screenshot" width="200">
+    pub fn hslider_stepped(
+        id: impl AsRef<str>,
+        value: &mut f32,
+        min: f32,
+        max: f32,
+        step: f32,
+        notch_labels: Option<&[&str]>,
+        width: Option<f32>,
+    ) -> bool {
+        let previous = *value;
+        let mut slider_value = Self::slider_snap(*value, min, max, step);
+        Self::hslider(id, &mut slider_value, min, max, Some(step.max(0.0)), width, None, None);
+        *value = Self::slider_snap(slider_value, min, max, step);
+
+        // The thumb is as large as the slider is high, so the notches go from half a thumb to the other.
+        let bounds = Self::get_layout_last();
+        let top_left = bounds.center + Vec3::new(bounds.dimensions.x, bounds.dimensions.y, 0.0) / 2.0;
+        let thumb = bounds.dimensions.y;
+        let travel = (bounds.dimensions.x - thumb).max(0.0);
+        let notch_count = if step > 0.0 && max > min { ((max - min) / step + 0.001).floor() as usize + 1 } else { 2 };
+        let notch_x = |notch: usize| {
+            let notch_value = if step > 0.0 { min + notch as f32 * step } else { min + notch as f32 * (max - min) };
+            let ratio = if max > min { (notch_value - min) / (max - min) } else { 0.0 };
+            top_left.x - thumb / 2.0 - ratio * travel
+        };
+        let tick_size = Vec3::new(thumb * 0.08, thumb * 0.2, thumb * 0.05);
+        for notch in 0..notch_count {
+            let start = Vec3::new(notch_x(notch) + tick_size.x / 2.0, top_left.y - thumb, top_left.z);
+            Self::draw_element(UiVisual::SliderLine, None, start, tick_size, 0.0);
+        }
+
+        if let Some(labels) = notch_labels {
+            let line = Self::layout_reserve(Vec2::new(bounds.dimensions.x, Self::get_line_height()), false, 0.0);
+            let label_top = line.center.y + line.dimensions.y / 2.0;
+            let label_width = if notch_count > 1 { travel / (notch_count - 1) as f32 } else { bounds.dimensions.x };
+            for (notch, label) in labels.iter().take(notch_count).enumerate() {
+                let start = Vec3::new(notch_x(notch) + label_width / 2.0, label_top, top_left.z);
+                Self::text_at(
+                    label,
+                    None,
+                    None,
+                    TextAlign::TopCenter,
+                    TextFit::Squeeze,
+                    start,
+                    Vec2::new(label_width, line.dimensions.y),
+                );
+            }
+        }
+        *value != previous
+    }
+
+    /// Non canonical function giving the notch of a [`Ui::hslider_stepped`] nearest to a value.
+    /// * step - The distance between two notches, starting at min. 0 or less only clamps the value.
+    ///
+    /// Returns the value of the nearest notch between min and max.
+    /// see also [`Ui::hslider_stepped`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::ui::Ui;
+    ///
+    /// assert_eq!(Ui::slider_snap(0.4, 0.0, 2.0, 1.0), 0.0);
+    /// assert_eq!(Ui::slider_snap(0.6, 0.0, 2.0, 1.0), 1.0);
+    /// assert_eq!(Ui::slider_snap(1.7, 0.0, 2.0, 1.0), 2.0);
+    /// assert_eq!(Ui::slider_snap(5.0, 0.0, 2.0, 1.0), 2.0);
+    /// // The notches start at min, and the last one can't be beyond max.
+    /// assert_eq!(Ui::slider_snap(9.9, 1.0, 10.0, 4.0), 9.0);
+    /// assert_eq!(Ui::slider_snap(-3.0, 1.0, 10.0, 4.0), 1.0);
+    /// assert_eq!(Ui::slider_snap(0.33, 0.0, 1.0, 0.0), 0.33);
+    /// ```
+    pub fn slider_snap(value: f32, min: f32, max: f32, step: f32) -> f32 {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        let value = value.clamp(min, max);
+        if step <= 0.0 {
+            return value;
+        }
+        let last_notch = ((max - min) / step + 0.001).floor();
+        min + ((value - min) / step).round().min(last_notch) * step
+    }
+
     /// A vertical slider element! You can stick your finger in it, and slide the value up and down.
     /// <https://stereokit.net/Pages/StereoKit/UI/HSlider.html>
     /// * step - Locks the value to increments of step. Starts at min, and increments by step. Default 0 is valid,
@@ -1424,6 +1923,111 @@ impl Ui {
         unsafe { ui_image(image.as_ref().0.as_ptr(), size.into()) };
     }
 
+    /// Non canonical function adding a region of a texture to the UI, like an icon of an atlas or a tiled background.
+    /// The UV rectangle is mapped onto the image, uv_min at its top left corner and uv_max at its bottom right one.
+    /// UVs beyond 0..1 follow the address mode of the texture, so with the default [`crate::tex::TexAddress::Wrap`] the texture
+    /// tiles. A flipped rectangle (min > max) mirrors the image.
+    ///
+    /// All the images share a single quad, the UV rectangle being the `tex_trans` parameter of a material made for each
+    /// texture and rectangle. A material not drawn during the last frame is released with its texture.
+    /// * image - The texture to draw from.
+    /// * size - The layout size of the image in Hierarchy space.
+    /// * uv_min - The UV of the top left corner of the image.
+    /// * uv_max - The UV of the bottom right corner of the image.
+    ///
+    /// see also [`Ui::image`] [`Ui::layout_reserve`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Quat, Vec2}, system::{AssetType, Assets}, tex::{Tex, TexSample}, ui::Ui,
+    ///                      util::named_colors::{BLUE, GREEN, RED, YELLOW}};
+    ///
+    /// // A checker of 4 colored quadrants.
+    /// let mut pixels = vec![RED; 8 * 8];
+    /// for (i, pixel) in pixels.iter_mut().enumerate() {
+    ///     *pixel = match ((i % 8) / 4, (i / 8) / 4) {
+    ///         (0, 0) => RED,
+    ///         (1, 0) => GREEN,
+    ///         (0, _) => BLUE,
+    ///         _ => YELLOW,
+    ///     };
+    /// }
+    /// let mut checker = Tex::from_color32(&pixels, 8, 8, true).unwrap();
+    /// checker.sample_mode(TexSample::Point);
+    ///
+    /// let mut window_pose = Pose::new([0.0, 0.05, 0.9], Some(Quat::from_angles(0.0, 180.0, 0.0)));
+    /// let materials_before = Assets::all_of_type(AssetType::Material).count();
+    /// filename_scr = "screenshots/ui_image_uv.jpeg";
+    /// number_of_steps = 12;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Ui::window_begin("Atlas", &mut window_pose, None, None, None);
+    ///     // A scrolling region during the first frames only.
+    ///     if iter < 8 {
+    ///         let offset = iter as f32 / 8.0;
+    ///         Ui::image_uv(&checker, Vec2::new(0.06, 0.06), Vec2::new(offset, 0.0), Vec2::new(offset + 0.5, 0.5));
+    ///         Ui::same_line();
+    ///     }
+    ///     // The top left (red) quadrant.
+    ///     Ui::image_uv(&checker, Vec2::new(0.06, 0.06), Vec2::ZERO, Vec2::new(0.5, 0.5));
+    ///     Ui::same_line();
+    ///     // Tiled 2x2.
+    ///     Ui::image_uv(&checker, Vec2::new(0.06, 0.06), Vec2::ZERO, Vec2::new(2.0, 2.0));
+    ///     Ui::same_line();
+    ///     // Mirrored horizontally.
+    ///     Ui::image_uv(&checker, Vec2::new(0.06, 0.06), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0));
+    ///     Ui::window_end();
+    /// );
+    /// // Only the materials of the three regions still drawn are kept.
+    /// assert!(Assets::all_of_type(AssetType::Material).count() <= materials_before + 3);
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/ui_image_uv.jpeg" alt="screenshot" width="200">
+    pub fn image_uv(image: impl AsRef<Tex>, size: impl Into<Vec2>, uv_min: impl Into<Vec2>, uv_max: impl Into<Vec2>) {
+        let image = image.as_ref();
+        let (uv_min, uv_max): (Vec2, Vec2) = (uv_min.into(), uv_max.into());
+        let bounds = Self::layout_reserve(size, false, 0.0);
+
+        let mesh = UI_IMAGE_UV_QUAD.with_borrow_mut(|quad| {
+            quad.get_or_insert_with(|| {
+                // A unit quad facing the user, X+ is on the left in the UI.
+                let vertices = [
+                    Vertex::new([0.5, 0.5, 0.0], [0.0, 0.0, 1.0], Some(Vec2::new(0.0, 0.0)), None),
+                    Vertex::new([-0.5, 0.5, 0.0], [0.0, 0.0, 1.0], Some(Vec2::new(1.0, 0.0)), None),
+                    Vertex::new([0.5, -0.5, 0.0], [0.0, 0.0, 1.0], Some(Vec2::new(0.0, 1.0)), None),
+                    Vertex::new([-0.5, -0.5, 0.0], [0.0, 0.0, 1.0], Some(Vec2::new(1.0, 1.0)), None),
+                ];
+                let mut mesh = Mesh::new();
+                mesh.id("ui_image_uv/quad").set_verts(&vertices, true).set_inds(&[0, 1, 3, 0, 3, 2]);
+                mesh
+            })
+            .0
+        });
+        let frame = Time::get_frame();
+        let key = (image.get_id().to_string(), [uv_min.x, uv_min.y, uv_max.x, uv_max.y].map(f32::to_bits));
+        let material = UI_IMAGE_UV_MATERIALS.with_borrow_mut(|cache| {
+            // Only the materials drawn by the previous frame may still be used.
+            if cache.frame != frame {
+                cache.frame = frame;
+                cache.materials.retain(|_, (_, last_frame)| *last_frame + 1 >= frame);
+            }
+            let (material, last_frame) = cache.materials.entry(key).or_insert_with(|| {
+                let mut material = Material::unlit().copy();
+                material.diffuse_tex(image).transparency(Transparency::Blend).tex_transform(Vec4::new(
+                    uv_min.x,
+                    uv_min.y,
+                    uv_max.x - uv_min.x,
+                    uv_max.y - uv_min.y,
+                ));
+                (material, frame)
+            });
+            *last_frame = frame;
+            material.0
+        });
+
+        let transform = Matrix::ts(bounds.center, Vec3::new(bounds.dimensions.x, bounds.dimensions.y, 1.0));
+        unsafe { mesh_draw(mesh.as_ptr(), material.as_ptr(), transform, Color128::WHITE, RenderLayer::Layer0) };
+    }
+
     /// This is an input field where users can input text to the app! Selecting it will spawn a virtual keyboard, or act
     /// as the keyboard focus. Hitting escape or enter, or focusing another UI element will remove focus from this Input.
     /// <https://stereokit.net/Pages/StereoKit/UI/Input.html>
@@ -1622,6 +2226,167 @@ impl Ui {
         unsafe { ui_nextline() };
     }
 
+    /// Non canonical function drawing a numeric field followed by two buttons adding or subtracting `step` to the
+    /// value. The text typed in the field is kept while it is edited, even when it is not a number yet (like "-" or
+    /// "1."), and the value changes each time the text is a number. Once no field has the keyboard focus, the field
+    /// shows the value again.
+    /// * id - An id for tracking element state. MUST be unique within current hierarchy.
+    /// * value - The value displayed and edited.
+    /// * step - The amount added by the up button and subtracted by the down button.
+    /// * range - If Some((min, max)), the value is kept inside this range.
+    ///
+    /// Returns true if the value has changed this frame.
+    /// see also [`Ui::number_input_step`] [`Ui::number_input_parse`] [`Ui::input`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Quat, Vec3}, system::{HandJoint, Handed, Hierarchy, Input}, ui::Ui};
+    ///
+    /// let mut scale = 1.0;
+    /// let mut window_pose = Pose::new([0.0, 0.05, 0.9], Some(Quat::from_angles(0.0, 180.0, 0.0)));
+    /// // A hand with all its joints at the same point.
+    /// let hand_at = |point: Vec3| [HandJoint { position: point, orientation: Quat::IDENTITY, radius: 0.005 }; 25];
+    /// let (mut field, mut toward_user) = (Vec3::ZERO, Vec3::FORWARD);
+    ///
+    /// filename_scr = "screenshots/number_input.jpeg";
+    /// number_of_steps = 12;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Ui::window_begin("Transform", &mut window_pose, None, None, None);
+    ///     Ui::label("Scale", None, false);
+    ///     Ui::same_line();
+    ///     // 2cm inside the left of the field, at the middle of the line.
+    ///     let local = Ui::get_layout_at() + Vec3::new(-0.02, -Ui::get_line_height() / 2.0, 0.0);
+    ///     field = Hierarchy::to_world_point(token, local);
+    ///     toward_user = Hierarchy::to_world_direction(token, Vec3::FORWARD);
+    ///     if Ui::number_input("scale", &mut scale, 0.1, None) {
+    ///         println!("New scale {scale}");
+    ///     }
+    ///     Ui::window_end();
+    ///
+    ///     // The index finger pokes the field to give it the focus, then the text is typed one character per step.
+    ///     match iter {
+    ///         1 | 4 => Input::hand_override(Handed::Right, &hand_at(field + toward_user * 0.03)),
+    ///         2 => Input::hand_override(Handed::Right, &hand_at(field + toward_user * 0.005)),
+    ///         3 => Input::hand_override(Handed::Right, &hand_at(field - toward_user * 0.005)),
+    ///         5 => Input::hand_clear_override(Handed::Right),
+    ///         6 => Input::text_inject_chars("2"),
+    ///         7 => Input::text_inject_chars("."),
+    ///         // "12." is kept while it is edited, the value being 12.
+    ///         8 => { assert_eq!(scale, 12.0); Input::text_inject_chars("7") }
+    ///         9 => Input::text_inject_chars("5"),
+    ///         _ => {}
+    ///     }
+    /// );
+    /// // More characters than the initial "1" were typed.
+    /// assert_eq!(scale, 12.75);
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/number_input.jpeg" alt="screenshot" width="200">
+    pub fn number_input(id: impl AsRef<str>, value: &mut f64, step: f64, range: Option<(f64, f64)>) -> bool {
+        let line_height = Ui::get_line_height();
+        let button_size = Vec2::new(line_height, line_height);
+        let input_width = (Ui::get_layout_remaining().x - (line_height + Ui::get_settings().gutter) * 2.0).max(0.0);
+        let mut changed = false;
+        Ui::push_id(id);
+        let key = Ui::stack_hash("value");
+        let editing = unsafe { ui_has_keyboard_focus() } != 0;
+        let mut input = UI_NUMBER_INPUTS
+            .with_borrow_mut(|inputs| inputs.remove(&key))
+            .unwrap_or_else(|| NumberInputText::new(*value));
+        // The value has been changed by someone else, or the typed text is no longer edited.
+        if input.shown != *value || (!editing && input.text() != format!("{value}")) {
+            input.show(*value);
+        }
+        let cstr_id = CString::new("value").unwrap();
+        let size = Vec2::new(input_width, 0.0);
+        let buffer = input.buffer.as_mut_ptr() as *mut c_char;
+        if unsafe { ui_input(cstr_id.as_ptr(), buffer, NUMBER_INPUT_CAPACITY as i32, size, TextContext::Number) } != 0 {
+            changed |= Ui::number_input_parse(value, input.text(), range);
+            input.shown = *value;
+        }
+        UI_NUMBER_INPUTS.with_borrow_mut(|inputs| inputs.insert(key, input));
+        Ui::same_line();
+        if Ui::button_img("up", Sprite::arrow_up(), Some(UiBtnLayout::CenterNoText), Some(button_size), None) {
+            changed |= Ui::number_input_step(value, step, range);
+        }
+        Ui::same_line();
+        if Ui::button_img("down", Sprite::arrow_down(), Some(UiBtnLayout::CenterNoText), Some(button_size), None) {
+            changed |= Ui::number_input_step(value, -step, range);
+        }
+        Ui::pop_id();
+        changed
+    }
+
+    /// Non canonical function doing what a press on the up button of [`Ui::number_input`] does, or on the down button
+    /// with a negative `step`.
+    /// * value - The value to change.
+    /// * step - The amount added to the value.
+    /// * range - If Some((min, max)), the value is kept inside this range.
+    ///
+    /// Returns true if the value has changed.
+    /// see also [`Ui::number_input`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::ui::Ui;
+    ///
+    /// let mut value = 9.0;
+    /// assert!(Ui::number_input_step(&mut value, 0.5, Some((0.0, 10.0))));
+    /// assert_eq!(value, 9.5);
+    /// assert!(Ui::number_input_step(&mut value, 0.5, Some((0.0, 10.0))));
+    /// assert_eq!(value, 10.0);
+    ///
+    /// // Clamped at the max, a press changes nothing.
+    /// assert!(!Ui::number_input_step(&mut value, 0.5, Some((0.0, 10.0))));
+    /// assert_eq!(value, 10.0);
+    ///
+    /// assert!(Ui::number_input_step(&mut value, -0.5, None));
+    /// assert_eq!(value, 9.5);
+    /// ```
+    pub fn number_input_step(value: &mut f64, step: f64, range: Option<(f64, f64)>) -> bool {
+        Self::number_input_set(value, *value + step, range)
+    }
+
+    /// Non canonical function doing what a typed text does to the value of a [`Ui::number_input`]. A text that is not
+    /// a number leaves the value unchanged.
+    /// * value - The value to change.
+    /// * text - The typed text.
+    /// * range - If Some((min, max)), the value is kept inside this range.
+    ///
+    /// Returns true if the value has changed.
+    /// see also [`Ui::number_input`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::ui::Ui;
+    ///
+    /// let mut value = 1.0;
+    /// assert!(Ui::number_input_parse(&mut value, " 2.5", None));
+    /// assert_eq!(value, 2.5);
+    /// assert!(Ui::number_input_parse(&mut value, "42", Some((0.0, 10.0))));
+    /// assert_eq!(value, 10.0);
+    ///
+    /// assert!(!Ui::number_input_parse(&mut value, "2.5cm", None));
+    /// assert!(!Ui::number_input_parse(&mut value, "NaN", None));
+    /// assert_eq!(value, 10.0);
+    /// ```
+    pub fn number_input_parse(value: &mut f64, text: impl AsRef<str>, range: Option<(f64, f64)>) -> bool {
+        match text.as_ref().trim().parse::<f64>() {
+            Ok(new_value) if new_value.is_finite() => Self::number_input_set(value, new_value, range),
+            _ => false,
+        }
+    }
+
+    fn number_input_set(value: &mut f64, new_value: f64, range: Option<(f64, f64)>) -> bool {
+        let new_value = match range {
+            Some((min, max)) => new_value.clamp(min.min(max), max.max(min)),
+            None => new_value,
+        };
+        if new_value == *value {
+            return false;
+        }
+        *value = new_value;
+        true
+    }
+
     /// If you wish to manually draw a Panel, this function will let you draw one wherever you want!
     /// <https://stereokit.net/Pages/StereoKit/UI/PanelAt.html>
     /// * padding - If None the default value is UiPad::Outside
@@ -1788,6 +2553,91 @@ impl Ui {
         }
     }
 
+    /// Non canonical function drawing a circular progress indicator, as an arc going clockwise from the top of a ring
+    /// to `progress * 360°`. A negative progress draws an indeterminate spinner, turning over time.
+    /// * id - An id for tracking the ring. MUST be unique within current hierarchy.
+    /// * progress - A value between 0 and 1 indicating progress from 0% to 100%, or a negative value for the
+    ///   indeterminate mode.
+    /// * size - The diameter of the ring, in meters.
+    /// * thickness - The thickness of the ring, in meters.
+    /// * color - If None, the Primary theme color is used.
+    ///
+    /// see also [`Ui::hprogress_bar`] [`Ui::get_progress_ring_angle`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(1.0 / 60.0); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Quat}, ui::Ui, util::named_colors::GREEN};
+    ///
+    /// let mut angles = vec![];
+    /// let mut window_pose = Pose::new([0.0, 0.05, 0.9], Some(Quat::from_angles(0.0, 180.0, 0.0)));
+    /// filename_scr = "screenshots/ui_progress_ring.jpeg";
+    /// number_of_steps = 3;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Ui::window_begin("Loading", &mut window_pose, None, None, None);
+    ///     Ui::progress_ring("quarter", 0.25, 0.05, 0.008, None);
+    ///     Ui::same_line();
+    ///     Ui::progress_ring("three_quarters", 0.75, 0.05, 0.008, Some(GREEN.into()));
+    ///     Ui::same_line();
+    ///     Ui::progress_ring("spinner", -1.0, 0.05, 0.008, None);
+    ///     Ui::window_end();
+    ///     angles.push(Ui::get_progress_ring_angle("spinner").expect("the spinner is drawn"));
+    /// );
+    /// assert!(angles.windows(2).all(|pair| pair[1] > pair[0]));
+    /// assert_eq!(Ui::get_progress_ring_angle("quarter"), Some(0.0));
+    /// assert_eq!(Ui::get_progress_ring_angle("unknown"), None);
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/ui_progress_ring.jpeg" alt="screenshot" width="200">
+    pub fn progress_ring(id: impl AsRef<str>, progress: f32, size: f32, thickness: f32, color: Option<Color128>) {
+        let bounds = Self::layout_reserve(Vec2::new(size, size), false, 0.0);
+        let color = color.unwrap_or_else(|| Ui::get_theme_color(UiColor::Primary, None));
+
+        UI_PROGRESS_RINGS.with_borrow_mut(|rings| {
+            let (mesh, spin) = rings.entry(id.as_ref().to_string()).or_insert_with(|| (Mesh::new(), 0.0));
+            let (start, sweep) = if progress < 0.0 {
+                *spin = (*spin + Time::get_stepf() * PROGRESS_RING_SPIN_SPEED) % 360.0;
+                (*spin, PROGRESS_RING_SPIN_ARC)
+            } else {
+                *spin = 0.0;
+                (0.0, progress.min(1.0) * 360.0)
+            };
+            if sweep <= 0.0 {
+                return;
+            }
+
+            // Angles go clockwise from the top, for the user facing the window where X+ is on the left.
+            let (outer, inner) = (size / 2.0, (size / 2.0 - thickness).max(0.0));
+            let segments = (sweep / 360.0 * PROGRESS_RING_SEGMENTS).ceil().max(1.0) as u32;
+            let mut vertices = Vec::with_capacity(segments as usize * 2 + 2);
+            for i in 0..=segments {
+                let angle = (start + sweep * i as f32 / segments as f32).to_radians();
+                let direction = Vec3::new(-angle.sin(), angle.cos(), 0.0);
+                vertices.push(Vertex::new(direction * outer, Vec3::Z, None, None));
+                vertices.push(Vertex::new(direction * inner, Vec3::Z, None, None));
+            }
+            let mut indices = Vec::with_capacity(segments as usize * 6);
+            for i in 0..segments {
+                let (outer_a, inner_a, outer_b, inner_b) = (i * 2, i * 2 + 1, i * 2 + 2, i * 2 + 3);
+                indices.extend_from_slice(&[outer_a, outer_b, inner_b, outer_a, inner_b, inner_a]);
+            }
+            mesh.set_verts(&vertices, true).set_inds(&indices);
+
+            let transform = Matrix::t(bounds.center);
+            unsafe { mesh_draw(mesh.0.as_ptr(), Material::unlit().0.as_ptr(), transform, color, RenderLayer::Layer0) };
+        });
+    }
+
+    /// Non canonical function giving the start angle of a [`Ui::progress_ring`] in degrees, clockwise from the top. It
+    /// turns while the ring is indeterminate, and is 0 otherwise.
+    /// * id - The id of the ring.
+    ///
+    /// Returns None if no ring with this id has been drawn.
+    ///
+    /// see also [`Ui::progress_ring`]
+    pub fn get_progress_ring_angle(id: impl AsRef<str>) -> Option<f32> {
+        UI_PROGRESS_RINGS.with_borrow(|rings| rings.get(id.as_ref()).map(|(_, spin)| *spin))
+    }
+
     /// All UI between push_enabled and its matching pop_enabled will set the UI to an enabled or disabled state,
     /// allowing or preventing interaction with specific elements. The default state is true.
     /// <https://stereokit.net/Pages/StereoKit/UI/PushEnabled.html>
@@ -2234,6 +3084,340 @@ impl Ui {
         unsafe { ui_stack_hash(cstr.as_ptr()) }
     }
 
+    /// Non canonical function drawing a horizontal row of toggle-like tabs, the active one being the only one pressed.
+    /// The tabs continue on a new line when they don't fit in the remaining width of the current layout.
+    /// * id - The id of the tab bar. The names of the tabs must be unique inside a tab bar.
+    /// * tabs - The names displayed on the tabs.
+    /// * active - The index of the active tab, updated when another tab is pressed.
+    ///
+    /// Returns true if the active tab has changed this frame. Pressing the active tab is not a change.
+    /// see also [`Ui::tab_select`] [`crate::ui::ui_toggle_sz`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Quat}, ui::Ui};
+    ///
+    /// let tabs = ["General", "Display", "Sound"];
+    /// let mut active = 0;
+    /// let mut window_pose = Pose::new([0.0, 0.05, 0.9], Some(Quat::from_angles(0.0, 180.0, 0.0)));
+    ///
+    /// filename_scr = "screenshots/tab_bar.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Ui::window_begin("Settings", &mut window_pose, None, None, None);
+    ///     if Ui::tab_bar("settings_tabs", &tabs, &mut active) {
+    ///         println!("Now showing {}", tabs[active]);
+    ///     }
+    ///     Ui::label(format!("Page {}", tabs[active]), None, false);
+    ///     Ui::window_end();
+    /// );
+    /// assert_eq!(active, 0);
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/tab_bar.jpeg" alt="screenshot" width="200">
+    pub fn tab_bar(id: impl AsRef<str>, tabs: &[impl AsRef<str>], active: &mut usize) -> bool {
+        let settings = Ui::get_settings();
+        let text_style = Ui::get_text_style();
+        let mut changed = false;
+        Ui::push_id(id);
+        for (index, tab) in tabs.iter().enumerate() {
+            let tab = tab.as_ref();
+            let width = Text::size_layout(tab, Some(text_style), None).x + settings.padding * 2.0;
+            if index > 0 {
+                Ui::same_line();
+                let remaining = Ui::get_layout_remaining().x;
+                if remaining > 0.0 && width > remaining {
+                    Ui::next_line();
+                }
+            }
+            if Ui::toggle(tab, index == *active, Some(Vec2::new(width, 0.0))).is_some() {
+                changed |= Ui::tab_select(tabs.len(), active, index);
+            }
+        }
+        Ui::pop_id();
+        changed
+    }
+
+    /// Non canonical function doing what a press on a tab of [`Ui::tab_bar`] does. This is useful to switch tabs from
+    /// code, with a keyboard shortcut for instance.
+    /// * tab_count - The number of tabs of the tab bar.
+    /// * active - The index of the active tab, updated with `pressed` if it is a valid index.
+    /// * pressed - The index of the pressed tab.
+    ///
+    /// Returns true if the active tab has changed.
+    /// see also [`Ui::tab_bar`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::ui::Ui;
+    ///
+    /// let mut active = 0;
+    /// assert!(Ui::tab_select(3, &mut active, 2));
+    /// assert_eq!(active, 2);
+    ///
+    /// // Pressing the active tab or a tab that doesn't exist changes nothing.
+    /// assert!(!Ui::tab_select(3, &mut active, 2));
+    /// assert!(!Ui::tab_select(3, &mut active, 3));
+    /// assert_eq!(active, 2);
+    /// ```
+    pub fn tab_select(tab_count: usize, active: &mut usize, pressed: usize) -> bool {
+        if pressed >= tab_count || pressed == *active {
+            return false;
+        }
+        *active = pressed;
+        true
+    }
+
+    /// Non canonical function drawing a button that can be dragged to a [`Ui::drop_target`]. Activating it with a
+    /// pinch begins a drag carrying `payload`, and a ghost of the button follows the pinch until it is released. The
+    /// drag is forgotten if the source is not drawn anymore.
+    /// * id - The id of the element, also displayed on it.
+    /// * payload - The value given to the drop target. It is captured when the drag begins.
+    ///
+    /// Returns true while this source is dragged.
+    /// see also [`Ui::drop_target`] [`Ui::drag_begin`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Quat, Vec3}, system::{HandJoint, Handed, Hierarchy, Input}, ui::Ui};
+    ///
+    /// let mut window_pose = Pose::new([0.0, 0.05, 0.9], Some(Quat::from_angles(0.0, 180.0, 0.0)));
+    /// // A hand with its index finger at `point`, the thumb being far from it unless the hand pinches.
+    /// let hand_at = |point: Vec3, pinch: bool| {
+    ///     let mut joints = [HandJoint { position: point, orientation: Quat::IDENTITY, radius: 0.005 }; 25];
+    ///     if !pinch {
+    ///         joints[0..5].iter_mut().for_each(|joint| joint.position = point + Vec3::UP * 0.06);
+    ///     }
+    ///     joints
+    /// };
+    /// let (mut pear, mut basket, mut toward_user) = (Vec3::ZERO, Vec3::ZERO, Vec3::FORWARD);
+    /// let mut basket_content: Vec<String> = vec![];
+    /// let mut pear_dragged = false;
+    ///
+    /// filename_scr = "screenshots/drag_and_drop.jpeg";
+    /// number_of_steps = 14;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Ui::window_begin("Fruits", &mut window_pose, None, None, None);
+    ///     toward_user = Hierarchy::to_world_direction(token, Vec3::FORWARD);
+    ///     Ui::drag_source("Apple", "apple".to_string());
+    ///     Ui::same_line();
+    ///     pear = Hierarchy::to_world_point(token, Ui::get_layout_at() + Vec3::new(-0.015, -Ui::get_line_height() / 2.0, 0.0));
+    ///     pear_dragged |= Ui::drag_source("Pear", "pear".to_string());
+    ///     basket = Hierarchy::to_world_point(token, Ui::get_layout_at() + Vec3::new(-0.02, -Ui::get_line_height(), 0.0));
+    ///     if let Some(payload) = Ui::drop_target("Basket") {
+    ///         basket_content.push(*payload.downcast::<String>().expect("a String"));
+    ///     }
+    ///     Ui::window_end();
+    ///
+    ///     // The pinching hand pokes "Pear", moves over "Basket" and opens.
+    ///     match iter {
+    ///         1 => Input::hand_override(Handed::Right, &hand_at(pear + toward_user * 0.03, true)),
+    ///         2 => Input::hand_override(Handed::Right, &hand_at(pear + toward_user * 0.005, true)),
+    ///         3 => Input::hand_override(Handed::Right, &hand_at(pear - toward_user * 0.005, true)),
+    ///         4..=7 => {
+    ///             let to = basket + toward_user * 0.01;
+    ///             let at = pear + (to - pear) * (iter - 3) as f32 / 4.0;
+    ///             Input::hand_override(Handed::Right, &hand_at(at, true))
+    ///         }
+    ///         8 => Input::hand_override(Handed::Right, &hand_at(basket + toward_user * 0.01, false)),
+    ///         9 => Input::hand_clear_override(Handed::Right),
+    ///         // A drag whose source is never drawn is forgotten after a frame.
+    ///         10 => {
+    ///             Ui::drag_begin("Ghost", 0, Handed::Left);
+    ///             assert_eq!(Ui::get_drag_source(), Some("Ghost".to_string()));
+    ///         }
+    ///         11 => assert_eq!(Ui::get_drag_source(), Some("Ghost".to_string())),
+    ///         12 => assert!(Ui::get_drag_source().is_none()),
+    ///         _ => (),
+    ///     }
+    /// );
+    /// assert!(pear_dragged);
+    /// assert_eq!(basket_content, vec!["pear".to_string()]);
+    ///
+    /// // What a pinch on "Pear" released over "Basket" does:
+    /// Ui::drag_begin("Pear", "pear".to_string(), Handed::Right);
+    /// assert_eq!(Ui::get_drag_source(), Some("Pear".to_string()));
+    /// let payload = Ui::drop_release().expect("a drag in progress");
+    /// assert_eq!(payload.downcast_ref::<String>(), Some(&"pear".to_string()));
+    /// assert!(Ui::get_drag_source().is_none());
+    /// assert!(Ui::drop_release().is_none());
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/drag_and_drop.jpeg" alt="screenshot" width="200">
+    pub fn drag_source(id: impl AsRef<str>, payload: impl Any + Clone) -> bool {
+        let id = id.as_ref();
+        Ui::button(id, None);
+        let bounds = Ui::get_layout_last();
+        if Ui::get_last_element_active().is_just_active() {
+            let handed =
+                if Ui::last_element_hand_active(Handed::Left).is_active() { Handed::Left } else { Handed::Right };
+            Ui::drag_begin(id, payload, handed);
+        }
+
+        let frame = Time::get_frame();
+        let Some(handed) = with_ui_drag(|drag| {
+            let drag = drag.as_mut().filter(|drag| drag.source == id)?;
+            drag.frame = frame;
+            Some(drag.handed)
+        }) else {
+            return false;
+        };
+        let hand = Input::hand(handed);
+        if !hand.pinch.is_active() && !hand.pinch.is_just_inactive() {
+            // Released over no target.
+            with_ui_drag(|drag| drag.take());
+            return false;
+        }
+        let at = unsafe { hierarchy_to_local_point(&hand.pinch_pt) };
+        let size = Vec2::new(bounds.dimensions.x, bounds.dimensions.y);
+        let top_left = at + Vec3::new(size.x / 2.0, size.y / 2.0, 0.0);
+        Ui::push_tint(Color128::new(1.0, 1.0, 1.0, 0.6));
+        Ui::draw_element(UiVisual::Button, None, top_left, Vec3::new(size.x, size.y, Ui::get_settings().depth), 0.5);
+        Ui::text_at(id, None, None, TextAlign::Center, TextFit::Squeeze, top_left, size);
+        Ui::pop_tint();
+        true
+    }
+
+    /// Non canonical function drawing an area receiving the payloads of [`Ui::drag_source`]. The payload is given when
+    /// the pinch dragging it is released over the area.
+    /// * id - The id of the element, also displayed on it.
+    ///
+    /// Returns the payload dropped this frame, to be downcast by the app to the type given to the source.
+    /// see also [`Ui::drag_source`] [`Ui::drop_release`]
+    pub fn drop_target(id: impl AsRef<str>) -> Option<Box<dyn Any>> {
+        let id = id.as_ref();
+        let settings = Ui::get_settings();
+        let text_size = Text::size_layout(id, Some(Ui::get_text_style()), None);
+        let size = Vec2::new(text_size.x + settings.padding * 2.0, Ui::get_line_height() * 2.0);
+        let bounds = Ui::layout_reserve(size, false, settings.depth);
+        let top_left = bounds.center + bounds.dimensions / 2.0;
+
+        let dragging = with_ui_drag(|drag| drag.as_ref().map(|drag| drag.handed));
+        let mut hovered = false;
+        if let Some(handed) = dragging {
+            let hand = Input::hand(handed);
+            let at = unsafe { hierarchy_to_local_point(&hand.pinch_pt) };
+            let (min, max) = (bounds.center - bounds.dimensions / 2.0, top_left);
+            hovered = at.x >= min.x
+                && at.x <= max.x
+                && at.y >= min.y
+                && at.y <= max.y
+                && (at.z - bounds.center.z).abs() < 0.1;
+            if hovered && hand.pinch.is_just_inactive() {
+                return Ui::drop_release();
+            }
+        }
+        let focus = if hovered {
+            1.0
+        } else if dragging.is_some() {
+            0.5
+        } else {
+            0.0
+        };
+        Ui::draw_element(UiVisual::Input, None, top_left, Vec3::new(size.x, size.y, settings.depth), focus);
+        Ui::text_at(id, None, None, TextAlign::Center, TextFit::Squeeze, top_left, size);
+        None
+    }
+
+    /// Non canonical function beginning a drag, as a pinch on a [`Ui::drag_source`] does. The previous drag, if any,
+    /// is forgotten. Like the other drags, it is forgotten if no [`Ui::drag_source`] with this id is drawn during the
+    /// next frame.
+    /// * id - The id of the source.
+    /// * payload - The value the drop target will receive.
+    /// * handed - The hand dragging the payload, its pinch point is where the payload can be dropped.
+    ///
+    /// see also [`Ui::drag_source`]
+    pub fn drag_begin(id: impl AsRef<str>, payload: impl Any, handed: Handed) {
+        let drag =
+            UiDrag { source: id.as_ref().to_string(), handed, payload: Box::new(payload), frame: Time::get_frame() };
+        UI_DRAG.with(|cell| cell.replace(Some(drag)));
+    }
+
+    /// Non canonical function ending the drag in progress, as a release over a [`Ui::drop_target`] does.
+    ///
+    /// Returns the payload of the drag, or None if there is no drag in progress.
+    /// see also [`Ui::drop_target`]
+    pub fn drop_release() -> Option<Box<dyn Any>> {
+        with_ui_drag(|drag| drag.take()).map(|drag| drag.payload)
+    }
+
+    /// Non canonical function giving the id of the [`Ui::drag_source`] being dragged, if any.
+    ///
+    /// see also [`Ui::drag_source`]
+    pub fn get_drag_source() -> Option<String> {
+        with_ui_drag(|drag| drag.as_ref().map(|drag| drag.source.clone()))
+    }
+
+    /// Non canonical function drawing a button opening a file picker, and giving the chosen file for the frame it's
+    /// picked. On desktop the picker is the one of the OS, elsewhere StereoKit falls back to its own in-app picker.
+    /// * id - An id for tracking the picker. MUST be unique within current hierarchy.
+    /// * label - The text of the button.
+    /// * filters - The extensions of the files to show, with or without their leading dot, like
+    ///   [`crate::system::Assets::MODEL_FORMATS`]. Empty to show all the files.
+    ///
+    /// Returns the chosen file once, None otherwise.
+    ///
+    /// see also [`Ui::file_picker_select`] [`crate::util::Platform::file_picker_sz`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Quat}, ui::Ui};
+    /// use std::path::PathBuf;
+    ///
+    /// let mut picked = vec![];
+    /// let mut window_pose = Pose::new([0.0, 0.05, 0.9], Some(Quat::from_angles(0.0, 180.0, 0.0)));
+    /// filename_scr = "screenshots/ui_file_picker_button.jpeg";
+    /// number_of_steps = 3;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Ui::window_begin("Assets", &mut window_pose, None, None, None);
+    ///     picked.push(Ui::file_picker_button("model", "Open a model...", &["gltf", ".glb"]));
+    ///     Ui::window_end();
+    ///     // What the picker gives when the user chooses a file.
+    ///     if iter == 1 {
+    ///         Ui::file_picker_select("model", "assets/center.glb");
+    ///     }
+    /// );
+    /// assert_eq!(picked[0], None);
+    /// assert_eq!(picked[1], None);
+    /// assert_eq!(picked[2], Some(PathBuf::from("assets/center.glb")));
+    /// assert!(picked[3..].iter().all(|path| path.is_none()));
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/ui_file_picker_button.jpeg" alt="screenshot" width="200">
+    pub fn file_picker_button(id: impl AsRef<str>, label: impl AsRef<str>, filters: &[&str]) -> Option<PathBuf> {
+        let id = id.as_ref();
+        Ui::push_id(id);
+        let clicked = Ui::button(label, None);
+        Ui::pop_id();
+
+        if clicked {
+            UI_FILE_PICKER_PENDING.with_borrow_mut(|pending| *pending = Some(id.to_string()));
+            let filters: Vec<FileFilter> = filters
+                .iter()
+                .map(|filter| FileFilter::new(format!(".{}", filter.trim_start_matches('.'))))
+                .collect();
+            unsafe {
+                platform_file_picker_sz(
+                    PickerMode::Open,
+                    null_mut(),
+                    Some(ui_file_picker_callback),
+                    filters.as_ptr(),
+                    filters.len() as i32,
+                )
+            }
+        }
+        UI_FILE_PICKS.with_borrow_mut(|picks| picks.remove(id))
+    }
+
+    /// Non canonical function giving a chosen file to the [`Ui::file_picker_button`] with this id, which returns it at
+    /// its next call. This is how the file picker reports, and how a picker of your own can do the same, like the
+    /// FileBrowser tool when its caller receives the selected file.
+    /// * id - The id of the file picker button.
+    /// * path - The chosen file.
+    ///
+    /// see also [`Ui::file_picker_button`]
+    pub fn file_picker_select(id: impl AsRef<str>, path: impl Into<PathBuf>) {
+        UI_FILE_PICKS.with_borrow_mut(|picks| picks.insert(id.as_ref().to_string(), path.into()));
+    }
+
     /// A scrolling text element! This is for reading large chunks of text that may be too long to fit in the available
     /// space when scroll is Some(size). It requires a height, as well as a place to store the current scroll value.
     /// Text uses the UI's current font settings, which can be changed with UI.Push/PopTextStyle.
@@ -2447,6 +3631,118 @@ impl Ui {
         }
     }
 
+    /// Non canonical function starting a collapsible node of a tree. It draws a caret toggling `expanded` and the
+    /// label, indented by the number of expanded nodes this one is nested in. When it returns true, draw the content
+    /// of the node, its child nodes included, then call [`Ui::tree_node_end`].
+    /// * id - The id of the node, unique among its siblings.
+    /// * label - The text displayed after the caret.
+    /// * expanded - Is the content of the node visible. Toggled when the caret is pressed.
+    ///
+    /// Returns true if the node is expanded and its content must be drawn.
+    /// see also [`Ui::tree_node_end`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Quat}, ui::Ui};
+    ///
+    /// let mut expanded = [true, true, false];
+    /// let mut window_pose = Pose::new([0.0, 0.05, 0.9], Some(Quat::from_angles(0.0, 180.0, 0.0)));
+    ///
+    /// filename_scr = "screenshots/tree_node.jpeg";
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     Ui::window_begin("Model nodes", &mut window_pose, None, None, None);
+    ///     if Ui::tree_node_begin("root", "Root", &mut expanded[0]) {
+    ///         if Ui::tree_node_begin("body", "Body", &mut expanded[1]) {
+    ///             Ui::label("Mesh: body_mesh", None, false);
+    ///             Ui::tree_node_end();
+    ///         }
+    ///         if Ui::tree_node_begin("head", "Head", &mut expanded[2]) {
+    ///             Ui::label("Mesh: head_mesh", None, false);
+    ///             Ui::tree_node_end();
+    ///         }
+    ///         Ui::tree_node_end();
+    ///     }
+    ///     Ui::window_end();
+    /// );
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/tree_node.jpeg" alt="screenshot" width="200">
+    pub fn tree_node_begin(id: impl AsRef<str>, label: impl AsRef<str>, expanded: &mut bool) -> bool {
+        let depth = TREE_NODE_DEPTH.with(|depth| depth.get());
+        let line_height = Ui::get_line_height();
+        if depth > 0 {
+            Ui::hspace(depth as f32 * line_height);
+            Ui::same_line();
+        }
+        Ui::push_id(id);
+        let caret = if *expanded { Sprite::arrow_down() } else { Sprite::arrow_right() };
+        let caret_size = Vec2::new(line_height, line_height);
+        if Ui::button_img("caret", caret, Some(UiBtnLayout::CenterNoText), Some(caret_size), None) {
+            *expanded = !*expanded;
+        }
+        Ui::same_line();
+        Ui::label(label, None, true);
+        if *expanded {
+            TREE_NODE_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        } else {
+            Ui::pop_id();
+        }
+        *expanded
+    }
+
+    /// Non canonical function closing a node opened by a [`Ui::tree_node_begin`] that returned true.
+    ///
+    /// see also [`Ui::tree_node_begin`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::Pose, ui::Ui};
+    ///
+    /// let mut root_expanded = true;
+    /// let mut child_expanded = true;
+    /// let mut leaves_drawn = vec![];
+    /// let mut window_pose = Pose::IDENTITY;
+    ///
+    /// number_of_steps = 4;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     // Collapsing the root as a press on its caret would do.
+    ///     if iter == 2 {
+    ///         root_expanded = false;
+    ///     }
+    ///     let mut leaf_drawn = false;
+    ///     Ui::window_begin("Tree", &mut window_pose, None, None, None);
+    ///     if Ui::tree_node_begin("root", "Root", &mut root_expanded) {
+    ///         if Ui::tree_node_begin("child", "Child", &mut child_expanded) {
+    ///             Ui::label("Leaf", None, false);
+    ///             leaf_drawn = true;
+    ///             Ui::tree_node_end();
+    ///         }
+    ///         Ui::tree_node_end();
+    ///     }
+    ///     Ui::window_end();
+    ///     leaves_drawn.push(leaf_drawn);
+    /// );
+    /// assert_eq!(leaves_drawn.first(), Some(&true));
+    /// assert_eq!(leaves_drawn.last(), Some(&false));
+    /// // The child keeps its own state while hidden.
+    /// assert!(child_expanded);
+    /// ```
+    pub fn tree_node_end() {
+        let closed = TREE_NODE_DEPTH.with(|depth| match depth.get() {
+            0 => false,
+            current => {
+                depth.set(current - 1);
+                true
+            }
+        });
+        if closed {
+            Ui::pop_id();
+        } else {
+            Log::err("Ui::tree_node_end called without an expanded Ui::tree_node_begin!");
+        }
+    }
+
     /// A volume for helping to build one handed interactions. This checks for the presence of a hand inside the bounds,
     /// and if found, return that hand along with activation and focus information defined by the interactType.
     /// <https://stereokit.net/Pages/StereoKit/UI/VolumeAt.html>
@@ -2638,6 +3934,127 @@ impl Ui {
         unsafe { ui_window_begin(cstr.as_ptr(), pose, size, window_type, move_type) }
     }
 
+    /// Non canonical function beginning a window the user can resize by pinching and dragging a grip in its bottom
+    /// right corner. The new size is written back into `size` and applied at the next frame. Must be finished with a
+    /// call to Ui::window_end().
+    /// * size - The size of the window, clamped between `min_size` and `max_size`.
+    /// * min_size - The smallest size the user can give to the window.
+    /// * max_size - The largest size the user can give to the window.
+    /// * window_type - Describes how the window should be drawn, use a header, a body, neither, or both? None is
+    ///   UiWin::Normal
+    /// * move_type - Describes how the window will move when dragged around. None is UiMove::FaceUser
+    ///
+    /// Returns true if the size changed this frame, by the grip or by the clamping.
+    /// see also [`Ui::window_begin`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::{maths::{Pose, Vec2, Vec3, Quat}, system::{HandJoint, Handed, Hierarchy, Input}, ui::Ui};
+    ///
+    /// let mut window_pose = Pose::new(Vec3::new(0.0, 0.05, 0.90), Some(Quat::from_angles(0.0, 180.0, 0.0)));
+    /// let (min_size, max_size) = (Vec2::new(0.1, 0.1), Vec2::new(0.3, 0.2));
+    /// let mut size = Vec2::new(0.5, 0.05);
+    /// // A hand with its index finger at `point`, the thumb being far from it unless the hand pinches.
+    /// let hand_at = |point: Vec3, pinch: bool| {
+    ///     let mut joints = [HandJoint { position: point, orientation: Quat::IDENTITY, radius: 0.005 }; 25];
+    ///     if !pinch {
+    ///         joints[0..5].iter_mut().for_each(|joint| joint.position = point + Vec3::UP * 0.06);
+    ///     }
+    ///     joints
+    /// };
+    /// let (mut grip, mut grabbed_at, mut drag) = (Vec3::ZERO, Vec3::ZERO, Vec3::ZERO);
+    ///
+    /// let mut changes = vec![];
+    /// let mut sizes = vec![];
+    /// filename_scr = "screenshots/window_resizable.jpeg";
+    /// number_of_steps = 14;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     let resized = Ui::window_begin_resizable("Resizable", &mut window_pose, &mut size, min_size, max_size, None, None);
+    ///     // The grip is in the bottom right corner of the window, X+ being on the left in the UI.
+    ///     let padding = Ui::get_settings().padding;
+    ///     let corner = Ui::get_layout_at() + Vec3::new(padding, padding, 0.0);
+    ///     grip = Hierarchy::to_world_point(token, corner - Vec3::new(size.x - 0.01, size.y - 0.01, 0.0));
+    ///     // Toward the right and the bottom, further than the largest size.
+    ///     drag = Hierarchy::to_world_direction(token, Vec3::new(-0.3, -0.3, 0.0));
+    ///     Ui::label("Pinch the corner to resize", None, true);
+    ///     Ui::window_end();
+    ///     changes.push(resized);
+    ///     sizes.push(size);
+    ///
+    ///     match iter {
+    ///         1 => Input::hand_override(Handed::Right, &hand_at(grip, false)),
+    ///         2 => {
+    ///             grabbed_at = grip;
+    ///             Input::hand_override(Handed::Right, &hand_at(grabbed_at, true))
+    ///         }
+    ///         3..=8 => Input::hand_override(Handed::Right, &hand_at(grabbed_at + drag * (iter - 2) as f32 / 6.0, true)),
+    ///         9 => Input::hand_override(Handed::Right, &hand_at(grabbed_at + drag, false)),
+    ///         10 => Input::hand_clear_override(Handed::Right),
+    ///         _ => (),
+    ///     }
+    /// );
+    /// // The size is clamped at the first frame.
+    /// assert!(changes[0]);
+    /// assert_eq!(sizes[0], Vec2::new(0.3, 0.1));
+    /// // The grip made the window taller, without going beyond the largest size.
+    /// assert!(changes[3..10].iter().any(|resized| *resized));
+    /// assert!(sizes.iter().all(|size| size.x >= min_size.x && size.x <= max_size.x));
+    /// assert!(sizes.iter().all(|size| size.y >= min_size.y && size.y <= max_size.y));
+    /// assert_eq!(size, max_size);
+    /// // Nobody touches the grip anymore.
+    /// assert!(changes[11..].iter().all(|resized| !resized));
+    /// ```
+    /// <img src="https://raw.githubusercontent.com/mvvvv/StereoKit-rust/refs/heads/master/screenshots/window_resizable.jpeg" alt="screenshot" width="200">
+    pub fn window_begin_resizable(
+        text: impl AsRef<str>,
+        pose: &mut Pose,
+        size: &mut Vec2,
+        min_size: impl Into<Vec2>,
+        max_size: impl Into<Vec2>,
+        window_type: Option<UiWin>,
+        move_type: Option<UiMove>,
+    ) -> bool {
+        let (min_size, max_size) = (min_size.into(), max_size.into());
+        let previous = *size;
+        *size = Vec2::new(size.x.clamp(min_size.x, max_size.x), size.y.clamp(min_size.y, max_size.y));
+        Self::window_begin(text, pose, Some(*size), window_type, move_type);
+
+        // The grip slides between the corners of the smallest and the largest windows.
+        let settings = Self::get_settings();
+        let top_left = Self::get_layout_at() + Vec3::new(settings.padding, settings.padding, 0.0);
+        let area_at = top_left - Vec3::new(min_size.x - RESIZE_GRIP_SIZE, min_size.y - RESIZE_GRIP_SIZE, 0.0);
+        let area_size = max_size - min_size + Vec2::ONE * RESIZE_GRIP_SIZE;
+        let id = Self::stack_hash("window_resize_grip");
+        let mut data = UiSliderData::default();
+        Self::slider_behavior(
+            area_at,
+            area_size,
+            id,
+            size,
+            min_size,
+            max_size,
+            Vec2::ONE * RESIZE_GRIP_SIZE,
+            Vec2::ONE * RESIZE_GRIP_SIZE,
+            Some(UiConfirm::Pinch),
+            &mut data,
+        );
+        let grip_at = Vec3::new(
+            data.button_center.x + RESIZE_GRIP_SIZE / 2.0,
+            data.button_center.y + RESIZE_GRIP_SIZE / 2.0,
+            top_left.z,
+        );
+        let focus = Self::get_anim_focus(id, data.focus_state, data.active_state);
+        Self::draw_element(
+            UiVisual::SliderPinch,
+            None,
+            grip_at,
+            Vec3::new(RESIZE_GRIP_SIZE, RESIZE_GRIP_SIZE, settings.depth),
+            focus,
+        );
+        *size != previous
+    }
+
     /// Finishes a window! Must be called after Ui::window_begin() and all elements have been drawn.
     /// <https://stereokit.net/Pages/StereoKit/UI/WindowEnd.html>
     ///
@@ -2709,6 +4126,14 @@ impl Ui {
         unsafe { ui_line_height() }
     }
 
+    /// Non canonical function giving the state of the secondary action opening the context menus: the right button of
+    /// the mouse or the second button (B/Y) of the controllers.
+    ///
+    /// see also [`Ui::context_menu_begin`]
+    pub fn get_secondary_action() -> BtnState {
+        Input::key(Key::MouseRight) | Input::controller(Handed::Left).x2 | Input::controller(Handed::Right).x2
+    }
+
     /// UI sizing and layout settings.
     /// <https://stereokit.net/Pages/StereoKit/UI/Settings.html>
     ///
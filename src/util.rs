@@ -2,9 +2,12 @@ use crate::{
     maths::{lerp, Bool32T, Vec3},
     sk::DisplayBlend,
     system::TextContext,
+    tex::Tex,
     StereoKitError,
 };
 use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
     ffi::{c_char, c_void, CStr, CString},
     fmt::Display,
     ops::{Div, DivAssign, Mul, MulAssign},
@@ -26,6 +29,7 @@ use std::{
 /// <https://stereokit.net/Pages/StereoKit/Color.html>
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color128 {
     pub r: f32,
     pub g: f32,
@@ -169,6 +173,111 @@ impl Color128 {
     pub fn to_lab(&self) -> Vec3 {
         unsafe { color_to_lab(self) }
     }
+
+    /// Non canonical function to create a Red/Green/Blue gamma space color from Hue/Saturation/Value information,
+    /// computed in Rust. All the values are in the 0-1 range, hue included.
+    /// * hue - 0 and 1 are red, 1/3 is green, 2/3 is blue.
+    /// * saturation - 0 is gray, 1 is the pure color.
+    /// * value - 0 is black, 1 is the brightest.
+    /// * transparency - The alpha of the color.
+    ///
+    /// see also [`Color128::hsv`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::util::{Color128, named_colors};
+    ///
+    /// let red: Color128 = named_colors::RED.into();
+    /// assert_eq!(Color128::from_hsv(0.0, 1.0, 1.0, 1.0).to_hex_string(), red.to_hex_string());
+    /// let blue = Color128::from_hsv(2.0 / 3.0, 1.0, 1.0, 0.5);
+    /// assert_eq!(blue.to_hex_string(), "#0000FF80");
+    ///
+    /// // Round trip
+    /// let orange = Color128::from_hex("#FFA500").unwrap();
+    /// let (h, s, v, a) = orange.to_hsva();
+    /// assert_eq!(Color128::from_hsv(h, s, v, a).to_hex_string(), "#FFA500FF");
+    /// ```
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32, transparency: f32) -> Self {
+        let hue = (hue - hue.floor()) * 6.0;
+        let sector = hue.floor();
+        let fraction = hue - sector;
+        let p = value * (1.0 - saturation);
+        let q = value * (1.0 - saturation * fraction);
+        let t = value * (1.0 - saturation * (1.0 - fraction));
+        let (r, g, b) = match sector as u32 {
+            0 => (value, t, p),
+            1 => (q, value, p),
+            2 => (p, value, t),
+            3 => (p, q, value),
+            4 => (t, p, value),
+            _ => (value, p, q),
+        };
+        Self { r, g, b, a: transparency }
+    }
+
+    /// Non canonical function to convert the gamma space color to Hue/Saturation/Value and transparency, computed in
+    /// Rust. All the values are in the 0-1 range. Gray colors have a hue of 0.
+    ///
+    /// Returns (hue, saturation, value, transparency).
+    /// see also [`Color128::to_hsv`] [`Color128::from_hsv`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::util::Color128;
+    ///
+    /// let (h, s, v, a) = Color128::new(0.0, 0.5, 0.0, 0.25).to_hsva();
+    /// assert!((h - 1.0 / 3.0).abs() < 0.0001);
+    /// assert_eq!((s, v, a), (1.0, 0.5, 0.25));
+    ///
+    /// assert_eq!(Color128::new(0.5, 0.5, 0.5, 1.0).to_hsva(), (0.0, 0.0, 0.5, 1.0));
+    /// ```
+    pub fn to_hsva(&self) -> (f32, f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+        let saturation = if max > 0.0 { delta / max } else { 0.0 };
+        let hue = if delta <= 0.0 {
+            0.0
+        } else if max == self.r {
+            ((self.g - self.b) / delta).rem_euclid(6.0) / 6.0
+        } else if max == self.g {
+            ((self.b - self.r) / delta + 2.0) / 6.0
+        } else {
+            ((self.r - self.g) / delta + 4.0) / 6.0
+        };
+        (hue, saturation, max, self.a)
+    }
+
+    /// Non canonical function to parse a color from an hex string `#RRGGBB` or `#RRGGBBAA`. The leading `#` is
+    /// optional and alpha is 1.0 if not given.
+    ///
+    /// Returns None if the string is malformed.
+    /// see also [`Color32::from_hex`] [`Color128::hex`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::util::Color128;
+    ///
+    /// let color = Color128::from_hex("#FF8000").unwrap();
+    /// assert_eq!((color.r, color.g, color.b, color.a), (1.0, 128.0 / 255.0, 0.0, 1.0));
+    /// assert_eq!(color.to_hex_string(), "#FF8000FF");
+    ///
+    /// let color = Color128::from_hex("00ff0080").unwrap();
+    /// assert_eq!(color.to_hex_string(), "#00FF0080");
+    ///
+    /// assert!(Color128::from_hex("#FF80").is_none());
+    /// assert!(Color128::from_hex("#GG8000").is_none());
+    /// assert!(Color128::from_hex("#+F8000").is_none());
+    /// ```
+    pub fn from_hex(hex: impl AsRef<str>) -> Option<Self> {
+        Color32::from_hex(hex).map(|color| color.into())
+    }
+
+    /// Non canonical function to write the color as an hex string `#RRGGBBAA`. Components are clamped to 0-1 and
+    /// rounded to the nearest byte.
+    ///
+    /// see also [`Color32::to_hex_string`]
+    pub fn to_hex_string(&self) -> String {
+        let to_byte = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Color32::new(to_byte(self.r), to_byte(self.g), to_byte(self.b), to_byte(self.a)).to_hex_string()
+    }
 }
 
 impl Display for Color128 {
@@ -325,6 +434,7 @@ impl Mul<Color128> for f32 {
 /// See also [Color128]
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color32 {
     pub r: u8,
     pub g: u8,
@@ -393,6 +503,44 @@ impl Color32 {
             a: (hex_value & 0x000000FF) as u8,
         }
     }
+
+    /// Non canonical function to parse a color from an hex string `#RRGGBB` or `#RRGGBBAA`. The leading `#` is
+    /// optional and alpha is 255 if not given.
+    ///
+    /// Returns None if the string is malformed.
+    /// see also [`Color32::hex`]
+    /// ### Examples
+    /// ```
+    /// use stereokit_rust::util::{Color32, named_colors};
+    ///
+    /// assert_eq!(Color32::from_hex("#808000"), Some(named_colors::OLIVE));
+    /// assert_eq!(Color32::from_hex("#12345678"), Some(Color32::hex(0x12345678)));
+    /// assert_eq!(Color32::hex(0x12345678).to_hex_string(), "#12345678");
+    ///
+    /// assert_eq!(Color32::from_hex(""), None);
+    /// assert_eq!(Color32::from_hex("#1234567"), None);
+    /// assert_eq!(Color32::from_hex("#12345G"), None);
+    /// ```
+    pub fn from_hex(hex: impl AsRef<str>) -> Option<Self> {
+        let hex = hex.as_ref();
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        match hex.len() {
+            6 => Some(Self::hex((value << 8) | 0xFF)),
+            8 => Some(Self::hex(value)),
+            _ => None,
+        }
+    }
+
+    /// Non canonical function to write the color as an hex string `#RRGGBBAA`.
+    ///
+    /// see also [`Color32::from_hex`]
+    pub fn to_hex_string(&self) -> String {
+        format!("#{:02X}{:02X}{:02X}{:02X}", self.r, self.g, self.b, self.a)
+    }
 }
 
 impl Display for Color32 {
@@ -751,9 +899,15 @@ impl GradientKey {
 pub struct Gradient(pub NonNull<_GradientT>);
 impl Drop for Gradient {
     fn drop(&mut self) {
+        let _ = GRADIENT_POSITIONS.try_with(|positions| positions.borrow_mut().remove(&(self.0.as_ptr() as usize)));
         unsafe { gradient_destroy(self.0.as_ptr()) };
     }
 }
+
+thread_local! {
+    /// The positions of the keys of each Gradient in StereoKit's order, as StereoKit doesn't give them back.
+    static GRADIENT_POSITIONS: RefCell<HashMap<usize, Vec<f32>>> = RefCell::new(HashMap::new());
+}
 impl AsRef<Gradient> for Gradient {
     fn as_ref(&self) -> &Gradient {
         self
@@ -783,12 +937,28 @@ impl Gradient {
     ///
     /// see also [`crate::util::gradient_create`][`crate::util::gradient_create_keys`]
     pub fn new(keys: Option<&[GradientKey]>) -> Self {
-        match keys {
+        let gradient = match keys {
             Some(keys) => {
                 Gradient(NonNull::new(unsafe { gradient_create_keys(keys.as_ptr(), keys.len() as i32) }).unwrap())
             }
             None => Gradient(NonNull::new(unsafe { gradient_create() }).unwrap()),
+        };
+        let mut positions = vec![];
+        for key in keys.unwrap_or_default() {
+            Self::insert_position(&mut positions, key.position);
         }
+        gradient.with_positions(|gradient_positions| *gradient_positions = positions);
+        gradient
+    }
+
+    fn with_positions<R>(&self, work: impl FnOnce(&mut Vec<f32>) -> R) -> R {
+        GRADIENT_POSITIONS.with_borrow_mut(|positions| work(positions.entry(self.0.as_ptr() as usize).or_default()))
+    }
+
+    /// Insert a position where StereoKit inserts a key: after the keys at lower or equal positions.
+    fn insert_position(positions: &mut Vec<f32>, position: f32) {
+        let index = positions.partition_point(|key| *key <= position);
+        positions.insert(index, position);
     }
 
     ///This adds a color key into the list. It’ll get inserted to the right slot based on its position.
@@ -797,6 +967,7 @@ impl Gradient {
     /// see also [`crate::util::gradient_add`]
     pub fn add(&mut self, color_linear: impl Into<Color128>, position: f32) -> &mut Self {
         unsafe { gradient_add(self.0.as_ptr(), color_linear.into(), position) };
+        self.with_positions(|positions| Self::insert_position(positions, position));
         self
     }
 
@@ -807,6 +978,11 @@ impl Gradient {
     /// see also [`crate::util::gradient_set`]
     pub fn set(&mut self, index: i32, color_linear: impl Into<Color128>, position: f32) -> &mut Self {
         unsafe { gradient_set(self.0.as_ptr(), index, color_linear.into(), position) };
+        self.with_positions(|positions| {
+            if let Some(key) = positions.get_mut(index as usize) {
+                *key = position;
+            }
+        });
         self
     }
 
@@ -816,6 +992,11 @@ impl Gradient {
     /// see also [`crate::util::gradient_remove`]
     pub fn remove(&mut self, index: i32) -> &mut Self {
         unsafe { gradient_remove(self.0.as_ptr(), index) };
+        self.with_positions(|positions| {
+            if (index as usize) < positions.len() {
+                positions.remove(index as usize);
+            }
+        });
         self
     }
 
@@ -835,6 +1016,107 @@ impl Gradient {
     pub fn get32(&self, at: f32) -> Color32 {
         unsafe { gradient_get32(self.0.as_ptr(), at) }
     }
+
+    /// Non canonical function to add a color key at position `t`. Unlike [`Gradient::add`], a key already at position
+    /// `t` is replaced, so the last color given for a position is kept.
+    /// * t - The position of the key, typically between 0 and 1.
+    /// * color_linear - The color of the key, preferably in a linear color space.
+    ///
+    /// see also [`crate::util::gradient_add`] [`crate::util::gradient_set`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::util::{Color128, Gradient};
+    ///
+    /// let mut gradient = Gradient::new(None);
+    /// gradient.add_stop(0.0, Color128::BLACK).add_stop(1.0, Color128::WHITE);
+    /// // The end of the ramp becomes red.
+    /// gradient.add_stop(1.0, Color128::new(1.0, 0.0, 0.0, 1.0));
+    /// assert_eq!(gradient.get_count(), 2);
+    /// let end = gradient.sample(1.0);
+    /// assert_eq!((end.r, end.g, end.b), (1.0, 0.0, 0.0));
+    /// let middle = gradient.sample(0.5);
+    /// assert!((middle.r - 0.5).abs() < 0.001 && middle.g.abs() < 0.001);
+    ///
+    /// // Keys added with Gradient::add at the same position are still found.
+    /// gradient.add(Color128::WHITE, 0.5).add_stop(0.5, Color128::BLACK);
+    /// assert_eq!(gradient.get_count(), 3);
+    /// assert_eq!(gradient.sample(0.5).r, 0.0);
+    /// ```
+    pub fn add_stop(&mut self, t: f32, color_linear: impl Into<Color128>) -> &mut Self {
+        match self.with_positions(|positions| positions.iter().rposition(|position| *position == t)) {
+            Some(index) => self.set(index as i32, color_linear, t),
+            None => self.add(color_linear, t),
+        }
+    }
+
+    /// Non canonical function to sample the gradient at position `t`. Colors are linearly interpolated between the
+    /// neighboring keys, and clamped to the first and last keys outside of their range.
+    /// * t - The position to sample.
+    ///
+    /// see also [`Gradient::get`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::util::{Color128, Gradient};
+    ///
+    /// let mut gradient = Gradient::new(None);
+    /// gradient.add_stop(0.0, Color128::BLACK).add_stop(1.0, Color128::WHITE);
+    /// assert_eq!(gradient.get_count(), 2);
+    ///
+    /// let middle = gradient.sample(0.5);
+    /// assert!((middle.r - 0.5).abs() < 0.001 && (middle.g - 0.5).abs() < 0.001);
+    /// assert_eq!(gradient.sample(0.0).r, 0.0);
+    /// assert_eq!(gradient.sample(1.0).r, 1.0);
+    /// // Clamped at the ends.
+    /// assert_eq!(gradient.sample(-1.0).r, 0.0);
+    /// assert_eq!(gradient.sample(2.0).r, 1.0);
+    /// ```
+    pub fn sample(&self, t: f32) -> Color128 {
+        self.get(t)
+    }
+
+    /// Non canonical function returning the number of color keys of this gradient.
+    ///
+    /// see also [`crate::util::gradient_count`]
+    pub fn get_count(&self) -> i32 {
+        unsafe { gradient_count(self.0.as_ptr()) }
+    }
+
+    /// Non canonical function to bake this gradient into a `width`x1 lookup texture, sampling it from 0 to 1. As the
+    /// gradient colors are linear, the texture is not sRGB.
+    /// * width - The number of pixels of the texture, at least 1.
+    ///
+    /// see also [`Tex::from_color32`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::util::{Color128, Color32, Gradient};
+    ///
+    /// let mut gradient = Gradient::new(None);
+    /// gradient.add_stop(0.0, Color128::new(1.0, 0.0, 0.0, 1.0)).add_stop(1.0, Color128::new(0.0, 0.0, 1.0, 1.0));
+    ///
+    /// let tex = gradient.to_tex(8).expect("The ramp should be baked");
+    /// assert_eq!((tex.get_width(), tex.get_height()), (Some(8), Some(1)));
+    ///
+    /// let colors = [Color32::BLACK_TRANSPARENT; 8];
+    /// assert!(tex.get_color_data::<Color32>(&colors, 0));
+    /// assert_eq!(colors[0], Color32::new(255, 0, 0, 255));
+    /// assert_eq!(colors[7], Color32::new(0, 0, 255, 255));
+    ///
+    /// assert!(gradient.to_tex(0).is_err());
+    /// ```
+    pub fn to_tex(&self, width: usize) -> Result<Tex, StereoKitError> {
+        if width == 0 {
+            return Err(StereoKitError::TexColor("0x1".into(), "Gradient::to_tex needs a width".into()));
+        }
+        let last = (width - 1).max(1) as f32;
+        let colors: Vec<Color32> = (0..width).map(|i| self.get32(i as f32 / last)).collect();
+        Tex::from_color32(&colors, width, 1, false)
+    }
 }
 
 /// file extension to filter ie ".txt" ".gltf"
@@ -1298,10 +1580,35 @@ extern "C" {
 }
 
 impl Time {
-    /// Time is scaled by this value! Want time to pass slower? Set it to 0.5! Faster? Try 2!
+    /// Time is scaled by this value! Want time to pass slower? Set it to 0.5! Faster? Try 2! With 0.0 the scaled time
+    /// is frozen while the unscaled time keeps running, which is what a pause menu needs.
     /// <https://stereokit.net/Pages/StereoKit/Time/Scale.html>
     ///
     ///  see also [`crate::util::time_scale`]
+    /// ### Examples
+    /// ```
+    /// stereokit_rust::test_init_sk!(); // !!!! Get a proper way to initialize sk !!!!
+    ///
+    /// use stereokit_rust::util::Time;
+    ///
+    /// let first_frame = Time::get_frame();
+    /// let (mut total, mut total_unscaled) = (0.0, 0.0);
+    /// Time::scale(0.5);
+    /// number_of_steps = 6;
+    /// test_screenshot!( // !!!! Get a proper main loop !!!!
+    ///     if iter == 1 {
+    ///         assert!(Time::get_frame() > first_frame);
+    ///     } else if iter == 2 {
+    ///         assert!((Time::get_step() - Time::get_step_unscaled() * 0.5).abs() < 0.000_001);
+    ///         // Pause the game time.
+    ///         Time::scale(0.0);
+    ///         (total, total_unscaled) = (Time::get_total(), Time::get_total_unscaled());
+    ///     } else if iter == 5 {
+    ///         assert_eq!(Time::get_total(), total);
+    ///         assert!(Time::get_total_unscaled() > total_unscaled);
+    ///     }
+    /// );
+    /// ```
     pub fn scale(factor: f64) {
         unsafe { time_scale(factor) }
     }
@@ -1393,3 +1700,174 @@ impl Time {
         unsafe { time_totalf_unscaled() }
     }
 }
+
+/// Remaining time under which a schedule is due, so the rounding of the steps doesn't delay it of a whole step.
+const SCHEDULE_EPSILON: f64 = 1e-6;
+
+/// The id of a schedule, given by [`Scheduler::after`] and [`Scheduler::every`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ScheduleId(u64);
+
+enum ScheduleAction {
+    Once(Box<dyn FnOnce()>),
+    Every(f64, Box<dyn FnMut()>),
+}
+
+struct Schedule {
+    id: ScheduleId,
+    remaining: f64,
+    action: ScheduleAction,
+}
+
+thread_local! {
+    static SCHEDULES: RefCell<Vec<Schedule>> = const { RefCell::new(Vec::new()) };
+    static SCHEDULE_NEXT_ID: Cell<u64> = const { Cell::new(0) };
+    /// The schedules taken out by the current step.
+    static SCHEDULE_DUE: RefCell<Vec<ScheduleId>> = const { RefCell::new(Vec::new()) };
+    /// The schedules of SCHEDULE_DUE cancelled while the step fires them.
+    static SCHEDULE_CANCELLED: RefCell<Vec<ScheduleId>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Non canonical structure running actions after a delay or at a regular interval, instead of managing timers in
+/// each stepper. The schedules are driven on the main thread by the [`Time`] step: they fire at each step after the
+/// steppers and before the step closure of the app, with or without the event loop. The remainder of a period is kept
+/// so repeated actions don't drift.
+/// ### Examples
+/// ```
+/// stereokit_rust::test_init_sk!(1.0 / 60.0); // !!!! Get a proper way to initialize sk !!!!
+///
+/// use stereokit_rust::util::Scheduler;
+/// use std::{cell::Cell, rc::Rc};
+///
+/// let once = Rc::new(Cell::new(0));
+/// let once_clone = once.clone();
+/// Scheduler::after(0.1, move || once_clone.set(once_clone.get() + 1));
+///
+/// let ticks = Rc::new(Cell::new(0));
+/// let ticks_clone = ticks.clone();
+/// let every = Scheduler::every(1.0 / 30.0, move || ticks_clone.set(ticks_clone.get() + 1));
+///
+/// let cancelled = Scheduler::after(0.05, || panic!("a cancelled schedule must not fire"));
+/// assert!(Scheduler::cancel(cancelled));
+/// assert!(!Scheduler::cancel(cancelled));
+///
+/// let mut fired_at = None;
+/// number_of_steps = 12;
+/// test_screenshot!( // !!!! Get a proper main loop !!!!
+///     // 0.1s is 6 steps of 1/60s.
+///     if once.get() == 1 && fired_at.is_none() {
+///         fired_at = Some(iter);
+///     }
+///     if iter == 9 {
+///         Scheduler::cancel(every);
+///     }
+/// );
+/// assert_eq!(fired_at, Some(5));
+/// assert_eq!(once.get(), 1);
+/// // Every 2 steps during the first 10 steps.
+/// assert_eq!(ticks.get(), 5);
+/// ```
+pub struct Scheduler;
+
+impl Scheduler {
+    /// Run an action once after a delay.
+    /// * seconds - The delay from the current time.
+    /// * action - What to do.
+    ///
+    /// Returns the id to give to [`Scheduler::cancel`].
+    pub fn after(seconds: f32, action: impl FnOnce() + 'static) -> ScheduleId {
+        Self::add(seconds as f64, ScheduleAction::Once(Box::new(action)))
+    }
+
+    /// Run an action at a regular interval, until it is cancelled. If the step is longer than the interval, the action
+    /// runs once per elapsed interval.
+    /// * seconds - The interval. The first run is after one interval. If 0 or less, the action runs at each step.
+    /// * action - What to do.
+    ///
+    /// Returns the id to give to [`Scheduler::cancel`].
+    pub fn every(seconds: f32, action: impl FnMut() + 'static) -> ScheduleId {
+        let interval = (seconds as f64).max(0.0);
+        Self::add(interval, ScheduleAction::Every(interval, Box::new(action)))
+    }
+
+    /// Stop a schedule, it will not fire anymore. This can be called from a scheduled action.
+    /// * id - The schedule to stop.
+    ///
+    /// Returns true if the schedule was waiting, false if it is unknown, already cancelled or a finished one shot.
+    pub fn cancel(id: ScheduleId) -> bool {
+        let waiting = SCHEDULES.with_borrow_mut(|schedules| {
+            let len = schedules.len();
+            schedules.retain(|schedule| schedule.id != id);
+            schedules.len() != len
+        });
+        if waiting {
+            return true;
+        }
+        let due = SCHEDULE_DUE.with_borrow(|due| due.contains(&id));
+        if due {
+            SCHEDULE_CANCELLED.with_borrow_mut(|cancelled| cancelled.push(id));
+        }
+        due
+    }
+
+    /// The number of schedules waiting to fire.
+    pub fn get_count() -> usize {
+        SCHEDULES.with_borrow(|schedules| schedules.len())
+    }
+
+    fn add(seconds: f64, action: ScheduleAction) -> ScheduleId {
+        let id = ScheduleId(SCHEDULE_NEXT_ID.get());
+        SCHEDULE_NEXT_ID.set(id.0 + 1);
+        SCHEDULES.with_borrow_mut(|schedules| schedules.push(Schedule { id, remaining: seconds, action }));
+        id
+    }
+
+    fn is_cancelled(id: ScheduleId) -> bool {
+        SCHEDULE_CANCELLED.with_borrow(|cancelled| cancelled.contains(&id))
+    }
+
+    /// Fire the due schedules. Called at each step of the main loop.
+    pub(crate) fn step() {
+        let delta = Time::get_step();
+        let due: Vec<Schedule> = SCHEDULES.with_borrow_mut(|schedules| {
+            schedules.iter_mut().for_each(|schedule| schedule.remaining -= delta);
+            let (due, waiting) =
+                std::mem::take(schedules).into_iter().partition(|schedule| schedule.remaining <= SCHEDULE_EPSILON);
+            *schedules = waiting;
+            due
+        });
+        if due.is_empty() {
+            return;
+        }
+        SCHEDULE_DUE.with_borrow_mut(|due_ids| due_ids.extend(due.iter().map(|schedule| schedule.id)));
+
+        // The actions run outside of the borrows, so they can add or cancel schedules.
+        for mut schedule in due {
+            if Self::is_cancelled(schedule.id) {
+                continue;
+            }
+            match schedule.action {
+                ScheduleAction::Once(action) => action(),
+                ScheduleAction::Every(interval, ref mut action) => {
+                    loop {
+                        action();
+                        if interval <= 0.0 {
+                            schedule.remaining = 0.0;
+                            break;
+                        }
+                        schedule.remaining += interval;
+                        if schedule.remaining > SCHEDULE_EPSILON || Self::is_cancelled(schedule.id) {
+                            break;
+                        }
+                    }
+                    if !Self::is_cancelled(schedule.id) {
+                        SCHEDULES.with_borrow_mut(|schedules| schedules.push(schedule));
+                    }
+                }
+            }
+        }
+
+        SCHEDULE_DUE.with_borrow_mut(|due_ids| due_ids.clear());
+        SCHEDULE_CANCELLED.with_borrow_mut(|cancelled| cancelled.clear());
+    }
+}
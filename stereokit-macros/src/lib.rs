@@ -21,6 +21,10 @@ fn has_field(field_name: &str, input: &DeriveInput) -> bool {
     }
 }
 
+/// The maximum time in seconds a frame can add to the fixed update accumulator. This avoids a burst of fixed updates
+/// after a long pause.
+const MAX_FIXED_ACCUMULATION: f32 = 0.25;
+
 /// Derive the IStepper trait for a struct which must implement:
 /// * Fields:     
 ///   - **id**: StepperId,
@@ -28,11 +32,16 @@ fn has_field(field_name: &str, input: &DeriveInput) -> bool {
 ///   - *Optional* when the stepper should initialize on more than one step : **initialize_completed**: bool
 ///   - *Optional* when you want to implement an active/inactive flag: **enabled**: bool
 ///   - *Optional* when the stepper should shutdown some stuffs : **shutdown_completed**: bool
+///   - *Optional* when the stepper needs a fixed rate update : **fixed_accumulator**: f32
+///   - *Optional* to change the fixed rate (default 1/60 s) : **fixed_dt**: f32
 /// * Functions:
 ///   - IStepper::initialize calls **fn start(&mut self) -> bool** where you can abort the initialization by returning false:
 ///   - *Optional* if field **initialize_completed** is present IStepper::initialize_done calls
 ///     **fn start_completed(&mut self) -> bool** where you can tell the initialization is done:
 ///   - IStepper::step calls  **fn check_event(&mut self, _key: &str, _value: &str)** where you can check the event report:
+///   - *Optional* if field **fixed_accumulator** is present IStepper::step calls **fn fixed_update(&mut self, dt: f32)**
+///     after check_event, zero or more times, so it is called once every `fixed_dt` seconds whatever the frame rate.
+///     A frame can't add more than 0.25 seconds:
 ///   - IStepper::step calls **fn draw(&mut self, token: &MainThreadToken)** after check_event where you can draw your UI:
 ///   - *Optional* if field **shutdown_completed** is present IStepper::shutdown and IStepper::shutdown_done call
 ///     **fn close(&mut self, triggering:bool) -> bool**
@@ -81,6 +90,26 @@ pub fn derive_istepper(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    let fixed_update = if has_field("fixed_accumulator", &input) {
+        let fixed_dt = if has_field("fixed_dt", &input) {
+            quote! { self.fixed_dt }
+        } else {
+            quote! { 1.0 / 60.0 }
+        };
+        quote! {
+            let fixed_dt: f32 = #fixed_dt;
+            if fixed_dt > 0.0 {
+                self.fixed_accumulator += stereokit_rust::util::Time::get_stepf().min(#MAX_FIXED_ACCUMULATION);
+                while self.fixed_accumulator >= fixed_dt {
+                    self.fixed_accumulator -= fixed_dt;
+                    self.fixed_update(fixed_dt);
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let expanded = quote! {
         impl #generics IStepper for #name #generics {
 
@@ -107,6 +136,8 @@ pub fn derive_istepper(input: TokenStream) -> TokenStream {
                     return;
                 };
 
+                #fixed_update
+
                 self.draw(token)
             }
 
@@ -149,6 +180,63 @@ pub fn include_asset_tree(body: TokenStream) -> TokenStream {
     // .collect()
 }
 
+/// Embed the list of the files of the assets tree in your crate, as `&[&str]` of the paths relative to the crate.
+/// Add `bytes` to embed the content of the files too, as `&[(&str, &[u8])]`. Beware, with `bytes` every asset file
+/// becomes part of your binary.
+/// * `include_asset_files!("assets")`
+/// * `include_asset_files!("assets", bytes)`
+#[proc_macro]
+pub fn include_asset_files(body: TokenStream) -> TokenStream {
+    let mut vec_path = vec![];
+    let mut with_bytes = false;
+    let cargo_dir = std::env::var("CARGO_MANIFEST_DIR").ok().unwrap();
+    let path_cargo = Path::new(&cargo_dir);
+    let mut tokens = body.into_iter();
+    if let Some(TokenTree::Literal(dir)) = tokens.next() {
+        let mut sub_dir = dir.to_string();
+        sub_dir.remove(0);
+        sub_dir.pop();
+        let path_assets = path_cargo.join(&sub_dir);
+        if path_assets.is_dir() {
+            let sub_path = Path::new(&sub_dir).to_owned();
+            vec_path.append(&mut get_sub_files(path_assets, &sub_path))
+        }
+    }
+    for token in tokens {
+        if let TokenTree::Ident(ident) = token {
+            with_bytes = ident.to_string() == "bytes";
+        }
+    }
+    vec_path.sort();
+
+    if with_bytes {
+        let entries = vec_path.iter().map(|file| {
+            let full_path = path_cargo.join(file).to_string_lossy().to_string();
+            quote! { (#file, include_bytes!(#full_path) as &[u8]) }
+        });
+        TokenStream::from(quote! { &[#(#entries),*] })
+    } else {
+        let stringified = format!("&{:?}", vec_path);
+        TokenStream::from_str(&stringified).unwrap()
+    }
+}
+
+fn get_sub_files(path_assets: PathBuf, sub_path: &Path) -> Vec<String> {
+    let mut vec_path = vec![];
+    if let Ok(read_dir) = read_dir(path_assets) {
+        for file in read_dir.flatten() {
+            let path_file = file.path();
+            let sub_sub_path = &sub_path.join(file.file_name());
+            if path_file.is_dir() {
+                vec_path.append(&mut get_sub_files(path_file, sub_sub_path));
+            } else if path_file.is_file() {
+                vec_path.push(sub_sub_path.to_string_lossy().to_string().replace("\\", "/"));
+            }
+        }
+    }
+    vec_path
+}
+
 fn get_sub_dirs(path_assets: PathBuf, sub_path: &Path) -> Vec<String> {
     let mut vec_path = vec![];
     if path_assets.exists() && path_assets.is_dir() {
@@ -169,8 +257,16 @@ fn get_sub_dirs(path_assets: PathBuf, sub_path: &Path) -> Vec<String> {
     vec_path
 }
 
+/// Initialize sk for the doc tests. An optional fixed timestep in seconds can be given to have reproducible timings:
+/// `test_init_sk!(1.0 / 60.0)`
 #[proc_macro]
-pub fn test_init_sk(_input: TokenStream) -> TokenStream {
+pub fn test_init_sk(input: TokenStream) -> TokenStream {
+    let input: proc_macro2::TokenStream = input.into();
+    let fixed_timestep = if input.is_empty() {
+        quote! {}
+    } else {
+        quote! { sk.set_fixed_timestep(Some(#input)); }
+    };
     let expanded = quote! {
         use stereokit_rust::{*, prelude::*, test_screenshot};
 
@@ -179,6 +275,8 @@ pub fn test_init_sk(_input: TokenStream) -> TokenStream {
         #[cfg(feature = "event-loop")]
         let (mut sk, mut event_loop) = sk::SkSettings::default().mode(sk::AppMode::Offscreen).app_name("cargo test").init_with_event_loop().unwrap();
 
+        #fixed_timestep
+
         let mut filename_scr = "screenshots/default_screenshoot.png";
         let mut number_of_steps = 1;
         let (mut width_scr, mut height_scr, mut fov_scr, mut from_scr, mut at_scr)  = (200, 200, 99.0, maths::Vec3::Z, maths::Vec3::ZERO);